@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use avian3d::prelude::*;
+use bevy::asset::AssetPlugin;
+use bevy::input::InputPlugin;
+use bevy::mesh::MeshPlugin;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_locomotion::prelude::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// One fixed tick per `App::update`
+const TICK: Duration = Duration::from_nanos(1_000_000_000 / 64);
+
+/// Headless app with `count` players running in circles over a floor with steps
+fn build_app(count: usize, probe_interval: Option<u32>, crowd: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        InputPlugin,
+        AssetPlugin::default(),
+        MeshPlugin,
+        PhysicsPlugin,
+        PlayerPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(TICK));
+
+    let world = app.world_mut();
+    world.spawn((
+        RigidBody::Static,
+        Collider::cuboid(400.0, 1.0, 400.0),
+        Transform::from_xyz(0.0, -0.5, 0.0),
+        CollisionLayers::new(GameLayer::World, [GameLayer::Player]),
+    ));
+    for i in 0..20 {
+        world.spawn((
+            RigidBody::Static,
+            Collider::cuboid(2.0, 0.2, 2.0),
+            Transform::from_xyz(i as f32 * 8.0 - 80.0, 0.1, 0.0),
+            CollisionLayers::new(GameLayer::World, [GameLayer::Player]),
+        ));
+    }
+
+    let side = (count as f32).sqrt().ceil() as usize;
+    let mut commands = world.commands();
+    for i in 0..count {
+        let position = Vec3::new(
+            (i % side) as f32 * 2.0 - side as f32,
+            1.0,
+            (i / side) as f32 * 2.0 - side as f32,
+        );
+        let player = PlayerBuilder::new(PlayerConfig::default(), position)
+            .without_input()
+            .with_camera(false)
+            .spawn(&mut commands);
+        let angle = i as f32;
+        let mut entity = commands.entity(player);
+        entity.insert(LocomotionIntent::new(Vec2::new(angle.cos(), angle.sin())));
+        if let Some(interval) = probe_interval {
+            entity.insert(ReducedProbeRate::new(interval));
+        }
+        if crowd {
+            entity.insert(CrowdController::default());
+        }
+    }
+    world.flush();
+
+    // Settle onto the floor before measuring
+    for _ in 0..30 {
+        app.update();
+    }
+    app
+}
+
+fn many_controllers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("many_controllers");
+    for count in [1, 50, 200, 500] {
+        group.bench_with_input(BenchmarkId::new("tick", count), &count, |b, &count| {
+            let mut app = build_app(count, None, false);
+            b.iter(|| app.update());
+        });
+        group.bench_with_input(
+            BenchmarkId::new("tick_reduced_probes", count),
+            &count,
+            |b, &count| {
+                let mut app = build_app(count, Some(4), false);
+                b.iter(|| app.update());
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("tick_crowd", count), &count, |b, &count| {
+            let mut app = build_app(count, None, true);
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, many_controllers);
+criterion_main!(benches);
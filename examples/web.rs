@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_locomotion::prelude::*;
+
+// The test gym in a browser canvas. Build with:
+//
+//   cargo build --release --example web --target wasm32-unknown-unknown --features test-gym
+//   wasm-bindgen --target web --out-dir web --out-name web --no-typescript \
+//       target/wasm32-unknown-unknown/release/examples/web.wasm
+//
+// then serve the `web` directory. Click the canvas to grab the cursor.
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "FPS Character Controller".into(),
+                // Render into the page's canvas, sized by its parent
+                canvas: Some("#bevy".into()),
+                fit_canvas_to_parent: true,
+                // Keep Space, Ctrl and the arrow keys from scrolling the page
+                prevent_default_event_handling: true,
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins((BevyLocomotionPlugin, TestGymPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    spawn_player(&mut commands, PlayerConfig::default(), Vec3::new(0.0, 2.0, 0.0));
+}
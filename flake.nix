@@ -20,6 +20,7 @@
 
         rustToolchain = pkgs.rust-bin.stable.latest.default.override {
           extensions = [ "rust-src" "rust-analyzer" ];
+          targets = [ "wasm32-unknown-unknown" ];
         };
 
         # Runtime libraries needed by Bevy
@@ -48,6 +49,7 @@
           pkg-config
           clang
           mold
+          wasm-bindgen-cli
         ];
 
       in
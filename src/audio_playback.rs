@@ -0,0 +1,187 @@
+use bevy::audio::{PlaybackMode, Volume};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::player::{
+    LocomotionDeterminism, PlayerAudioMessage, PlayerSound, PlayerSoundKind, SurfaceMaterial,
+};
+
+/// Sound to play for a `PlayerSoundKind`, with optional variation
+#[derive(Clone, Debug)]
+pub struct SoundClip {
+    /// Variants, one picked at random each time
+    pub handles: Vec<Handle<AudioSource>>,
+    /// Linear volume before speed and impact scaling
+    pub volume: f32,
+    /// Playback speed (also shifts the pitch)
+    pub speed: f32,
+    /// Random volume offset range, `volume ± volume_jitter`
+    pub volume_jitter: f32,
+    /// Random speed offset range, `speed ± speed_jitter`
+    pub speed_jitter: f32,
+    /// Shortest time between two plays for the same player in seconds
+    pub min_interval: f32,
+}
+
+impl SoundClip {
+    pub fn new(handle: Handle<AudioSource>) -> Self {
+        Self::variants([handle])
+    }
+
+    /// Picks one of `handles` at random each time
+    pub fn variants(handles: impl IntoIterator<Item = Handle<AudioSource>>) -> Self {
+        Self {
+            handles: handles.into_iter().collect(),
+            volume: 1.0,
+            speed: 1.0,
+            volume_jitter: 0.0,
+            speed_jitter: 0.0,
+            min_interval: 0.0,
+        }
+    }
+
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn with_jitter(mut self, volume: f32, speed: f32) -> Self {
+        self.volume_jitter = volume;
+        self.speed_jitter = speed;
+        self
+    }
+
+    pub fn with_min_interval(mut self, seconds: f32) -> Self {
+        self.min_interval = seconds;
+        self
+    }
+}
+
+/// Sounds `LocomotionAudioPlugin` plays for each `PlayerAudioMessage`.
+///
+/// Kinds without a clip are silent. A clip for a kind and `SurfaceMaterial`
+/// wins over the plain clip for that kind.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct LocomotionSounds {
+    pub clips: HashMap<PlayerSoundKind, SoundClip>,
+    pub surface_clips: HashMap<(PlayerSoundKind, SurfaceMaterial), SoundClip>,
+    /// Play sounds at the message position; the camera needs a `SpatialListener`
+    pub spatial: bool,
+}
+
+impl LocomotionSounds {
+    pub fn with(mut self, kind: PlayerSoundKind, clip: SoundClip) -> Self {
+        self.clips.insert(kind, clip);
+        self
+    }
+
+    pub fn with_surface(
+        mut self,
+        kind: PlayerSoundKind,
+        material: SurfaceMaterial,
+        clip: SoundClip,
+    ) -> Self {
+        self.surface_clips.insert((kind, material), clip);
+        self
+    }
+
+    /// Clip for `kind` on `material`, falling back to the plain clip
+    pub fn clip(
+        &self,
+        kind: PlayerSoundKind,
+        material: Option<SurfaceMaterial>,
+    ) -> Option<&SoundClip> {
+        material
+            .and_then(|material| self.surface_clips.get(&(kind, material)))
+            .or_else(|| self.clips.get(&kind))
+    }
+}
+
+/// Volume multiplier from how hard or fast the sound was made, and the stance
+fn intensity(sound: PlayerSound) -> f32 {
+    match sound {
+        PlayerSound::Footstep { speed, volume } => (speed / 8.0).clamp(0.3, 1.0) * volume,
+        PlayerSound::Landed { impact_speed } => (impact_speed / 15.0).clamp(0.4, 1.0),
+        _ => 1.0,
+    }
+}
+
+/// Maps random bits to `[-1, 1)`
+fn signed_unit(bits: u64) -> f32 {
+    (bits >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+/// Spawns a self-despawning audio player for each `PlayerAudioMessage`.
+///
+/// `SlideLoop` updates are skipped: a looping scrape needs its own sink to
+/// follow the slide speed. Variants and jitter are rolled from
+/// `LocomotionDeterminism`, so replays sound the same. Cooldown entries past
+/// the longest `min_interval` are dropped, so despawned players don't pile up.
+pub fn play_locomotion_sounds(
+    mut commands: Commands,
+    mut reader: MessageReader<PlayerAudioMessage>,
+    sounds: Res<LocomotionSounds>,
+    determinism: Res<LocomotionDeterminism>,
+    time: Res<Time>,
+    mut last_played: Local<HashMap<(Entity, PlayerSoundKind), f32>>,
+    mut plays: Local<u64>,
+) {
+    let now = time.elapsed_secs();
+    let longest = sounds
+        .clips
+        .values()
+        .chain(sounds.surface_clips.values())
+        .fold(0.0, |longest: f32, clip| longest.max(clip.min_interval));
+    last_played.retain(|_, last| now - *last < longest);
+
+    for msg in reader.read() {
+        let kind = msg.sound.kind();
+        if kind == PlayerSoundKind::SlideLoop {
+            continue;
+        }
+        let Some(clip) = sounds.clip(kind, msg.material) else {
+            continue;
+        };
+        if clip.handles.is_empty() {
+            continue;
+        }
+        let key = (msg.player, kind);
+        if last_played.get(&key).is_some_and(|&last| now - last < clip.min_interval) {
+            continue;
+        }
+        last_played.insert(key, now);
+
+        *plays += 1;
+        let roll = |salt: u64| determinism.hash(msg.player, *plays ^ (salt << 56));
+        let handle = &clip.handles[roll(0) as usize % clip.handles.len()];
+        let volume = clip.volume + clip.volume_jitter * signed_unit(roll(1));
+        let speed = clip.speed + clip.speed_jitter * signed_unit(roll(2));
+
+        commands.spawn((
+            AudioPlayer::new(handle.clone()),
+            PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::Linear(volume.max(0.0) * intensity(msg.sound)),
+                speed: speed.max(0.01),
+                spatial: sounds.spatial,
+                ..default()
+            },
+            Transform::from_translation(msg.position),
+        ));
+    }
+}
+
+/// Plays `LocomotionSounds` for the controller's audio messages
+pub struct LocomotionAudioPlugin;
+
+impl Plugin for LocomotionAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LocomotionSounds>()
+            .add_systems(Update, play_locomotion_sounds);
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+
+use super::{CameraConfig, CameraPitch, CameraRigOf, CameraYaw, PitchAngle};
+use crate::player::{LookInput, LookStickInput, Player};
+
+/// Marks an entity the aim assist may lock onto
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AimAssistTarget {
+    /// Radius of the target around its origin in meters
+    pub radius: f32,
+}
+
+impl Default for AimAssistTarget {
+    fn default() -> Self {
+        Self { radius: 0.5 }
+    }
+}
+
+/// Gamepad aim assist settings and state (lives on the pitch entity).
+///
+/// Friction slows look input while the reticle is near an `AimAssistTarget`;
+/// magnetism pulls the aim toward it while the player is looking around.
+/// Toggle `enabled` from your own input-device detection.
+#[derive(Component, Clone, Debug)]
+pub struct AimAssist {
+    pub enabled: bool,
+    /// Cone around a target's edge where assist is active (radians)
+    pub max_angle: f32,
+    /// Targets beyond this distance are ignored (m)
+    pub max_distance: f32,
+    /// Look sensitivity reduction at full strength (0.0 = none, 1.0 = stop)
+    pub friction: f32,
+    /// How quickly the aim is pulled toward the target at full strength (1/s)
+    pub magnetism: f32,
+    /// Current assist strength (0.0 = no target, 1.0 = reticle on target)
+    pub strength: f32,
+    /// Aim error toward the current target in radians (x = yaw left, y = pitch up)
+    pub target_error: Vec2,
+    /// Current target, if any
+    pub target: Option<Entity>,
+}
+
+impl Default for AimAssist {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_angle: 6.0_f32.to_radians(),
+            max_distance: 50.0,
+            friction: 0.4,
+            magnetism: 3.0,
+            strength: 0.0,
+            target_error: Vec2::ZERO,
+            target: None,
+        }
+    }
+}
+
+impl AimAssist {
+    /// Look sensitivity multiplier from friction
+    pub fn sensitivity_scale(&self) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        1.0 - self.friction.clamp(0.0, 1.0) * self.strength
+    }
+}
+
+/// Picks the aim assist target closest to the reticle.
+/// Runs before mouse look so friction applies to this frame's input.
+pub fn update_aim_assist(
+    mut pitch_query: Query<(&mut AimAssist, &GlobalTransform), With<CameraPitch>>,
+    target_query: Query<(Entity, &AimAssistTarget, &GlobalTransform)>,
+) {
+    for (mut assist, pitch_global) in &mut pitch_query {
+        assist.strength = 0.0;
+        assist.target_error = Vec2::ZERO;
+        assist.target = None;
+
+        if !assist.enabled || assist.max_angle <= 0.0 {
+            continue;
+        }
+
+        let eye = pitch_global.translation();
+        let forward = pitch_global.forward().as_vec3();
+
+        for (entity, target, target_global) in &target_query {
+            let to_target = target_global.translation() - eye;
+            let distance = to_target.length();
+            if distance <= f32::EPSILON || distance > assist.max_distance {
+                continue;
+            }
+
+            let direction = to_target / distance;
+            let angle = forward.dot(direction).clamp(-1.0, 1.0).acos();
+            let angular_radius = (target.radius / distance).atan();
+            let off_edge = (angle - angular_radius).max(0.0);
+            let strength = 1.0 - off_edge / assist.max_angle;
+            if strength <= assist.strength {
+                continue;
+            }
+
+            let yaw_error = (-direction.x).atan2(-direction.z) - (-forward.x).atan2(-forward.z);
+            let yaw_error = (yaw_error + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+                - std::f32::consts::PI;
+            let pitch_error = direction.y.asin() - forward.y.asin();
+
+            assist.strength = strength;
+            assist.target_error = Vec2::new(yaw_error, pitch_error);
+            assist.target = Some(entity);
+        }
+    }
+}
+
+/// Pulls the aim toward the current target while the player is looking around.
+/// Runs after mouse look so magnetism composes with player input.
+pub fn apply_aim_magnetism(
+    player_query: Query<(&LookInput, Option<&LookStickInput>), With<Player>>,
+    mut pitch_query: Query<
+        (&mut PitchAngle, &AimAssist, &CameraConfig, &CameraRigOf, &ChildOf),
+        With<CameraPitch>,
+    >,
+    mut yaw_query: Query<&mut Transform, With<CameraYaw>>,
+    time: Res<Time>,
+) {
+    for (mut pitch_angle, assist, config, rig_of, child_of) in &mut pitch_query {
+        if !assist.enabled || assist.strength <= 0.0 {
+            continue;
+        }
+        let Ok((look_input, look_stick)) = player_query.get(rig_of.0) else {
+            continue;
+        };
+        if look_input.0 == Vec2::ZERO && look_stick.is_none_or(|stick| stick.0 == Vec2::ZERO) {
+            continue;
+        }
+
+        let alpha = (1.0 - (-assist.magnetism * time.delta_secs()).exp()) * assist.strength;
+        let pull = assist.target_error * alpha;
+
+        pitch_angle.0 = (pitch_angle.0 + pull.y).clamp(config.min_pitch, config.max_pitch);
+        if let Ok(mut yaw_transform) = yaw_query.get_mut(child_of.parent()) {
+            yaw_transform.rotate_y(pull.x);
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use std::f32::consts::{PI, TAU};
+
+use bevy::prelude::*;
+
+use super::{CameraRig, CameraRigOf, CameraYaw, FpsCamera};
+use crate::player::{
+    Crouching, LedgeClimbing, LedgeGrabbing, MovementBasis, OnLadder, Player, PlayerConfig,
+    PlayerVelocity, Sliding,
+};
+
+/// Parent for a character mesh, spawned as a child of the player.
+///
+/// Sits at the player's feet and turns toward the view or movement direction,
+/// so the mesh never has to know about the camera rig. Spawn the mesh or scene
+/// as a child of this entity, facing `-Z`.
+#[derive(Component, Clone, Debug)]
+#[require(Transform, Visibility)]
+pub struct BodyAnchor {
+    /// Face the movement direction while moving instead of the view
+    pub face_movement: bool,
+    /// Yaw stiffness for turning the body (0.0 = snap)
+    pub turn_speed: f32,
+    /// View yaw away from the body that starts a turn in place while standing
+    /// still (radians; 0.0 keeps the body locked to the view)
+    pub turn_in_place_threshold: f32,
+    /// Extra height offset while crouching in meters
+    pub crouch_offset: f32,
+    /// Extra height offset while sliding in meters
+    pub slide_offset: f32,
+    /// Backward lean while sliding (radians)
+    pub slide_lean: f32,
+    /// Stiffness for height and lean changes (0.0 = snap)
+    pub pose_speed: f32,
+    /// Hides `FirstPersonHidden` parts of the body
+    pub first_person: bool,
+    /// Current body yaw in world space (radians)
+    pub yaw: f32,
+    /// Whether a turn in place is in progress
+    pub turning: bool,
+    /// Current height of the anchor relative to the player's center in meters
+    pub height: f32,
+    /// Current lean (radians)
+    pub lean: f32,
+}
+
+impl Default for BodyAnchor {
+    fn default() -> Self {
+        Self {
+            face_movement: false,
+            turn_speed: 0.0,
+            turn_in_place_threshold: 0.0,
+            crouch_offset: 0.0,
+            slide_offset: -0.1,
+            slide_lean: 0.35,
+            pose_speed: 12.0,
+            first_person: true,
+            yaw: 0.0,
+            turning: false,
+            height: 0.0,
+            lean: 0.0,
+        }
+    }
+}
+
+impl BodyAnchor {
+    /// Body that faces where it moves and turns in place once the view is
+    /// more than 70° away, with nothing hidden
+    pub fn third_person() -> Self {
+        Self {
+            face_movement: true,
+            turn_speed: 10.0,
+            turn_in_place_threshold: 70.0_f32.to_radians(),
+            first_person: false,
+            ..default()
+        }
+    }
+}
+
+/// Parent for first-person arms and legs, spawned as a child of the `CameraYaw`
+/// entity.
+///
+/// Turns with the view but not its pitch, and sits at the player's feet so a
+/// full-body mesh lines up with the capsule. Spawn the mesh as a child facing
+/// `-Z`, and drive its walk cycle from `LocomotionAnimState::gait_phase` to
+/// keep it in step with head bob and footsteps.
+#[derive(Component, Clone, Debug)]
+#[require(Transform, Visibility)]
+pub struct FirstPersonAnchor {
+    /// Offset from the feet while standing
+    pub offset: Vec3,
+    /// Extra offset while crouching
+    pub crouch_offset: Vec3,
+    /// Extra offset while sliding
+    pub slide_offset: Vec3,
+    /// Extra offset while hanging from or climbing a ledge
+    pub ledge_offset: Vec3,
+    /// Stiffness for pose changes (0.0 = snap)
+    pub pose_speed: f32,
+    /// Near plane set on the rig's camera so the body isn't clipped
+    /// (`None` leaves the projection alone)
+    pub near_clip: Option<f32>,
+    /// Current blended offset from the player's center
+    pub current: Vec3,
+}
+
+impl Default for FirstPersonAnchor {
+    fn default() -> Self {
+        Self {
+            offset: Vec3::ZERO,
+            crouch_offset: Vec3::ZERO,
+            slide_offset: Vec3::new(0.0, -0.1, -0.2),
+            ledge_offset: Vec3::new(0.0, -0.2, 0.15),
+            pose_speed: 12.0,
+            near_clip: Some(0.02),
+            current: Vec3::ZERO,
+        }
+    }
+}
+
+/// Hides a body part (typically the head) while its `BodyAnchor` is in first person.
+///
+/// Meshes are hidden through `Visibility`. Anything else is treated as a bone
+/// and scaled to zero, collapsing the skinned vertices it drives; its scale is
+/// reset to one when leaving first person.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct FirstPersonHidden;
+
+/// Yaw that turns `-Z` toward `direction`
+fn yaw_toward(direction: Vec3) -> f32 {
+    (-direction.x).atan2(-direction.z)
+}
+
+/// Shortest signed angle from `from` to `to`
+fn yaw_delta(from: f32, to: f32) -> f32 {
+    (to - from + PI).rem_euclid(TAU) - PI
+}
+
+/// Frame-rate independent blend factor for a stiffness (0.0 = snap)
+fn blend(stiffness: f32, dt: f32) -> f32 {
+    if stiffness > 0.0 {
+        1.0 - (-stiffness * dt).exp()
+    } else {
+        1.0
+    }
+}
+
+/// Places body anchors at the player's feet and turns them.
+///
+/// Runs in `PostUpdate` after the rig has followed the interpolated body.
+/// Ledges and ladders turn the body to face the wall.
+#[allow(clippy::type_complexity)]
+pub fn sync_body_anchor(
+    player_query: Query<
+        (
+            &Transform,
+            &PlayerConfig,
+            (&PlayerVelocity, &MovementBasis),
+            Option<&CameraRig>,
+            (Has<Crouching>, Has<Sliding>),
+            (Option<&LedgeGrabbing>, Option<&OnLadder>),
+        ),
+        With<Player>,
+    >,
+    yaw_query: Query<&Transform, (With<CameraYaw>, Without<Player>, Without<BodyAnchor>)>,
+    mut anchor_query: Query<
+        (&mut Transform, &mut BodyAnchor, &ChildOf),
+        (Without<Player>, Without<CameraYaw>),
+    >,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut anchor, child_of) in &mut anchor_query {
+        let Ok((
+            player_transform,
+            config,
+            (velocity, basis),
+            rig,
+            (crouching, sliding),
+            (ledge, ladder),
+        )) = player_query.get(child_of.parent())
+        else {
+            continue;
+        };
+
+        // The rig has this frame's look; headless players only have their basis
+        let view_yaw = rig
+            .and_then(|rig| yaw_query.iter_many(rig.entities()).next())
+            .map_or(basis.0, |yaw| yaw.rotation.to_euler(EulerRot::YXZ).0);
+        let planar = Vec3::new(velocity.x, 0.0, velocity.z);
+        let moving = planar.length_squared() > 0.25;
+
+        let wall = ladder
+            .map(|ladder| ladder.outward_normal)
+            .or(ledge.map(|ledge| ledge.wall_normal));
+        let target = if let Some(normal) = wall {
+            anchor.turning = false;
+            yaw_toward(-normal)
+        } else if moving {
+            anchor.turning = false;
+            if anchor.face_movement {
+                yaw_toward(planar)
+            } else {
+                view_yaw
+            }
+        } else {
+            // Feet stay planted until the view strays far enough
+            let offset = yaw_delta(anchor.yaw, view_yaw).abs();
+            if offset > anchor.turn_in_place_threshold {
+                anchor.turning = true;
+            } else if offset < 0.05 {
+                anchor.turning = false;
+            }
+            if anchor.turning {
+                view_yaw
+            } else {
+                anchor.yaw
+            }
+        };
+        let step = yaw_delta(anchor.yaw, target) * blend(anchor.turn_speed, dt);
+        anchor.yaw = (anchor.yaw + step).rem_euclid(TAU);
+
+        let offset = if sliding {
+            anchor.slide_offset
+        } else if crouching {
+            anchor.crouch_offset
+        } else {
+            0.0
+        };
+        let target_height = -config.collider.height(crouching || sliding) / 2.0 + offset;
+        let target_lean = if sliding { anchor.slide_lean } else { 0.0 };
+        let alpha = blend(anchor.pose_speed, dt);
+        anchor.height += (target_height - anchor.height) * alpha;
+        anchor.lean += (target_lean - anchor.lean) * alpha;
+
+        let world_rotation = Quat::from_rotation_y(anchor.yaw) * Quat::from_rotation_x(anchor.lean);
+        transform.translation = Vec3::Y * anchor.height;
+        transform.rotation = player_transform.rotation.inverse() * world_rotation;
+    }
+}
+
+/// Poses first-person anchors under the yaw entity and applies their near clip.
+///
+/// Runs in `PostUpdate` after the rig has followed the interpolated body.
+#[allow(clippy::type_complexity)]
+pub fn sync_first_person_anchor(
+    player_query: Query<
+        (&PlayerConfig, Has<Crouching>, Has<Sliding>, Has<LedgeGrabbing>, Has<LedgeClimbing>),
+        With<Player>,
+    >,
+    yaw_query: Query<&CameraRigOf, With<CameraYaw>>,
+    rig_query: Query<&CameraRig>,
+    mut anchor_query: Query<(&mut Transform, &mut FirstPersonAnchor, &ChildOf)>,
+    mut projection_query: Query<&mut Projection, With<FpsCamera>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut anchor, child_of) in &mut anchor_query {
+        let Ok(rig_of) = yaw_query.get(child_of.parent()) else {
+            continue;
+        };
+        let Ok((config, crouching, sliding, grabbing, climbing)) = player_query.get(rig_of.0)
+        else {
+            continue;
+        };
+
+        let pose = if grabbing || climbing {
+            anchor.ledge_offset
+        } else if sliding {
+            anchor.slide_offset
+        } else if crouching {
+            anchor.crouch_offset
+        } else {
+            Vec3::ZERO
+        };
+        let feet = -config.collider.height(crouching || sliding) / 2.0;
+        let target = Vec3::Y * feet + anchor.offset + pose;
+        let step = (target - anchor.current) * blend(anchor.pose_speed, dt);
+        anchor.current += step;
+        transform.translation = anchor.current;
+        transform.rotation = Quat::IDENTITY;
+
+        let (Some(near), Ok(rig)) = (anchor.near_clip, rig_query.get(rig_of.0)) else {
+            continue;
+        };
+        let mut cameras = projection_query.iter_many_mut(rig.entities());
+        while let Some(mut projection) = cameras.fetch_next() {
+            let Projection::Perspective(perspective) = &*projection else {
+                continue;
+            };
+            if perspective.near != near {
+                let perspective = PerspectiveProjection {
+                    near,
+                    ..perspective.clone()
+                };
+                *projection = Projection::Perspective(perspective);
+            }
+        }
+    }
+}
+
+/// Hides or restores `FirstPersonHidden` parts under each body anchor.
+///
+/// Runs after animation so the collapsed bone scale is what gets rendered.
+#[allow(clippy::type_complexity)]
+pub fn hide_first_person_parts(
+    anchor_query: Query<(Entity, &BodyAnchor)>,
+    children_query: Query<&Children>,
+    mut part_query: Query<
+        (&mut Transform, Option<&mut Visibility>, Has<Mesh3d>),
+        (With<FirstPersonHidden>, Without<BodyAnchor>),
+    >,
+) {
+    for (entity, anchor) in &anchor_query {
+        for part in children_query.iter_descendants(entity) {
+            let Ok((mut transform, visibility, is_mesh)) = part_query.get_mut(part) else {
+                continue;
+            };
+            match visibility {
+                Some(mut visibility) if is_mesh => {
+                    let target = if anchor.first_person {
+                        Visibility::Hidden
+                    } else {
+                        Visibility::Inherited
+                    };
+                    visibility.set_if_neq(target);
+                }
+                _ => {
+                    let scale = if anchor.first_person {
+                        Vec3::ZERO
+                    } else {
+                        Vec3::ONE
+                    };
+                    if anchor.first_person || transform.scale == Vec3::ZERO {
+                        transform.scale = scale;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+use super::FpsCamera;
+
+/// Phase of a cinematic camera override
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CinematicPhase {
+    /// Blending from the player view toward the target
+    BlendingIn,
+    /// Fully at the target until `release` is called
+    Holding,
+    /// Blending from the target back to the player view
+    BlendingOut,
+}
+
+/// Takes camera control away from the player for cutscenes and scripted moments.
+///
+/// Insert on the `FpsCamera` entity. The camera blends to `target` (world space),
+/// holds there, and blends back after `release`; the component removes itself
+/// when the blend out finishes. While `lock_look` is set, player look input is
+/// ignored.
+#[derive(Component, Clone, Debug)]
+pub struct CinematicOverride {
+    /// World-space camera transform to blend to
+    pub target: Transform,
+    /// Blend-in duration in seconds
+    pub blend_in: f32,
+    /// Blend-out duration in seconds
+    pub blend_out: f32,
+    /// Ignore player look input while the override is active
+    pub lock_look: bool,
+    /// Current phase
+    pub phase: CinematicPhase,
+    /// Seconds spent in the current phase
+    pub elapsed: f32,
+}
+
+impl CinematicOverride {
+    pub fn new(target: Transform, blend_in: f32) -> Self {
+        Self {
+            target,
+            blend_in,
+            blend_out: blend_in,
+            lock_look: true,
+            phase: CinematicPhase::BlendingIn,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn with_blend_out(mut self, blend_out: f32) -> Self {
+        self.blend_out = blend_out;
+        self
+    }
+
+    pub fn with_look_lock(mut self, lock_look: bool) -> Self {
+        self.lock_look = lock_look;
+        self
+    }
+
+    /// Starts blending back to the player view
+    pub fn release(&mut self) {
+        if self.phase != CinematicPhase::BlendingOut {
+            // Start the blend out from wherever the blend in currently is
+            let weight = self.weight();
+            self.phase = CinematicPhase::BlendingOut;
+            self.elapsed = (1.0 - weight) * self.blend_out;
+        }
+    }
+
+    /// Blend weight toward the target (0.0 = player view, 1.0 = target)
+    pub fn weight(&self) -> f32 {
+        let smooth = |t: f32| {
+            let t = t.clamp(0.0, 1.0);
+            t * t * (3.0 - 2.0 * t)
+        };
+        match self.phase {
+            CinematicPhase::BlendingIn if self.blend_in > 0.0 => {
+                smooth(self.elapsed / self.blend_in)
+            }
+            CinematicPhase::BlendingIn | CinematicPhase::Holding => 1.0,
+            CinematicPhase::BlendingOut if self.blend_out > 0.0 => {
+                1.0 - smooth(self.elapsed / self.blend_out)
+            }
+            CinematicPhase::BlendingOut => 0.0,
+        }
+    }
+}
+
+/// Emitted when a cinematic override finishes a blend
+#[derive(Message, Clone, Debug)]
+pub enum CinematicMessage {
+    /// The camera reached the override target
+    BlendInFinished { camera: Entity },
+    /// The camera is back under player control
+    BlendOutFinished { camera: Entity },
+}
+
+/// Advances cinematic overrides and blends the camera toward their targets.
+///
+/// Runs in `PostUpdate` after the rig has followed the player, so the blend is
+/// computed against this frame's rig transform.
+pub fn apply_cinematic_override(
+    mut commands: Commands,
+    mut camera_query: Query<
+        (Entity, &mut Transform, &mut CinematicOverride, &ChildOf),
+        With<FpsCamera>,
+    >,
+    rig_query: Query<(&Transform, &GlobalTransform, Option<&ChildOf>), Without<FpsCamera>>,
+    mut writer: MessageWriter<CinematicMessage>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut transform, mut cinematic, child_of) in &mut camera_query {
+        cinematic.elapsed += dt;
+
+        match cinematic.phase {
+            CinematicPhase::BlendingIn if cinematic.elapsed >= cinematic.blend_in => {
+                cinematic.phase = CinematicPhase::Holding;
+                cinematic.elapsed = 0.0;
+                writer.write(CinematicMessage::BlendInFinished { camera: entity });
+            }
+            CinematicPhase::BlendingOut if cinematic.elapsed >= cinematic.blend_out => {
+                commands.entity(entity).remove::<CinematicOverride>();
+                writer.write(CinematicMessage::BlendOutFinished { camera: entity });
+                continue;
+            }
+            _ => {}
+        }
+
+        // Rebuild the parent's world transform from the rig hierarchy
+        // (transform propagation has not run yet this frame)
+        let Ok((pitch_transform, _, pitch_parent)) = rig_query.get(child_of.parent()) else {
+            continue;
+        };
+        let parent_global = match pitch_parent.and_then(|p| rig_query.get(p.parent()).ok()) {
+            Some((yaw_transform, _, yaw_parent)) => {
+                // Mounted rigs hang off the mount, already placed this frame
+                let yaw_global = match yaw_parent.and_then(|p| rig_query.get(p.parent()).ok()) {
+                    Some((_, mount_global, _)) => mount_global.mul_transform(*yaw_transform),
+                    None => GlobalTransform::from(*yaw_transform),
+                };
+                yaw_global.mul_transform(*pitch_transform)
+            }
+            None => GlobalTransform::from(*pitch_transform),
+        };
+
+        let target_local = GlobalTransform::from(cinematic.target).reparented_to(&parent_global);
+        let weight = cinematic.weight();
+
+        transform.translation = transform.translation.lerp(target_local.translation, weight);
+        transform.rotation = transform.rotation.slerp(target_local.rotation, weight);
+    }
+}
@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, CursorOptions, CursorMoved, PrimaryWindow, WindowFocused};
+
+/// Browsers only grant pointer lock from a user gesture, and drop it on their
+/// own when Escape is pressed
+const WEB: bool = cfg!(target_arch = "wasm32");
+
+/// Seconds a web pointer lock request gets to take effect before a moving
+/// cursor counts as the lock being dropped
+const WEB_LOCK_GRACE: f32 = 0.5;
+
+/// Cursor grab behavior for the primary window.
+///
+/// Set `enabled` to false to manage the cursor entirely from game code with
+/// `grab_cursor` / `release_cursor`.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CursorGrabConfig {
+    /// Whether the plugin grabs and releases the cursor at all
+    pub enabled: bool,
+    /// Grab the cursor when the app starts; on the web, at the first click
+    pub grab_on_start: bool,
+    /// Key that releases the cursor
+    pub release_key: Option<KeyCode>,
+    /// Mouse button that grabs the cursor while it is released
+    pub grab_button: Option<MouseButton>,
+    /// Grab the cursor when the window gains focus
+    pub grab_on_focus: bool,
+}
+
+impl Default for CursorGrabConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            grab_on_start: true,
+            release_key: Some(KeyCode::Escape),
+            grab_button: Some(MouseButton::Left),
+            grab_on_focus: false,
+        }
+    }
+}
+
+/// Locks and hides the cursor
+pub fn grab_cursor(cursor: &mut CursorOptions) {
+    cursor.grab_mode = CursorGrabMode::Locked;
+    cursor.visible = false;
+}
+
+/// Frees and shows the cursor
+pub fn release_cursor(cursor: &mut CursorOptions) {
+    cursor.grab_mode = CursorGrabMode::None;
+    cursor.visible = true;
+}
+
+/// Grabs the cursor on startup if configured.
+///
+/// Skipped on the web, where the request would fail silently without a user
+/// gesture; `update_cursor_grab` grabs at the first click instead.
+pub fn grab_cursor_on_start(
+    config: Res<CursorGrabConfig>,
+    mut cursor_query: Query<&mut CursorOptions, With<PrimaryWindow>>,
+) {
+    if !config.enabled || !config.grab_on_start || WEB {
+        return;
+    }
+    if let Ok(mut cursor) = cursor_query.single_mut() {
+        grab_cursor(&mut cursor);
+    }
+}
+
+/// Grabs and releases the cursor from the configured keys and focus changes.
+///
+/// On the web, grabs only happen from clicks, and a lock the browser dropped
+/// on its own is noticed from the cursor moving again (the browser freezes its
+/// position while locked), so the next click grabs it back.
+pub fn update_cursor_grab(
+    config: Res<CursorGrabConfig>,
+    (keyboard, mouse): (Res<ButtonInput<KeyCode>>, Res<ButtonInput<MouseButton>>),
+    (mut focus_reader, mut moved_reader): (
+        MessageReader<WindowFocused>,
+        MessageReader<CursorMoved>,
+    ),
+    mut cursor_query: Query<(Entity, &mut CursorOptions), With<PrimaryWindow>>,
+    mut clicked: Local<bool>,
+    mut locked_time: Local<f32>,
+    time: Res<Time>,
+) {
+    let focus = focus_reader.read().last().cloned();
+    let moved: Vec<Entity> = moved_reader
+        .read()
+        .filter(|moved| moved.delta.is_some_and(|delta| delta != Vec2::ZERO))
+        .map(|moved| moved.window)
+        .collect();
+    if !config.enabled {
+        return;
+    }
+    let Ok((window, mut cursor)) = cursor_query.single_mut() else {
+        return;
+    };
+
+    if cursor.grab_mode == CursorGrabMode::None {
+        *locked_time = 0.0;
+    } else {
+        *locked_time += time.delta_secs();
+    }
+    if WEB && moved.contains(&window) && *locked_time > WEB_LOCK_GRACE {
+        release_cursor(&mut cursor);
+    }
+
+    if let Some(focus) = focus.filter(|focus| focus.window == window) {
+        if !focus.focused {
+            release_cursor(&mut cursor);
+        } else if config.grab_on_focus && !WEB {
+            grab_cursor(&mut cursor);
+        }
+    }
+
+    let first_click = WEB && !*clicked && mouse.get_just_pressed().next().is_some();
+    *clicked |= first_click;
+    let grab_pressed = config.grab_button.is_some_and(|button| mouse.just_pressed(button));
+    let unlocked = cursor.grab_mode == CursorGrabMode::None;
+
+    if config.release_key.is_some_and(|key| keyboard.just_pressed(key)) {
+        release_cursor(&mut cursor);
+    } else if (first_click && config.grab_on_start) || (grab_pressed && unlocked) {
+        grab_cursor(&mut cursor);
+    }
+}
@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+
+use super::{CameraPitch, CameraRig, CameraYaw, EyeHeight, PitchAngle};
+use crate::player::{Player, PlayerConfig};
+
+/// How the camera behaves after the player dies
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeathCameraMode {
+    /// Drop to the ground and settle, rolled onto one side
+    #[default]
+    Fall,
+    /// Slowly orbit the body
+    Orbit,
+}
+
+/// Death camera settings
+#[derive(Resource, Clone, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeathCameraConfig {
+    pub mode: DeathCameraMode,
+    /// Time to fall from eye height to the ground in seconds
+    pub fall_duration: f32,
+    /// Height above the feet the camera settles at in meters
+    pub ground_height: f32,
+    /// Camera roll once settled in radians
+    pub fall_roll: f32,
+    /// Orbit distance from the body in meters
+    pub orbit_radius: f32,
+    /// Orbit height above the body center in meters
+    pub orbit_height: f32,
+    /// Orbit angular speed in radians per second
+    pub orbit_speed: f32,
+}
+
+impl Default for DeathCameraConfig {
+    fn default() -> Self {
+        Self {
+            mode: DeathCameraMode::Fall,
+            fall_duration: 0.6,
+            ground_height: 0.2,
+            fall_roll: 70.0_f32.to_radians(),
+            orbit_radius: 3.0,
+            orbit_height: 1.5,
+            orbit_speed: 0.3,
+        }
+    }
+}
+
+/// Death and respawn triggers for the camera
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeathCameraMessage {
+    /// Detach the camera from the eye and play the death camera
+    Died { player: Entity },
+    /// Re-attach the normal rig
+    Respawned { player: Entity },
+}
+
+/// Present on a player while the death camera is active.
+///
+/// The rig stops following the body and ignores look input until
+/// `DeathCameraMessage::Respawned` removes it.
+#[derive(Component, Clone, Debug)]
+#[component(storage = "SparseSet")]
+pub struct DeathCamera {
+    pub mode: DeathCameraMode,
+    /// Seconds since death
+    pub elapsed: f32,
+    /// Yaw entity of the rig
+    pub yaw_entity: Entity,
+    start: Vec3,
+    yaw_rotation: Quat,
+}
+
+/// Starts and stops the death camera in response to `DeathCameraMessage`
+pub fn handle_death_camera_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<DeathCameraMessage>,
+    config: Res<DeathCameraConfig>,
+    death_query: Query<&DeathCamera>,
+    rig_query: Query<&CameraRig>,
+    mut yaw_query: Query<(Entity, &mut Transform), With<CameraYaw>>,
+) {
+    for msg in reader.read() {
+        match *msg {
+            DeathCameraMessage::Died { player } => {
+                if death_query.contains(player) {
+                    continue;
+                }
+                let Ok(rig) = rig_query.get(player) else {
+                    continue;
+                };
+                let Some((yaw_entity, yaw_transform)) = yaw_query.iter_many(rig.entities()).next()
+                else {
+                    continue;
+                };
+
+                commands.entity(player).insert(DeathCamera {
+                    mode: config.mode,
+                    elapsed: 0.0,
+                    yaw_entity,
+                    start: yaw_transform.translation,
+                    yaw_rotation: yaw_transform.rotation,
+                });
+            }
+            DeathCameraMessage::Respawned { player } => {
+                let Ok(death) = death_query.get(player) else {
+                    continue;
+                };
+                if let Ok((_, mut yaw_transform)) = yaw_query.get_mut(death.yaw_entity) {
+                    yaw_transform.rotation = death.yaw_rotation;
+                }
+                commands.entity(player).remove::<DeathCamera>();
+            }
+        }
+    }
+}
+
+/// Drives the rig while the death camera is active.
+///
+/// Runs in `PostUpdate` after the rig sync, overriding the yaw position and
+/// pitch rotation written earlier in the frame.
+#[allow(clippy::type_complexity)]
+pub fn apply_death_camera(
+    mut player_query: Query<(&Transform, &PlayerConfig, &mut DeathCamera), With<Player>>,
+    mut yaw_query: Query<&mut Transform, (With<CameraYaw>, Without<Player>)>,
+    mut pitch_query: Query<
+        (&mut Transform, &EyeHeight, &PitchAngle, &ChildOf),
+        (With<CameraPitch>, Without<CameraYaw>, Without<Player>),
+    >,
+    config: Res<DeathCameraConfig>,
+    time: Res<Time>,
+) {
+    for (player_transform, player_config, mut death) in &mut player_query {
+        death.elapsed += time.delta_secs();
+
+        let Some((mut pitch_transform, eye, pitch_angle, _)) = pitch_query
+            .iter_mut()
+            .find(|(_, _, _, child_of)| child_of.parent() == death.yaw_entity)
+        else {
+            continue;
+        };
+        let Ok(mut yaw_transform) = yaw_query.get_mut(death.yaw_entity) else {
+            continue;
+        };
+
+        let body = player_transform.translation;
+
+        match death.mode {
+            DeathCameraMode::Fall => {
+                let feet = body.y - player_config.collider.stand_height / 2.0;
+                let target = Vec3::new(body.x, feet + config.ground_height - eye.current, body.z);
+
+                let t = if config.fall_duration > 0.0 {
+                    (death.elapsed / config.fall_duration).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                // Accelerate into the ground like a falling head
+                let fall = t * t;
+
+                yaw_transform.translation = death.start.lerp(target, fall);
+                pitch_transform.rotation = Quat::from_rotation_x(pitch_angle.0 * (1.0 - t))
+                    * Quat::from_rotation_z(config.fall_roll * t);
+            }
+            DeathCameraMode::Orbit => {
+                let (start_yaw, _, _) = death.yaw_rotation.to_euler(EulerRot::YXZ);
+                let angle = start_yaw + death.elapsed * config.orbit_speed;
+                // Orbit behind the view direction at death
+                let offset =
+                    Quat::from_rotation_y(angle) * Vec3::new(0.0, 0.0, config.orbit_radius);
+                let eye_position = body + offset + Vec3::Y * config.orbit_height;
+
+                yaw_transform.translation = eye_position - Vec3::Y * eye.current;
+                yaw_transform.rotation = Quat::from_rotation_y(angle);
+
+                let look_down = (-config.orbit_height).atan2(config.orbit_radius);
+                pitch_transform.rotation = Quat::from_rotation_x(look_down);
+            }
+        }
+    }
+}
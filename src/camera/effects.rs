@@ -1,9 +1,12 @@
 use avian3d::prelude::LinearVelocity;
 use bevy::prelude::*;
 
-use crate::player::{Crouching, Grounded, Player, PlayerConfig, PlayerVelocity};
+use crate::player::{
+    player_dt, Crouching, Grounded, LocomotionAnimState, LocomotionDisabled, Player, PlayerConfig,
+    PlayerVelocity, Sliding, TimeDilation,
+};
 
-use super::CameraPitch;
+use super::{CameraConfig, CameraMotion, CameraMounted, CameraPitch, CameraRigOf, HeadTracking};
 
 /// Damped vertical bounce on ledge grab to sell impact weight
 #[derive(Component)]
@@ -32,7 +35,9 @@ pub struct LedgeClimbBob {
 }
 
 /// FPS camera marker with effect settings
-#[derive(Component)]
+#[derive(Component, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FpsCamera {
     /// Base FOV in radians
     pub base_fov: f32,
@@ -54,6 +59,9 @@ pub struct FpsCamera {
     pub head_bob_sway: f32,
     /// Internal head bob phase timer
     pub head_bob_timer: f32,
+    /// Drive head bob from `LocomotionAnimState::gait_phase` instead of
+    /// `head_bob_frequency`, dipping on every footstep
+    pub head_bob_sync_gait: bool,
 }
 
 impl Default for FpsCamera {
@@ -69,27 +77,100 @@ impl Default for FpsCamera {
             head_bob_frequency: 12.0,
             head_bob_sway: 0.01,
             head_bob_timer: 0.0,
+            head_bob_sync_gait: false,
+        }
+    }
+}
+
+impl FpsCamera {
+    fn with_fov(base_degrees: f32, sprint_degrees: f32) -> Self {
+        Self {
+            base_fov: base_degrees.to_radians(),
+            sprint_fov: sprint_degrees.to_radians(),
+            current_fov: base_degrees.to_radians(),
+            ..Self::default()
+        }
+    }
+
+    /// Camera effects matching `PlayerConfig::quake`: wide fixed FOV, no head bob
+    pub fn quake() -> Self {
+        Self {
+            head_bob_amplitude: 0.0,
+            head_bob_sway: 0.0,
+            ..Self::with_fov(100.0, 100.0)
+        }
+    }
+
+    /// Camera effects matching `PlayerConfig::source`: fixed FOV, subtle bob
+    pub fn source() -> Self {
+        Self {
+            head_bob_amplitude: 0.008,
+            head_bob_sway: 0.004,
+            ..Self::with_fov(90.0, 90.0)
+        }
+    }
+
+    /// Camera effects matching `PlayerConfig::modern_military`: sprint FOV kick and strong bob
+    pub fn modern_military() -> Self {
+        Self {
+            head_bob_amplitude: 0.03,
+            head_bob_frequency: 11.0,
+            head_bob_sway: 0.015,
+            ..Self::with_fov(80.0, 92.0)
+        }
+    }
+
+    /// Camera effects matching `PlayerConfig::walking_sim`: narrow FOV, slow gentle bob
+    pub fn walking_sim() -> Self {
+        Self {
+            fov_speed: 3.0,
+            head_bob_amplitude: 0.015,
+            head_bob_frequency: 7.0,
+            head_bob_sway: 0.01,
+            ..Self::with_fov(70.0, 74.0)
+        }
+    }
+
+    /// Camera effects matching `PlayerConfig::realistic`: natural FOV, light bob
+    pub fn realistic() -> Self {
+        Self {
+            head_bob_amplitude: 0.012,
+            head_bob_frequency: 9.0,
+            head_bob_sway: 0.006,
+            ..Self::with_fov(75.0, 78.0)
         }
     }
 }
 
 /// Updates camera FOV based on player speed
+#[allow(clippy::type_complexity)]
 pub fn update_fov(
-    player_query: Query<(&PlayerVelocity, &PlayerConfig), With<Player>>,
-    mut camera_query: Query<(&mut Projection, &mut FpsCamera)>,
+    player_query: Query<
+        (&PlayerVelocity, &PlayerConfig),
+        (
+            With<Player>,
+            Without<CameraMounted>,
+            Without<HeadTracking>,
+            Without<LocomotionDisabled>,
+        ),
+    >,
+    mut camera_query: Query<(&mut Projection, &mut FpsCamera, &CameraRigOf)>,
     time: Res<Time>,
 ) {
-    let Ok((velocity, config)) = player_query.single() else {
-        return;
-    };
+    for (mut projection, mut camera, rig_of) in &mut camera_query {
+        let Ok((velocity, config)) = player_query.get(rig_of.0) else {
+            continue;
+        };
 
-    let horizontal_speed = Vec2::new(velocity.x, velocity.z).length();
+        let horizontal_speed = Vec2::new(velocity.x, velocity.z).length();
 
-    for (mut projection, mut camera) in &mut camera_query {
         // Interpolate FOV between base and sprint based on speed
-        let t = ((horizontal_speed - config.walk_speed)
-            / (config.sprint_speed - config.walk_speed))
-            .clamp(0.0, 1.0);
+        let sprint_range = config.movement.sprint_speed - config.movement.walk_speed;
+        let t = if sprint_range > 0.0 {
+            ((horizontal_speed - config.movement.walk_speed) / sprint_range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
         let target_fov = camera.base_fov + (camera.sprint_fov - camera.base_fov) * t;
 
         let dt = time.delta_secs();
@@ -101,26 +182,42 @@ pub fn update_fov(
     }
 }
 
-/// Applies head bob based on movement speed
+/// Advances head bob based on movement speed, or the gait phase with
+/// `FpsCamera::head_bob_sync_gait`.
+///
+/// The bob is stored in `CameraMotion::bob_offset`; `apply_camera_offsets`
+/// writes it to the camera transform.
+#[allow(clippy::type_complexity)]
 pub fn apply_head_bob(
-    player_query: Query<(&PlayerVelocity, Has<Grounded>), With<Player>>,
-    mut camera_query: Query<(&mut Transform, &mut FpsCamera), With<FpsCamera>>,
+    player_query: Query<
+        (&PlayerVelocity, Option<&LocomotionAnimState>, Has<Grounded>, Has<HeadTracking>),
+        (With<Player>, Without<CameraMounted>, Without<LocomotionDisabled>),
+    >,
+    mut camera_query: Query<(&mut CameraMotion, &mut FpsCamera, &CameraRigOf)>,
     time: Res<Time>,
 ) {
-    let Ok((velocity, grounded)) = player_query.single() else {
-        return;
-    };
-
     let dt = time.delta_secs();
-    let horizontal_speed = Vec3::new(velocity.x, 0.0, velocity.z).length();
 
-    for (mut transform, mut camera) in &mut camera_query {
-        if camera.head_bob_amplitude == 0.0 {
-            return;
+    for (mut motion, mut camera, rig_of) in &mut camera_query {
+        let Ok((velocity, anim, grounded, head_tracked)) = player_query.get(rig_of.0) else {
+            continue;
+        };
+
+        let horizontal_speed = Vec3::new(velocity.x, 0.0, velocity.z).length();
+
+        if camera.head_bob_amplitude == 0.0 || head_tracked {
+            motion.bob_offset = Vec3::ZERO;
+            continue;
         }
 
+        let gait = anim.filter(|_| camera.head_bob_sync_gait);
         let (target_y, target_x) = if grounded && horizontal_speed > 0.5 {
-            camera.head_bob_timer += dt * camera.head_bob_frequency;
+            if let Some(anim) = gait {
+                // Two bobs per gait cycle, bottoming out on each footstep
+                camera.head_bob_timer = (anim.gait_phase * 2.0 - 0.25) * std::f32::consts::TAU;
+            } else {
+                camera.head_bob_timer += dt * camera.head_bob_frequency;
+            }
             // Wrap to avoid precision loss over long sessions
             if camera.head_bob_timer > std::f32::consts::TAU * 2.0 {
                 camera.head_bob_timer -= std::f32::consts::TAU * 2.0;
@@ -136,32 +233,35 @@ pub fn apply_head_bob(
         };
 
         let lerp_speed = 10.0 * dt;
-        transform.translation.y += (target_y - transform.translation.y) * lerp_speed;
-        transform.translation.x += (target_x - transform.translation.x) * lerp_speed;
+        motion.bob_offset.y += (target_y - motion.bob_offset.y) * lerp_speed;
+        motion.bob_offset.x += (target_x - motion.bob_offset.x) * lerp_speed;
     }
 }
 
-/// Tracks previous state for landing detection
-#[derive(Resource, Default)]
+/// Tracks previous player state for landing detection (lives on the `FpsCamera` entity)
+#[derive(Component, Default)]
 pub struct PreviousGroundedState {
     pub was_grounded: bool,
     pub last_vertical_velocity: f32,
 }
 
 /// Applies view punch on landing - scales with impact velocity
+#[allow(clippy::type_complexity)]
 pub fn apply_view_punch(
-    player_query: Query<(&LinearVelocity, Has<Grounded>), With<Player>>,
-    mut camera_query: Query<&mut FpsCamera>,
-    mut prev_state: ResMut<PreviousGroundedState>,
+    player_query: Query<
+        (&LinearVelocity, Has<Grounded>, Has<HeadTracking>),
+        (With<Player>, Without<CameraMounted>, Without<LocomotionDisabled>),
+    >,
+    mut camera_query: Query<(&mut FpsCamera, &mut PreviousGroundedState, &CameraRigOf)>,
     time: Res<Time>,
 ) {
-    let Ok((lin_vel, grounded)) = player_query.single() else {
-        return;
-    };
-
     let dt = time.delta_secs();
 
-    for mut camera in &mut camera_query {
+    for (mut camera, mut prev_state, rig_of) in &mut camera_query {
+        let Ok((lin_vel, grounded, head_tracked)) = player_query.get(rig_of.0) else {
+            continue;
+        };
+
         // Detect landing - was airborne, now grounded
         if grounded && !prev_state.was_grounded {
             // Impact velocity (how fast we were falling)
@@ -184,49 +284,106 @@ pub fn apply_view_punch(
         }
 
         // Decay view punch smoothly - exponential decay for natural feel
-        if camera.view_punch > 0.0005 {
+        if head_tracked {
+            // No screen-space punch while the head pose drives the view
+            camera.view_punch = 0.0;
+        } else if camera.view_punch > 0.0005 {
             camera.view_punch *= 1.0 - (camera.punch_decay_rate * dt);
         } else {
             camera.view_punch = 0.0;
         }
+
+        prev_state.was_grounded = grounded;
+        prev_state.last_vertical_velocity = lin_vel.y;
     }
+}
 
-    prev_state.was_grounded = grounded;
-    prev_state.last_vertical_velocity = lin_vel.y;
+/// Eye height transition state for the pitch entity.
+///
+/// `current` is the base eye height before additive effects (bounce, shuffle bob).
+#[derive(Component, Clone, Debug)]
+pub struct EyeHeight {
+    pub current: f32,
+    pub from: f32,
+    pub target: f32,
+    pub elapsed: f32,
 }
 
-/// Adjusts camera height for crouch
+impl EyeHeight {
+    pub fn new(height: f32) -> Self {
+        Self {
+            current: height,
+            from: height,
+            target: height,
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// Adjusts camera height for crouch and slide using `CameraConfig` timing and easing
+#[allow(clippy::type_complexity)]
 pub fn update_camera_height(
-    player_query: Query<(&PlayerConfig, Has<Crouching>), With<Player>>,
-    mut pitch_query: Query<&mut Transform, With<CameraPitch>>,
+    player_query: Query<
+        (&PlayerConfig, Has<Crouching>, Has<Sliding>),
+        (With<Player>, Without<CameraMounted>),
+    >,
+    mut pitch_query: Query<
+        (&mut Transform, &mut EyeHeight, &CameraConfig, &CameraRigOf),
+        With<CameraPitch>,
+    >,
     time: Res<Time>,
 ) {
-    let Ok((config, crouching)) = player_query.single() else {
-        return;
-    };
-
-    let target_height = if crouching {
-        config.crouch_height / 2.0 - 0.1
-    } else {
-        config.stand_height / 2.0 - 0.1
-    };
-
-    for mut transform in &mut pitch_query {
-        // Smooth transition
-        transform.translation.y +=
-            (target_height - transform.translation.y) * 10.0 * time.delta_secs();
+    for (mut transform, mut eye, camera_config, rig_of) in &mut pitch_query {
+        let Ok((config, crouching, sliding)) = player_query.get(rig_of.0) else {
+            continue;
+        };
+
+        let base_height = config.collider.eye_height(crouching);
+
+        let target_height = if sliding {
+            base_height - camera_config.slide_eye_offset
+        } else {
+            base_height
+        };
+
+        // Restart the transition whenever the target changes
+        if (eye.target - target_height).abs() > f32::EPSILON {
+            eye.from = eye.current;
+            eye.target = target_height;
+            eye.elapsed = 0.0;
+        }
+
+        let duration = camera_config.crouch_transition_time;
+        if duration <= 0.0 {
+            eye.current = eye.target;
+        } else {
+            eye.elapsed += time.delta_secs();
+            let t = eye.elapsed / duration;
+            eye.current = eye.from + (eye.target - eye.from) * camera_config.crouch_easing.ease(t);
+        }
+
+        transform.translation.y = eye.current;
     }
 }
 
 /// Applies a damped vertical bounce to the camera on ledge grab.
-/// Runs after `update_camera_height` so the offset layers on top.
+/// Runs after `update_camera_height` so the offset layers on top. Held while
+/// the player is disabled.
 pub fn apply_ledge_grab_bounce(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Transform, &mut LedgeGrabBounce), With<CameraPitch>>,
+    player_query: Query<Option<&TimeDilation>, (With<Player>, Without<LocomotionDisabled>)>,
+    mut query: Query<
+        (Entity, &mut Transform, &mut LedgeGrabBounce, &CameraRigOf),
+        With<CameraPitch>,
+    >,
     time: Res<Time>,
 ) {
-    for (entity, mut transform, mut bounce) in &mut query {
-        bounce.elapsed += time.delta_secs();
+    for (entity, mut transform, mut bounce, rig_of) in &mut query {
+        // Disabled players hold the effect where it is
+        let dt = player_query
+            .get(rig_of.0)
+            .map_or(0.0, |dilation| player_dt(time.delta_secs(), dilation));
+        bounce.elapsed += dt;
         if bounce.elapsed >= bounce.duration {
             commands.entity(entity).remove::<LedgeGrabBounce>();
             continue;
@@ -248,14 +405,20 @@ pub fn apply_ledge_shuffle_bob(
     }
 }
 
-/// Advances the ledge climb bob timer and removes the component when done
+/// Advances the ledge climb bob timer and removes the component when done.
+/// Held while the player is disabled.
 pub fn apply_ledge_climb_bob(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut LedgeClimbBob), With<CameraPitch>>,
+    player_query: Query<Option<&TimeDilation>, (With<Player>, Without<LocomotionDisabled>)>,
+    mut query: Query<(Entity, &mut LedgeClimbBob, &CameraRigOf), With<CameraPitch>>,
     time: Res<Time>,
 ) {
-    for (entity, mut bob) in &mut query {
-        bob.elapsed += time.delta_secs();
+    for (entity, mut bob, rig_of) in &mut query {
+        // Disabled players hold the effect where it is
+        let dt = player_query
+            .get(rig_of.0)
+            .map_or(0.0, |dilation| player_dt(time.delta_secs(), dilation));
+        bob.elapsed += dt;
         if bob.elapsed >= bob.duration {
             commands.entity(entity).remove::<LedgeClimbBob>();
         }
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+use super::{CameraConfig, CameraPitch, CameraRigOf, CameraYaw, FreelookYaw, PitchAngle};
+use crate::player::{LookInput, LookStickInput, Player};
+
+/// Externally driven head orientation (XR tracking or any other source).
+///
+/// Lives on the player next to `HeadTracking`. The rotation is relative to the
+/// tracking space; its yaw is added to the snap-turn yaw and its pitch drives
+/// `PitchAngle`. Roll is ignored.
+#[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut)]
+pub struct LookPose(pub Quat);
+
+/// Drives look from `LookPose` instead of accumulated mouse deltas.
+///
+/// While present on a player, mouse look is skipped, horizontal `LookInput`
+/// and `LookStickInput` perform snap turns, and head bob, landing punch, sprint FOV, and ledge
+/// camera roll are disabled for comfort.
+#[derive(Component, Clone, Debug)]
+pub struct HeadTracking {
+    /// Yaw rotated per snap turn in radians (0.0 disables snap turning)
+    pub snap_turn_angle: f32,
+    /// Horizontal look input magnitude that triggers a snap turn
+    pub snap_turn_threshold: f32,
+    /// Accumulated snap-turn yaw in radians
+    pub snap_yaw: f32,
+    /// Whether look input has returned below the threshold since the last snap
+    pub snap_ready: bool,
+}
+
+impl Default for HeadTracking {
+    fn default() -> Self {
+        Self {
+            snap_turn_angle: 30.0_f32.to_radians(),
+            snap_turn_threshold: 0.7,
+            snap_yaw: 0.0,
+            snap_ready: true,
+        }
+    }
+}
+
+/// Applies head pose and snap turns to head-tracked camera rigs.
+/// Runs after mouse look so it owns the rig rotation for tracked players.
+#[allow(clippy::type_complexity)]
+pub fn apply_head_tracking(
+    mut player_query: Query<
+        (&LookPose, &LookInput, Option<&LookStickInput>, &mut HeadTracking),
+        With<Player>,
+    >,
+    mut yaw_query: Query<&mut Transform, (With<CameraYaw>, Without<CameraPitch>)>,
+    mut pitch_query: Query<
+        (
+            &mut Transform,
+            &mut PitchAngle,
+            &mut FreelookYaw,
+            &CameraConfig,
+            &CameraRigOf,
+            &ChildOf,
+        ),
+        With<CameraPitch>,
+    >,
+) {
+    for (mut pitch_transform, mut pitch_angle, mut freelook_yaw, config, rig_of, pitch_parent) in
+        &mut pitch_query
+    {
+        let Ok((pose, look_input, look_stick, mut tracking)) = player_query.get_mut(rig_of.0)
+        else {
+            continue;
+        };
+
+        // Snap turn on a threshold crossing, re-arm once the input settles
+        let input = look_input.x + look_stick.map_or(0.0, |stick| stick.x);
+        if tracking.snap_ready && input.abs() >= tracking.snap_turn_threshold {
+            tracking.snap_yaw -= input.signum() * tracking.snap_turn_angle;
+            tracking.snap_ready = false;
+        } else if input.abs() < tracking.snap_turn_threshold * 0.5 {
+            tracking.snap_ready = true;
+        }
+
+        let (head_yaw, head_pitch, _) = pose.0.to_euler(EulerRot::YXZ);
+
+        if let Ok(mut yaw_transform) = yaw_query.get_mut(pitch_parent.parent()) {
+            yaw_transform.rotation = Quat::from_rotation_y(tracking.snap_yaw + head_yaw);
+        }
+
+        pitch_angle.0 = head_pitch.clamp(config.min_pitch, config.max_pitch);
+        freelook_yaw.0 = 0.0;
+        pitch_transform.rotation = Quat::from_rotation_x(pitch_angle.0);
+    }
+}
@@ -1,74 +1,219 @@
 use bevy::prelude::*;
 
-use crate::player::{LookInput, Player};
+use super::{AimAssist, CinematicOverride, DeathCamera, HeadTracking};
+use crate::player::{FreelookInput, LocomotionDisabled, LookInput, LookStickInput, Player, Riding};
 
 /// Marker for the yaw (horizontal rotation) entity
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CameraYaw;
 
 /// Marker for the pitch (vertical rotation) entity
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CameraPitch;
 
+/// Binds a camera rig entity (yaw, pitch, or camera) to the player it belongs to
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[relationship(relationship_target = CameraRig)]
+pub struct CameraRigOf(pub Entity);
+
+/// The camera rig entities of a player, kept in sync with `CameraRigOf`
+#[derive(Component, Debug, Default)]
+#[relationship_target(relationship = CameraRigOf)]
+pub struct CameraRig(Vec<Entity>);
+
+impl CameraRig {
+    /// Yaw, pitch, and camera entities; look one up with `Query::iter_many`
+    pub fn entities(&self) -> &[Entity] {
+        &self.0
+    }
+}
+
+/// Easing curve for camera height transitions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraEasing {
+    /// Exponential approach (fast start, soft landing)
+    #[default]
+    Exponential,
+    /// Constant-rate transition
+    Linear,
+    /// Smoothstep ease-in-out
+    SmoothStep,
+}
+
+impl CameraEasing {
+    /// Maps linear progress `t` (0..=1) onto the easing curve
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            CameraEasing::Exponential => (1.0 - (-5.0 * t).exp()) / (1.0 - (-5.0_f32).exp()),
+            CameraEasing::Linear => t,
+            CameraEasing::SmoothStep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
 /// Camera configuration
-#[derive(Component, Clone)]
+#[derive(Component, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct CameraConfig {
     /// Mouse sensitivity
     pub sensitivity: f32,
+    /// Turn rate at full look stick deflection (radians per second)
+    pub stick_sensitivity: f32,
     /// Maximum pitch angle (looking up)
     pub max_pitch: f32,
     /// Minimum pitch angle (looking down)
     pub min_pitch: f32,
+    /// Maximum view yaw away from the movement direction while freelooking (radians)
+    pub freelook_max_yaw: f32,
+    /// How quickly the view snaps back to the movement direction after freelook
+    pub freelook_return_speed: f32,
+    /// Duration of the crouch/stand eye height transition in seconds
+    pub crouch_transition_time: f32,
+    /// Easing curve for the crouch/stand eye height transition
+    pub crouch_easing: CameraEasing,
+    /// Extra eye height drop while sliding in meters
+    pub slide_eye_offset: f32,
 }
 
 impl Default for CameraConfig {
     fn default() -> Self {
         Self {
             sensitivity: 0.003,
+            stick_sensitivity: 3.0,
             max_pitch: 89.0_f32.to_radians(),
             min_pitch: -89.0_f32.to_radians(),
+            freelook_max_yaw: 110.0_f32.to_radians(),
+            freelook_return_speed: 10.0,
+            crouch_transition_time: 0.3,
+            crouch_easing: CameraEasing::Exponential,
+            slide_eye_offset: 0.1,
         }
     }
 }
 
 /// Current pitch angle in radians
-#[derive(Component, Default, Deref, DerefMut)]
+#[derive(Component, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PitchAngle(pub f32);
 
-/// Applies mouse look rotation to camera
+/// View yaw offset from the movement yaw in radians (non-zero while freelooking)
+#[derive(Component, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreelookYaw(pub f32);
+
+/// Applies mouse and stick look rotation to camera.
+///
+/// Mouse deltas are scaled by `CameraConfig::sensitivity`, stick deflection by
+/// `stick_sensitivity` and the frame time.
+/// Normally horizontal look turns the yaw entity, which is also the movement
+/// basis. While freelook is held, horizontal look only rotates the view within
+/// `CameraConfig::freelook_max_yaw`, and the view eases back on release.
+/// Look input is ignored while a `CinematicOverride` with `lock_look` is active
+/// or the player is disabled (unless riding a `Mount` with `look`),
+/// and scaled down by `AimAssist` friction near targets.
+#[allow(clippy::type_complexity)]
 pub fn apply_mouse_look(
-    player_query: Query<&LookInput, With<Player>>,
+    player_query: Query<
+        (
+            &LookInput,
+            Option<&LookStickInput>,
+            Option<&FreelookInput>,
+            Has<LocomotionDisabled>,
+            Option<&Riding>,
+        ),
+        (
+            With<Player>,
+            Without<DeathCamera>,
+            Without<HeadTracking>,
+        ),
+    >,
     mut yaw_query: Query<&mut Transform, (With<CameraYaw>, Without<CameraPitch>)>,
-    mut pitch_query: Query<(&mut Transform, &mut PitchAngle, &CameraConfig), With<CameraPitch>>,
+    mut pitch_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut PitchAngle,
+            &mut FreelookYaw,
+            &CameraConfig,
+            &CameraRigOf,
+            &ChildOf,
+            Option<&AimAssist>,
+        ),
+        With<CameraPitch>,
+    >,
+    cinematic_query: Query<(&CinematicOverride, &ChildOf)>,
+    time: Res<Time>,
 ) {
-    let Ok(look_input) = player_query.single() else {
-        return;
-    };
+    for (
+        pitch_entity,
+        mut pitch_transform,
+        mut pitch_angle,
+        mut freelook_yaw,
+        config,
+        rig_of,
+        pitch_parent,
+        aim_assist,
+    ) in &mut pitch_query
+    {
+        let Ok((look_input, look_stick, freelook, disabled, riding)) = player_query.get(rig_of.0)
+        else {
+            continue;
+        };
+        // Frozen players don't look around, except riders of mounts that allow it
+        if disabled && !riding.is_some_and(|riding| riding.look) {
+            continue;
+        }
 
-    // Apply yaw (horizontal rotation)
-    if let Ok(mut yaw_transform) = yaw_query.single_mut() {
-        yaw_transform.rotate_y(-look_input.x * 0.003); // Use default sensitivity inline
-    }
+        let locked = cinematic_query
+            .iter()
+            .any(|(cinematic, parent)| cinematic.lock_look && parent.parent() == pitch_entity);
+        if locked {
+            continue;
+        }
 
-    // Apply pitch (vertical rotation)
-    if let Ok((mut pitch_transform, mut pitch_angle, config)) = pitch_query.single_mut() {
-        pitch_angle.0 -= look_input.y * config.sensitivity;
-        pitch_angle.0 = pitch_angle.0.clamp(config.min_pitch, config.max_pitch);
+        // Aim assist friction slows look near targets
+        let friction = aim_assist.map_or(1.0, |a| a.sensitivity_scale());
+        let stick = look_stick.map_or(Vec2::ZERO, |stick| stick.0);
+        let look_delta = (look_input.0 * config.sensitivity
+            + stick * config.stick_sensitivity * time.delta_secs())
+            * friction;
 
-        pitch_transform.rotation = Quat::from_rotation_x(pitch_angle.0);
-    }
-}
+        let yaw_delta = -look_delta.x;
+        let freelooking = freelook.is_some_and(|f| f.0);
 
-/// Syncs the camera yaw position to follow the player
-pub fn sync_camera_to_player(
-    player_query: Query<&Transform, With<Player>>,
-    mut yaw_query: Query<&mut Transform, (With<CameraYaw>, Without<Player>)>,
-) {
-    let Ok(player_transform) = player_query.single() else {
-        return;
-    };
+        if freelooking {
+            // Rotate the view only; movement yaw stays put
+            freelook_yaw.0 = (freelook_yaw.0 + yaw_delta)
+                .clamp(-config.freelook_max_yaw, config.freelook_max_yaw);
+        } else {
+            // Apply yaw (horizontal rotation)
+            if let Ok(mut yaw_transform) = yaw_query.get_mut(pitch_parent.parent()) {
+                yaw_transform.rotate_y(yaw_delta);
+            }
+
+            // Ease the view back toward the movement direction
+            let alpha = 1.0 - (-config.freelook_return_speed * time.delta_secs()).exp();
+            freelook_yaw.0 -= freelook_yaw.0 * alpha;
+            if freelook_yaw.0.abs() < 0.0005 {
+                freelook_yaw.0 = 0.0;
+            }
+        }
+
+        // Apply pitch (vertical rotation)
+        pitch_angle.0 -= look_delta.y;
+        pitch_angle.0 = pitch_angle.0.clamp(config.min_pitch, config.max_pitch);
 
-    if let Ok(mut yaw_transform) = yaw_query.single_mut() {
-        yaw_transform.translation = player_transform.translation;
+        pitch_transform.rotation =
+            Quat::from_rotation_y(freelook_yaw.0) * Quat::from_rotation_x(pitch_angle.0);
     }
 }
@@ -1,9 +1,33 @@
+mod aim_assist;
+mod body;
+mod cinematic;
+mod cursor;
+mod death;
 mod effects;
+mod head_tracking;
 mod look;
+mod motion;
+mod mount;
+mod photo;
 mod plugin;
+mod recoil;
 mod smoothing;
+mod third_person;
+mod viewport;
 
+pub use aim_assist::*;
+pub use body::*;
+pub use cinematic::*;
+pub use cursor::*;
+pub use death::*;
 pub use effects::*;
+pub use head_tracking::*;
 pub use look::*;
-pub use plugin::CameraPlugin;
+pub use motion::*;
+pub use mount::*;
+pub use photo::*;
+pub use plugin::{CameraPlugin, CameraSet};
+pub use recoil::*;
 pub use smoothing::*;
+pub use third_person::*;
+pub use viewport::*;
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use crate::player::{LocomotionDisabled, LookInput, LookStickInput, Player, PlayerVelocity};
+
+use super::{CameraConfig, CameraPitch, CameraRigOf, CameraYaw, FpsCamera, PitchAngle};
+
+/// Per-frame camera motion data for viewmodel sway, weapon lag, and similar consumers.
+///
+/// Lives on the `FpsCamera` entity and is refreshed every frame.
+///
+/// `additive_offset` is the extension point for layering extra camera-local
+/// translation on top of head bob: it is cleared by `reset_camera_motion` at the
+/// start of the camera chain, and anything added to it before `apply_camera_offsets`
+/// is applied to the camera transform that frame.
+#[derive(Component, Default, Clone, Debug)]
+pub struct CameraMotion {
+    /// Look rotation applied this frame in radians (x = yaw, y = pitch)
+    pub look_delta: Vec2,
+    /// Owning player's velocity in world space
+    pub velocity: Vec3,
+    /// Player velocity in the yaw frame (x = right, y = up, z = forward)
+    pub local_velocity: Vec3,
+    /// Head bob translation currently applied to the camera
+    pub bob_offset: Vec3,
+    /// Extra camera-local translation layered on top of head bob this frame
+    pub additive_offset: Vec3,
+    /// Current landing view punch in radians
+    pub view_punch: f32,
+    /// Pitch angle seen last frame, used to derive `look_delta.y`
+    pub(crate) last_pitch: f32,
+}
+
+/// Clears per-frame accumulators at the start of the camera chain
+pub fn reset_camera_motion(mut query: Query<&mut CameraMotion>) {
+    for mut motion in &mut query {
+        motion.additive_offset = Vec3::ZERO;
+    }
+}
+
+/// Records look deltas and player velocity after mouse look has been applied
+#[allow(clippy::type_complexity)]
+pub fn update_camera_motion(
+    player_query: Query<
+        (&LookInput, Option<&LookStickInput>, &PlayerVelocity, Has<LocomotionDisabled>),
+        With<Player>,
+    >,
+    yaw_query: Query<&Transform, With<CameraYaw>>,
+    pitch_query: Query<(&PitchAngle, &CameraConfig, &ChildOf), With<CameraPitch>>,
+    mut camera_query: Query<(&mut CameraMotion, &FpsCamera, &CameraRigOf, &ChildOf)>,
+    time: Res<Time>,
+) {
+    for (mut motion, camera, rig_of, child_of) in &mut camera_query {
+        let Ok((look_input, look_stick, velocity, disabled)) = player_query.get(rig_of.0) else {
+            continue;
+        };
+        if disabled {
+            // Hold velocity-driven offsets where they are and stop look sway
+            motion.look_delta = Vec2::ZERO;
+            continue;
+        }
+        let Ok((pitch_angle, config, pitch_parent)) = pitch_query.get(child_of.parent()) else {
+            continue;
+        };
+
+        let local_velocity = yaw_query
+            .get(pitch_parent.parent())
+            .map(|yaw| {
+                let right = yaw.right().as_vec3();
+                let forward = yaw.forward().as_vec3();
+                Vec3::new(velocity.dot(right), velocity.y, velocity.dot(forward))
+            })
+            .unwrap_or(velocity.0);
+
+        let stick_yaw = look_stick.map_or(0.0, |stick| stick.x);
+        motion.look_delta = Vec2::new(
+            -look_input.x * config.sensitivity
+                - stick_yaw * config.stick_sensitivity * time.delta_secs(),
+            pitch_angle.0 - motion.last_pitch,
+        );
+        motion.last_pitch = pitch_angle.0;
+        motion.velocity = velocity.0;
+        motion.local_velocity = local_velocity;
+        motion.view_punch = camera.view_punch;
+    }
+}
+
+/// Writes head bob plus any additive offsets to the camera transform
+pub fn apply_camera_offsets(mut query: Query<(&mut Transform, &CameraMotion), With<FpsCamera>>) {
+    for (mut transform, motion) in &mut query {
+        transform.translation = motion.bob_offset + motion.additive_offset;
+        transform.rotation = Quat::IDENTITY;
+    }
+}
+
+/// Shifts the camera toward the horizontal movement direction (lives on the `FpsCamera` entity).
+///
+/// Optional; layered through `CameraMotion::additive_offset`.
+#[derive(Component, Clone, Debug)]
+pub struct CameraLookAhead {
+    /// Offset per m/s of horizontal speed in meters
+    pub magnitude: f32,
+    /// Maximum offset in meters
+    pub max_offset: f32,
+    /// How quickly the offset follows velocity changes (1/s), 0.0 = instant
+    pub smoothing: f32,
+    /// Current smoothed offset in camera-local space
+    pub current: Vec3,
+}
+
+impl Default for CameraLookAhead {
+    fn default() -> Self {
+        Self {
+            magnitude: 0.02,
+            max_offset: 0.3,
+            smoothing: 4.0,
+            current: Vec3::ZERO,
+        }
+    }
+}
+
+/// Adds the velocity look-ahead offset to `CameraMotion::additive_offset`.
+/// Runs after `update_camera_motion` so it sees this frame's velocity.
+pub fn apply_look_ahead(
+    mut query: Query<(&mut CameraMotion, &mut CameraLookAhead)>,
+    time: Res<Time>,
+) {
+    for (mut motion, mut look_ahead) in &mut query {
+        // Yaw-frame velocity mapped to camera-local axes (forward is -Z)
+        let horizontal = Vec3::new(motion.local_velocity.x, 0.0, -motion.local_velocity.z);
+        let target = (horizontal * look_ahead.magnitude).clamp_length_max(look_ahead.max_offset);
+
+        look_ahead.current = if look_ahead.smoothing <= 0.0 {
+            target
+        } else {
+            let alpha = 1.0 - (-look_ahead.smoothing * time.delta_secs()).exp();
+            look_ahead.current.lerp(target, alpha)
+        };
+
+        motion.additive_offset += look_ahead.current;
+    }
+}
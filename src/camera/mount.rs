@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use super::{CameraRig, CameraYaw};
+
+/// Requests to move a player's camera rig onto another entity and back
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum CameraMountMessage {
+    /// Re-parent the rig of `player` onto `mount` (vehicle seat, turret, ...)
+    /// at `offset` in the mount's local space
+    Attach {
+        player: Entity,
+        mount: Entity,
+        offset: Vec3,
+    },
+    /// Return the rig of `player` to following the player body
+    Detach { player: Entity },
+}
+
+/// Present on a player whose camera rig is attached to another entity.
+///
+/// While attached, the rig stops following the body and player-driven camera
+/// effects (FOV, head bob, landing punch, crouch height) are paused. Look input
+/// keeps working, relative to the mount.
+#[derive(Component, Clone, Copy, Debug)]
+#[component(storage = "SparseSet")]
+pub struct CameraMounted {
+    /// Entity the rig is parented to
+    pub mount: Entity,
+    /// Yaw entity of the rig
+    pub yaw_entity: Entity,
+}
+
+/// Re-parents camera rigs in response to `CameraMountMessage`.
+///
+/// The world-space view direction is preserved on attach and detach, so the
+/// handoff has no visible snap in yaw or pitch.
+pub fn handle_camera_mount_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<CameraMountMessage>,
+    mounted_query: Query<&CameraMounted>,
+    rig_query: Query<&CameraRig>,
+    yaw_query: Query<(Entity, &GlobalTransform), With<CameraYaw>>,
+    mount_query: Query<&GlobalTransform>,
+) {
+    for msg in reader.read() {
+        match *msg {
+            CameraMountMessage::Attach {
+                player,
+                mount,
+                offset,
+            } => {
+                let Ok(rig) = rig_query.get(player) else {
+                    continue;
+                };
+                let Some((yaw_entity, yaw_global)) = yaw_query.iter_many(rig.entities()).next()
+                else {
+                    continue;
+                };
+                let Ok(mount_global) = mount_query.get(mount) else {
+                    continue;
+                };
+
+                // Keep the current world facing relative to the new parent
+                let rotation = mount_global.rotation().inverse() * yaw_global.rotation();
+
+                commands.entity(yaw_entity).insert((
+                    ChildOf(mount),
+                    Transform::from_translation(offset).with_rotation(rotation),
+                ));
+                commands.entity(player).insert(CameraMounted { mount, yaw_entity });
+            }
+            CameraMountMessage::Detach { player } => {
+                let Ok(mounted) = mounted_query.get(player) else {
+                    continue;
+                };
+                let Ok((_, yaw_global)) = yaw_query.get(mounted.yaw_entity) else {
+                    continue;
+                };
+
+                // Drop any mount roll/tilt; the free rig only yaws around world Y
+                let (yaw, _, _) = yaw_global.rotation().to_euler(EulerRot::YXZ);
+
+                commands
+                    .entity(mounted.yaw_entity)
+                    .remove::<ChildOf>()
+                    .insert(Transform {
+                        translation: yaw_global.translation(),
+                        rotation: Quat::from_rotation_y(yaw),
+                        ..default()
+                    });
+                commands.entity(player).remove::<CameraMounted>();
+            }
+        }
+    }
+}
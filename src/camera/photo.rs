@@ -0,0 +1,193 @@
+use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::prelude::*;
+
+use super::{CameraRig, FpsCamera};
+use crate::player::{LocomotionDisabled, Player};
+
+/// Photo mode settings
+#[derive(Resource, Clone, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhotoModeConfig {
+    /// Maximum distance the photo camera may travel from the frozen player (m)
+    pub max_radius: f32,
+    /// Fly speed in m/s
+    pub fly_speed: f32,
+    /// Fly speed multiplier while holding `fast_key`
+    pub fast_multiplier: f32,
+    /// Mouse sensitivity for the photo camera
+    pub sensitivity: f32,
+    pub forward_key: KeyCode,
+    pub back_key: KeyCode,
+    pub left_key: KeyCode,
+    pub right_key: KeyCode,
+    pub up_key: KeyCode,
+    pub down_key: KeyCode,
+    pub fast_key: KeyCode,
+}
+
+impl Default for PhotoModeConfig {
+    fn default() -> Self {
+        Self {
+            max_radius: 8.0,
+            fly_speed: 4.0,
+            fast_multiplier: 3.0,
+            sensitivity: 0.003,
+            forward_key: KeyCode::KeyW,
+            back_key: KeyCode::KeyS,
+            left_key: KeyCode::KeyA,
+            right_key: KeyCode::KeyD,
+            up_key: KeyCode::Space,
+            down_key: KeyCode::ControlLeft,
+            fast_key: KeyCode::ShiftLeft,
+        }
+    }
+}
+
+/// Requests to enter or leave photo mode
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhotoModeMessage {
+    /// Freeze `player` and fly a free camera from its view
+    Enter { player: Entity },
+    /// Leave photo mode and restore the player camera
+    Exit,
+}
+
+/// Free camera spawned by photo mode.
+///
+/// The player is frozen with `LocomotionDisabled` and the `FpsCamera` is
+/// deactivated while this camera exists. Both are put back the way they were
+/// on exit, so a player frozen by game code stays frozen.
+#[derive(Component)]
+pub struct PhotoCamera {
+    /// Frozen player the camera orbits around
+    pub player: Entity,
+    /// Player camera that was active before photo mode
+    pub fps_camera: Entity,
+    /// World position the camera is constrained around
+    pub anchor: Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    /// Whether the player was already disabled, so exiting leaves it disabled
+    was_disabled: bool,
+    /// Whether the player camera was active, so exiting leaves it inactive
+    was_active: bool,
+}
+
+/// Enters and exits photo mode in response to `PhotoModeMessage`
+pub fn handle_photo_mode_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<PhotoModeMessage>,
+    player_query: Query<(&Transform, &CameraRig, Has<LocomotionDisabled>), With<Player>>,
+    mut fps_query: Query<(Entity, &mut Camera, &GlobalTransform, &Projection), With<FpsCamera>>,
+    photo_query: Query<(Entity, &PhotoCamera)>,
+) {
+    for msg in reader.read() {
+        match *msg {
+            PhotoModeMessage::Enter { player } => {
+                if !photo_query.is_empty() {
+                    continue;
+                }
+                let Ok((player_transform, rig, disabled)) = player_query.get(player) else {
+                    continue;
+                };
+                let mut cameras = fps_query.iter_many_mut(rig.entities());
+                let Some((fps_entity, mut camera, camera_gt, projection)) = cameras.fetch_next()
+                else {
+                    continue;
+                };
+
+                let transform = camera_gt.compute_transform();
+                let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+
+                let was_active = camera.is_active;
+                camera.is_active = false;
+                commands.entity(player).insert(LocomotionDisabled);
+                commands.spawn((
+                    PhotoCamera {
+                        player,
+                        fps_camera: fps_entity,
+                        anchor: player_transform.translation,
+                        yaw,
+                        pitch,
+                        was_disabled: disabled,
+                        was_active,
+                    },
+                    Camera3d::default(),
+                    projection.clone(),
+                    transform,
+                ));
+            }
+            PhotoModeMessage::Exit => {
+                for (photo_entity, photo) in &photo_query {
+                    if let Ok((_, mut camera, _, _)) = fps_query.get_mut(photo.fps_camera) {
+                        camera.is_active = photo.was_active;
+                    }
+                    if !photo.was_disabled {
+                        commands.entity(photo.player).remove::<LocomotionDisabled>();
+                    }
+                    commands.entity(photo_entity).despawn();
+                }
+            }
+        }
+    }
+}
+
+/// Flies the photo camera with the `PhotoModeConfig` keys and the mouse,
+/// constrained to `PhotoModeConfig::max_radius` around the frozen player.
+///
+/// Uses real time so it keeps working while virtual time is paused.
+pub fn fly_photo_camera(
+    mut query: Query<(&mut Transform, &mut PhotoCamera)>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    config: Res<PhotoModeConfig>,
+    time: Res<Time<Real>>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut transform, mut photo) in &mut query {
+        photo.yaw -= mouse_motion.delta.x * config.sensitivity;
+        photo.pitch = (photo.pitch - mouse_motion.delta.y * config.sensitivity)
+            .clamp(-89.0_f32.to_radians(), 89.0_f32.to_radians());
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, photo.yaw, photo.pitch, 0.0);
+
+        let mut input = Vec3::ZERO;
+        if keyboard.pressed(config.forward_key) {
+            input.z += 1.0;
+        }
+        if keyboard.pressed(config.back_key) {
+            input.z -= 1.0;
+        }
+        if keyboard.pressed(config.right_key) {
+            input.x += 1.0;
+        }
+        if keyboard.pressed(config.left_key) {
+            input.x -= 1.0;
+        }
+        if keyboard.pressed(config.up_key) {
+            input.y += 1.0;
+        }
+        if keyboard.pressed(config.down_key) {
+            input.y -= 1.0;
+        }
+
+        let mut speed = config.fly_speed;
+        if keyboard.pressed(config.fast_key) {
+            speed *= config.fast_multiplier;
+        }
+
+        let movement = transform.forward().as_vec3() * input.z
+            + transform.right().as_vec3() * input.x
+            + Vec3::Y * input.y;
+        let mut position = transform.translation + movement.normalize_or_zero() * speed * dt;
+
+        // Keep the camera within the orbit radius around the frozen player
+        let offset = position - photo.anchor;
+        if offset.length() > config.max_radius {
+            position = photo.anchor + offset.normalize() * config.max_radius;
+        }
+
+        transform.translation = position;
+    }
+}
@@ -1,30 +1,119 @@
+use avian3d::prelude::PhysicsSystems;
+use bevy::ecs::schedule::ScheduleLabel;
 use bevy::prelude::*;
+use bevy::transform::TransformSystems;
 
-use super::{effects::*, look::*, smoothing::*};
+use super::{
+    aim_assist::*, body::*, cinematic::*, cursor::*, death::*, effects::*, head_tracking::*,
+    look::*, motion::*, mount::*, photo::*, recoil::*, smoothing::*, third_person::*,
+    viewport::*,
+};
+use crate::player::{LocomotionSchedule, LocomotionSet};
+
+/// Stages of the per-frame camera update, in order.
+///
+/// `Look` and `Effects` run in `Update`; `Follow` runs in `PostUpdate` before
+/// transform propagation.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CameraSet {
+    /// Look input, aim assist, head tracking, and recoil rotate the rig
+    Look,
+    /// FOV, bob, punch, sway, and crouch height offsets
+    Effects,
+    /// Rig follows the body; death camera, third-person boom, and cinematic
+    /// overrides apply
+    Follow,
+}
 
 /// Plugin for FPS camera systems
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<PreviousGroundedState>();
+        app.add_message::<CinematicMessage>();
+        app.add_message::<PhotoModeMessage>();
+        app.add_message::<CameraMountMessage>();
+        app.add_message::<DeathCameraMessage>();
+        app.init_resource::<PhotoModeConfig>();
+        app.init_resource::<DeathCameraConfig>();
+        app.init_resource::<CursorGrabConfig>();
+        app.init_resource::<CameraMode>();
+        app.init_resource::<ThirdPersonConfig>();
+
+        app.configure_sets(Update, (CameraSet::Look, CameraSet::Effects).chain());
+        app.configure_sets(PostUpdate, CameraSet::Follow.before(TransformSystems::Propagate));
+
+        // Outside the fixed loop the controller and Avian share frame schedules
+        // with the camera: movement has to read this frame's look, and the rig
+        // has to follow the body after Avian writes it back
+        let schedules = app.world().get_resource::<LocomotionSchedule>().copied();
+        let schedules = schedules.unwrap_or_default();
+        if schedules.controller == Update.intern() {
+            app.configure_sets(Update, LocomotionSet::Input.after(CameraSet::Look));
+        }
+        if schedules.physics == PostUpdate.intern() {
+            app.configure_sets(PostUpdate, CameraSet::Follow.after(PhysicsSystems::Writeback));
+        }
 
         app.add_systems(
             Update,
+            (
+                (
+                    reset_camera_motion,
+                    update_aim_assist,
+                    apply_mouse_look,
+                    apply_aim_magnetism,
+                    apply_head_tracking,
+                    apply_recoil,
+                )
+                    .chain()
+                    .in_set(CameraSet::Look),
+                (
+                    update_fov,
+                    apply_head_bob,
+                    apply_ledge_climb_bob,
+                    apply_view_punch,
+                    update_camera_motion,
+                    apply_look_ahead,
+                    update_camera_height,
+                    apply_ledge_grab_bounce,
+                    apply_ledge_shuffle_bob,
+                    apply_view_punch_rotation,
+                    apply_camera_offsets,
+                )
+                    .chain()
+                    .in_set(CameraSet::Effects),
+            ),
+        );
+
+        app.add_systems(Update, (handle_photo_mode_messages, fly_photo_camera).chain());
+        app.add_systems(Update, update_split_screen_viewports);
+        app.add_systems(Update, handle_camera_mount_messages);
+        app.add_systems(Update, handle_death_camera_messages);
+        app.add_systems(Startup, grab_cursor_on_start);
+        app.add_systems(Update, update_cursor_grab);
+        app.add_systems(Update, apply_camera_mode);
+
+        // Follow the interpolated body after all gameplay updates for this frame
+        app.add_systems(
+            PostUpdate,
             (
                 sync_camera_to_player,
-                apply_mouse_look,
-                update_fov,
-                apply_head_bob,
-                apply_ledge_climb_bob,
-                apply_view_punch,
-                update_camera_height,
-                apply_ledge_grab_bounce,
-                apply_ledge_shuffle_bob,
-                apply_view_punch_rotation,
+                apply_death_camera,
+                apply_third_person_boom,
+                apply_cinematic_override,
             )
-                .chain(),
+                .chain()
+                .in_set(CameraSet::Follow),
+        );
+        // Bone scale has to land after animation has posed the skeleton
+        app.add_systems(
+            PostUpdate,
+            (sync_body_anchor, sync_first_person_anchor, hide_first_person_parts)
+                .chain()
+                .after(sync_camera_to_player)
+                .after(bevy::app::AnimationSystems)
+                .in_set(CameraSet::Follow),
         );
-
     }
 }
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+use super::{CameraConfig, CameraPitch, CameraYaw, PitchAngle};
+
+/// Aim recoil for shooter integration (lives on the pitch entity).
+///
+/// Unlike view punch, recoil displaces the actual aim: impulses are added to
+/// `PitchAngle` and the yaw entity, then a configurable fraction recovers back
+/// toward the original aim.
+///
+/// Impulses are in radians with `x` = yaw (positive = right) and `y` = pitch
+/// (positive = up).
+#[derive(Component, Clone, Debug)]
+pub struct CameraRecoil {
+    /// How quickly queued impulses are applied (1/s), 0.0 = instant
+    pub kick_speed: f32,
+    /// How quickly recoil recovers back toward the original aim (1/s)
+    pub recovery_speed: f32,
+    /// Fraction of each impulse that is recovered (0.0 = none, 1.0 = full recenter)
+    pub recenter: f32,
+    /// Successive impulses used by `kick`; empty disables patterns
+    pub pattern: Vec<Vec2>,
+    /// Seconds without a kick before the pattern restarts from the first step
+    pub pattern_reset_time: f32,
+    /// Next pattern step used by `kick`
+    pub pattern_index: usize,
+    pending: Vec2,
+    recoverable: Vec2,
+    since_last_kick: f32,
+}
+
+impl Default for CameraRecoil {
+    fn default() -> Self {
+        Self {
+            kick_speed: 40.0,
+            recovery_speed: 6.0,
+            recenter: 0.8,
+            pattern: Vec::new(),
+            pattern_reset_time: 0.4,
+            pattern_index: 0,
+            pending: Vec2::ZERO,
+            recoverable: Vec2::ZERO,
+            since_last_kick: 0.0,
+        }
+    }
+}
+
+impl CameraRecoil {
+    /// Queues a recoil impulse (x = yaw right, y = pitch up, radians)
+    pub fn add_impulse(&mut self, impulse: Vec2) {
+        self.pending += impulse;
+        self.since_last_kick = 0.0;
+    }
+
+    /// Queues the next step of the recoil pattern, wrapping at the end.
+    /// Returns the impulse that was queued.
+    pub fn kick(&mut self) -> Vec2 {
+        if self.pattern.is_empty() {
+            return Vec2::ZERO;
+        }
+        let index = self.pattern_index % self.pattern.len();
+        let impulse = self.pattern[index];
+        self.pattern_index = (index + 1) % self.pattern.len();
+        self.add_impulse(impulse);
+        impulse
+    }
+
+    /// Recoil displacement still waiting to be recovered
+    pub fn outstanding(&self) -> Vec2 {
+        self.pending + self.recoverable
+    }
+
+    /// Drops all pending and recoverable recoil without moving the aim
+    pub fn clear(&mut self) {
+        self.pending = Vec2::ZERO;
+        self.recoverable = Vec2::ZERO;
+        self.pattern_index = 0;
+    }
+}
+
+/// Applies queued recoil impulses and recovery to the aim.
+/// Runs after mouse look so player input and recoil compose.
+pub fn apply_recoil(
+    mut pitch_query: Query<
+        (&mut PitchAngle, &mut CameraRecoil, &CameraConfig, &ChildOf),
+        With<CameraPitch>,
+    >,
+    mut yaw_query: Query<&mut Transform, With<CameraYaw>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut pitch_angle, mut recoil, config, child_of) in &mut pitch_query {
+        recoil.since_last_kick += dt;
+        if recoil.since_last_kick > recoil.pattern_reset_time {
+            recoil.pattern_index = 0;
+        }
+
+        // Kick: move part of the pending impulse into the aim
+        let kick = if recoil.kick_speed <= 0.0 {
+            recoil.pending
+        } else {
+            recoil.pending * (1.0 - (-recoil.kick_speed * dt).exp())
+        };
+        recoil.pending -= kick;
+        let recenter = recoil.recenter.clamp(0.0, 1.0);
+        recoil.recoverable += kick * recenter;
+
+        // Recovery: pull the recoverable part back toward the original aim
+        let recovery = recoil.recoverable * (1.0 - (-recoil.recovery_speed * dt).exp());
+        recoil.recoverable -= recovery;
+
+        let delta = kick - recovery;
+        if delta == Vec2::ZERO {
+            continue;
+        }
+
+        pitch_angle.0 = (pitch_angle.0 + delta.y).clamp(config.min_pitch, config.max_pitch);
+        if let Ok(mut yaw_transform) = yaw_query.get_mut(child_of.parent()) {
+            yaw_transform.rotate_y(-delta.x);
+        }
+    }
+}
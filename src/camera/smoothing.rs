@@ -1,21 +1,99 @@
 use bevy::prelude::*;
 
 use super::effects::LedgeClimbBob;
-use super::{FpsCamera, PitchAngle};
+use super::{
+    CameraMounted, CameraRigOf, CameraYaw, DeathCamera, FpsCamera, FreelookYaw, HeadTracking,
+    PitchAngle,
+};
+use crate::player::Player;
 
-/// Applies view punch and ledge climb bob to the camera rotation
+/// Positional follow settings for the camera rig (lives on the yaw entity).
+///
+/// The rig tracks the player's rendered (interpolated) transform in `PostUpdate`,
+/// so it stays smooth regardless of the `FixedUpdate` rate.
+#[derive(Component, Clone)]
+pub struct CameraSmoothing {
+    /// Spring stiffness for positional lag (0.0 = rigidly follow the body)
+    pub stiffness: f32,
+    /// Maximum distance the rig may trail behind the body in meters
+    pub max_lag: f32,
+}
+
+impl Default for CameraSmoothing {
+    fn default() -> Self {
+        Self {
+            stiffness: 0.0,
+            max_lag: 0.25,
+        }
+    }
+}
+
+/// Moves the camera rig to the player's interpolated position, optionally with spring lag.
+///
+/// Runs in `PostUpdate` before transform propagation so it sees the final
+/// interpolated body transform for this frame. Rigs attached to a mount
+/// (`CameraMounted`) are left to follow their parent instead, and the death
+/// camera (`DeathCamera`) takes over the rig entirely.
+#[allow(clippy::type_complexity)]
+pub fn sync_camera_to_player(
+    player_query: Query<
+        &Transform,
+        (With<Player>, Without<CameraMounted>, Without<DeathCamera>),
+    >,
+    mut yaw_query: Query<
+        (&mut Transform, &CameraRigOf, Option<&CameraSmoothing>),
+        (With<CameraYaw>, Without<Player>),
+    >,
+    time: Res<Time>,
+) {
+    for (mut yaw_transform, rig_of, smoothing) in &mut yaw_query {
+        let Ok(player_transform) = player_query.get(rig_of.0) else {
+            continue;
+        };
+
+        let target = player_transform.translation;
+
+        match smoothing {
+            Some(smoothing) if smoothing.stiffness > 0.0 => {
+                // Frame-rate independent exponential spring toward the body
+                let alpha = 1.0 - (-smoothing.stiffness * time.delta_secs()).exp();
+                let mut position = yaw_transform.translation.lerp(target, alpha);
+
+                let lag = position - target;
+                if lag.length() > smoothing.max_lag {
+                    position = target + lag.normalize() * smoothing.max_lag;
+                }
+
+                yaw_transform.translation = position;
+            }
+            _ => {
+                yaw_transform.translation = target;
+            }
+        }
+    }
+}
+
+/// Applies view punch and ledge climb bob to the camera rotation.
+/// Head-tracked rigs (`HeadTracking`) are left untouched for comfort.
+#[allow(clippy::type_complexity)]
 pub fn apply_view_punch_rotation(
-    camera_query: Query<&FpsCamera>,
+    camera_query: Query<(&FpsCamera, &ChildOf)>,
     mut pitch_query: Query<
-        (&mut Transform, &PitchAngle, Option<&LedgeClimbBob>),
+        (&mut Transform, &PitchAngle, &FreelookYaw, &CameraRigOf, Option<&LedgeClimbBob>),
         Without<FpsCamera>,
     >,
+    head_tracked_query: Query<(), With<HeadTracking>>,
 ) {
-    let Ok(camera) = camera_query.single() else {
-        return;
-    };
+    for (camera, child_of) in &camera_query {
+        let Ok((mut transform, pitch_angle, freelook_yaw, rig_of, climb_bob)) =
+            pitch_query.get_mut(child_of.parent())
+        else {
+            continue;
+        };
+        if head_tracked_query.contains(rig_of.0) {
+            continue;
+        }
 
-    for (mut transform, pitch_angle, climb_bob) in &mut pitch_query {
         let mut total_pitch = pitch_angle.0 - camera.view_punch;
         let mut roll = 0.0;
 
@@ -27,7 +105,8 @@ pub fn apply_view_punch_rotation(
             roll = wave * 0.08 * bob.roll_sign;
         }
 
-        transform.rotation =
-            Quat::from_rotation_x(total_pitch) * Quat::from_rotation_z(roll);
+        transform.rotation = Quat::from_rotation_y(freelook_yaw.0)
+            * Quat::from_rotation_x(total_pitch)
+            * Quat::from_rotation_z(roll);
     }
 }
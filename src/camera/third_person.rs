@@ -0,0 +1,157 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::{BodyAnchor, CameraRigOf, DeathCamera, FirstPersonAnchor, FpsCamera};
+use crate::player::{Player, PlayerConfig};
+
+/// Where the camera sits relative to the eye, switchable at runtime
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraMode {
+    /// Camera at the eye
+    #[default]
+    FirstPerson,
+    /// Camera on a boom arm behind the eye, orbiting with the view
+    ThirdPerson,
+}
+
+/// Third-person boom arm settings
+#[derive(Resource, Clone, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThirdPersonConfig {
+    /// Distance behind the eye in meters
+    pub boom_length: f32,
+    /// Offset from the eye in view space, applied before the boom (x = right)
+    pub shoulder_offset: Vec3,
+    /// Radius of the sphere cast along the arm in meters
+    pub probe_radius: f32,
+    /// How quickly the arm extends again once geometry clears (1/s, 0.0 = snap).
+    /// The arm always shortens instantly.
+    pub zoom_speed: f32,
+}
+
+impl Default for ThirdPersonConfig {
+    fn default() -> Self {
+        Self {
+            boom_length: 3.0,
+            shoulder_offset: Vec3::new(0.5, 0.2, 0.0),
+            probe_radius: 0.2,
+            zoom_speed: 6.0,
+        }
+    }
+}
+
+/// Current third-person arm length (lives on the `FpsCamera` entity).
+///
+/// Kept at 0.0 in first person, so switching modes extends the arm from the eye.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct SpringArm {
+    /// Distance from the eye along the arm in meters
+    pub length: f32,
+}
+
+/// Shows or hides first-person body parts when `CameraMode` changes.
+///
+/// Sets `BodyAnchor::first_person` and hides `FirstPersonAnchor` arms in third
+/// person. Only runs on a switch, so your own settings stand until the next one.
+pub fn apply_camera_mode(
+    mode: Res<CameraMode>,
+    mut body_query: Query<&mut BodyAnchor>,
+    mut arms_query: Query<&mut Visibility, With<FirstPersonAnchor>>,
+) {
+    if !mode.is_changed() || mode.is_added() {
+        return;
+    }
+    let first_person = *mode == CameraMode::FirstPerson;
+
+    for mut anchor in &mut body_query {
+        anchor.first_person = first_person;
+    }
+    for mut visibility in &mut arms_query {
+        *visibility = if first_person {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Pushes the camera out along the boom arm in third person.
+///
+/// A sphere is cast from the eye along the arm against the player's world
+/// layer; the arm shortens to the first hit and eases back out once clear.
+/// Runs in `PostUpdate` after the death camera, which it leaves alone, and
+/// before the cinematic override so cutscenes blend from the arm's end.
+pub fn apply_third_person_boom(
+    mut camera_query: Query<
+        (&mut Transform, &mut SpringArm, &CameraRigOf, &ChildOf),
+        With<FpsCamera>,
+    >,
+    player_query: Query<&PlayerConfig, (With<Player>, Without<DeathCamera>)>,
+    rig_query: Query<(&Transform, &GlobalTransform, Option<&ChildOf>), Without<FpsCamera>>,
+    spatial_query: SpatialQuery,
+    mode: Res<CameraMode>,
+    config: Res<ThirdPersonConfig>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut arm, rig_of, child_of) in &mut camera_query {
+        if *mode == CameraMode::FirstPerson {
+            arm.length = 0.0;
+            continue;
+        }
+        let Ok(player_config) = player_query.get(rig_of.0) else {
+            continue;
+        };
+
+        // Rebuild the eye's world transform from the rig hierarchy
+        // (transform propagation has not run yet this frame)
+        let Ok((pitch_transform, _, Some(pitch_parent))) = rig_query.get(child_of.parent()) else {
+            continue;
+        };
+        let Ok((yaw_transform, _, yaw_parent)) = rig_query.get(pitch_parent.parent()) else {
+            continue;
+        };
+        // Mounted rigs hang off the mount, already placed this frame
+        let yaw_global = match yaw_parent.and_then(|p| rig_query.get(p.parent()).ok()) {
+            Some((_, mount_global, _)) => mount_global.mul_transform(*yaw_transform),
+            None => GlobalTransform::from(*yaw_transform),
+        };
+        let eye = yaw_global.mul_transform(*pitch_transform);
+
+        let offset = config.shoulder_offset + Vec3::Z * config.boom_length;
+        let Ok(direction) = Dir3::new(eye.rotation() * offset) else {
+            continue;
+        };
+        let full_length = offset.length();
+
+        let filter = SpatialQueryFilter::default()
+            .with_mask(player_config.collider.world_layer)
+            .with_excluded_entities([rig_of.0]);
+        let cast_config = ShapeCastConfig {
+            max_distance: full_length,
+            ..default()
+        };
+        let target = spatial_query
+            .cast_shape(
+                &Collider::sphere(config.probe_radius),
+                eye.translation(),
+                Quat::IDENTITY,
+                direction,
+                &cast_config,
+                &filter,
+            )
+            .map_or(full_length, |hit| hit.distance);
+
+        arm.length = if target < arm.length || config.zoom_speed <= 0.0 {
+            target
+        } else {
+            let alpha = 1.0 - (-config.zoom_speed * time.delta_secs()).exp();
+            arm.length + (target - arm.length) * alpha
+        };
+
+        transform.translation += offset / full_length * arm.length;
+    }
+}
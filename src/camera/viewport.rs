@@ -0,0 +1,69 @@
+use bevy::camera::Viewport;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Places a camera in a cell of a split-screen layout on the primary window.
+///
+/// Layouts: 1 = fullscreen, 2 = side by side, 3-4 = 2x2 grid.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitScreenViewport {
+    /// Zero-based index of this camera's cell
+    pub index: u32,
+    /// Total number of split-screen cells
+    pub count: u32,
+}
+
+impl SplitScreenViewport {
+    pub fn new(index: u32, count: u32) -> Self {
+        Self { index, count }
+    }
+}
+
+/// Computes the physical position and size of a split-screen cell
+pub fn split_screen_rect(index: u32, count: u32, window_size: UVec2) -> (UVec2, UVec2) {
+    let (columns, rows) = match count {
+        0 | 1 => (1, 1),
+        2 => (2, 1),
+        _ => (2, count.div_ceil(2)),
+    };
+
+    let cell = UVec2::new(window_size.x / columns, window_size.y / rows);
+    let index = index.min(columns * rows - 1);
+    let position = UVec2::new(index % columns, index / columns) * cell;
+
+    (position, cell)
+}
+
+/// Keeps split-screen camera viewports in sync with the primary window size
+pub fn update_split_screen_viewports(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut camera_query: Query<(&mut Camera, &SplitScreenViewport)>,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let window_size = window.physical_size();
+    if window_size.x == 0 || window_size.y == 0 {
+        return;
+    }
+
+    for (mut camera, split) in &mut camera_query {
+        let (physical_position, physical_size) =
+            split_screen_rect(split.index, split.count, window_size);
+
+        let unchanged = camera.viewport.as_ref().is_some_and(|v| {
+            v.physical_position == physical_position && v.physical_size == physical_size
+        });
+        if unchanged {
+            continue;
+        }
+
+        camera.viewport = Some(Viewport {
+            physical_position,
+            physical_size,
+            ..default()
+        });
+        // Distinct orders avoid camera ambiguity warnings
+        camera.order = split.index as isize;
+    }
+}
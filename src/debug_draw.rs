@@ -0,0 +1,307 @@
+use avian3d::prelude::*;
+use bevy::color::palettes::css::{LIME, ORANGE, RED, SKY_BLUE, YELLOW};
+use bevy::prelude::*;
+
+#[cfg(feature = "ladder")]
+use crate::player::Ladder;
+#[cfg(feature = "ledge")]
+use crate::player::{LedgeClimbing, LedgeGrabbable, LedgeGrabbing};
+#[cfg(any(feature = "ledge", feature = "ladder"))]
+use crate::player::OnLadder;
+use crate::player::{GroundNormal, Grounded, Player, PlayerConfig, PlayerVelocity};
+
+/// Probe hit
+const HIT: Srgba = LIME;
+/// Probe missed
+const MISS: Srgba = RED;
+/// Probe hit something the ability rejects (wrong marker or normal too steep)
+const REJECTED: Srgba = ORANGE;
+
+/// Debug drawing settings
+#[derive(Resource, Clone, Debug)]
+pub struct LocomotionDebugDraw {
+    /// Whether probes are drawn
+    pub enabled: bool,
+    /// Key that turns drawing on and off
+    pub toggle_key: Option<KeyCode>,
+    /// Seconds of travel the velocity arrow represents
+    pub velocity_scale: f32,
+}
+
+impl Default for LocomotionDebugDraw {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            toggle_key: Some(KeyCode::F2),
+            velocity_scale: 0.25,
+        }
+    }
+}
+
+/// Run condition: debug drawing is enabled
+pub fn debug_draw_enabled(draw: Res<LocomotionDebugDraw>) -> bool {
+    draw.enabled
+}
+
+/// Turns debug drawing on and off with `LocomotionDebugDraw::toggle_key`
+pub fn toggle_debug_draw(
+    mut draw: ResMut<LocomotionDebugDraw>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    if draw.toggle_key.is_some_and(|key| keyboard.just_pressed(key)) {
+        draw.enabled = !draw.enabled;
+    }
+}
+
+/// Casts a ray and draws it up to the hit (with a dot) or to full length on a miss
+fn draw_ray(
+    gizmos: &mut Gizmos,
+    spatial_query: &SpatialQuery,
+    origin: Vec3,
+    direction: Dir3,
+    max_distance: f32,
+    filter: &SpatialQueryFilter,
+) -> Option<RayHitData> {
+    let hit = spatial_query.cast_ray(origin, direction, max_distance, true, filter);
+    match hit {
+        Some(hit) => {
+            let point = origin + *direction * hit.distance;
+            gizmos.line(origin, point, HIT);
+            gizmos.sphere(Isometry3d::from_translation(point), 0.03, HIT);
+        }
+        None => gizmos.line(origin, origin + *direction * max_distance, MISS),
+    }
+    hit
+}
+
+/// Marks a probe hit the ability rejected
+fn draw_rejected(gizmos: &mut Gizmos, origin: Vec3, direction: Dir3, hit: &RayHitData) {
+    let point = origin + *direction * hit.distance;
+    gizmos.line(origin, point, REJECTED);
+    gizmos.sphere(Isometry3d::from_translation(point), 0.05, REJECTED);
+}
+
+/// Draws the grounding ray and the ground normal.
+///
+/// Mirrors the ray in `update_grounded_state`.
+pub fn draw_ground_probe(
+    mut gizmos: Gizmos,
+    spatial_query: SpatialQuery,
+    query: Query<(&Transform, &PlayerConfig, Option<&GroundNormal>), With<Player>>,
+) {
+    for (transform, config, ground_normal) in &query {
+        let origin = transform.translation;
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+        let max_distance = config.collider.stand_height / 2.0 + config.collider.radius;
+        let hit = draw_ray(&mut gizmos, &spatial_query, origin, Dir3::NEG_Y, max_distance, &filter);
+
+        if let (Some(hit), Some(GroundNormal(normal))) = (hit, ground_normal) {
+            let point = origin + Vec3::NEG_Y * hit.distance;
+            gizmos.arrow(point, point + *normal * 0.5, SKY_BLUE);
+        } else if let Some(hit) = hit {
+            // Hit, but too steep to stand on
+            draw_rejected(&mut gizmos, origin, Dir3::NEG_Y, &hit);
+        }
+    }
+}
+
+/// Draws the current velocity as an arrow from the player's center
+pub fn draw_velocity(
+    mut gizmos: Gizmos,
+    draw: Res<LocomotionDebugDraw>,
+    query: Query<(&Transform, &PlayerVelocity), With<Player>>,
+) {
+    for (transform, velocity) in &query {
+        if velocity.length_squared() < 0.01 {
+            continue;
+        }
+        let origin = transform.translation;
+        gizmos.arrow(origin, origin + velocity.0 * draw.velocity_scale, YELLOW);
+    }
+}
+
+/// Draws the three ledge rays while airborne.
+///
+/// Mirrors `detect_ledge_grab` but ignores jump input and cooldown, so the
+/// probes are visible before the grab is attempted.
+#[cfg(feature = "ledge")]
+#[allow(clippy::type_complexity)]
+pub fn draw_ledge_probes(
+    mut gizmos: Gizmos,
+    spatial_query: SpatialQuery,
+    query: Query<
+        (&Transform, &PlayerConfig, &PlayerVelocity),
+        (
+            With<Player>,
+            Without<Grounded>,
+            Without<LedgeGrabbing>,
+            Without<LedgeClimbing>,
+            Without<OnLadder>,
+        ),
+    >,
+    ledge_query: Query<(), With<LedgeGrabbable>>,
+) {
+    for (transform, config, velocity) in &query {
+        let h_vel = Vec3::new(velocity.x, 0.0, velocity.z);
+        if h_vel.length_squared() < 0.1 {
+            continue;
+        }
+        let Ok(forward_dir) = Dir3::new(h_vel.normalize()) else {
+            continue;
+        };
+
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+        let half_height = config.collider.stand_height / 2.0;
+        let center = transform.translation;
+        let probe_dist = config.collider.radius + config.ledge.detect_reach;
+
+        // Ray 1: head height — must miss
+        let ray1_origin = center + Vec3::Y * half_height;
+        draw_ray(&mut gizmos, &spatial_query, ray1_origin, forward_dir, probe_dist, &filter);
+
+        // Ray 2: chest height — must hit a `LedgeGrabbable`
+        let ray2_origin = center + Vec3::Y * (half_height * 0.3);
+        let Some(wall_hit) =
+            draw_ray(&mut gizmos, &spatial_query, ray2_origin, forward_dir, probe_dist, &filter)
+        else {
+            continue;
+        };
+        if ledge_query.get(wall_hit.entity).is_err() {
+            draw_rejected(&mut gizmos, ray2_origin, forward_dir, &wall_hit);
+            continue;
+        }
+
+        // Ray 3: down onto the ledge surface — must hit an upward normal
+        let wall_point = ray2_origin + *forward_dir * wall_hit.distance;
+        let ray3_origin = Vec3::new(wall_point.x, ray1_origin.y + 0.3, wall_point.z);
+        let ledge_hit = draw_ray(
+            &mut gizmos,
+            &spatial_query,
+            ray3_origin,
+            Dir3::NEG_Y,
+            half_height * 2.0,
+            &filter,
+        );
+        if let Some(ledge_hit) = ledge_hit.filter(|hit| hit.normal.dot(Vec3::Y) < 0.7) {
+            draw_rejected(&mut gizmos, ray3_origin, Dir3::NEG_Y, &ledge_hit);
+        }
+    }
+}
+
+/// Draws the three step-up rays while grounded and moving.
+///
+/// Mirrors `apply_step_up`.
+#[allow(clippy::type_complexity)]
+pub fn draw_step_up_probes(
+    mut gizmos: Gizmos,
+    spatial_query: SpatialQuery,
+    query: Query<(&Transform, &PlayerConfig, &PlayerVelocity), (With<Player>, With<Grounded>)>,
+) {
+    for (transform, config, velocity) in &query {
+        let h_vel = Vec3::new(velocity.x, 0.0, velocity.z);
+        if h_vel.length_squared() < 0.25 {
+            continue;
+        }
+        let Ok(forward_dir) = Dir3::new(h_vel.normalize()) else {
+            continue;
+        };
+
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+        let half_height = config.collider.stand_height / 2.0;
+        let center = transform.translation;
+        let probe_dist = config.collider.radius + 0.15;
+
+        // Foot ray — must hit
+        let foot_origin = center + Vec3::Y * (-half_height + 0.05);
+        let Some(foot_hit) =
+            draw_ray(&mut gizmos, &spatial_query, foot_origin, forward_dir, probe_dist, &filter)
+        else {
+            continue;
+        };
+
+        // Step ray — must miss
+        let step_origin = center + Vec3::Y * (-half_height + config.movement.step_up_height);
+        if draw_ray(&mut gizmos, &spatial_query, step_origin, forward_dir, probe_dist, &filter)
+            .is_some()
+        {
+            continue;
+        }
+
+        // Surface ray — must hit an upward normal
+        let obstacle_point = foot_origin + *forward_dir * foot_hit.distance;
+        let surface_origin = Vec3::new(obstacle_point.x, step_origin.y, obstacle_point.z);
+        let surface_hit = draw_ray(
+            &mut gizmos,
+            &spatial_query,
+            surface_origin,
+            Dir3::NEG_Y,
+            config.movement.step_up_height,
+            &filter,
+        );
+        if let Some(surface_hit) = surface_hit.filter(|hit| hit.normal.dot(Vec3::Y) < 0.7) {
+            draw_rejected(&mut gizmos, surface_origin, Dir3::NEG_Y, &surface_hit);
+        }
+    }
+}
+
+/// Draws the capsule used for ladder overlap, green while touching a ladder.
+///
+/// Mirrors `detect_ladder` and `apply_ladder_movement`.
+#[cfg(feature = "ladder")]
+pub fn draw_ladder_volume(
+    mut gizmos: Gizmos,
+    spatial_query: SpatialQuery,
+    query: Query<(&Transform, &PlayerConfig, Has<OnLadder>), With<Player>>,
+    ladder_query: Query<(), With<Ladder>>,
+) {
+    for (transform, config, on_ladder) in &query {
+        let capsule_height = config.collider.stand_height - config.collider.radius * 2.0;
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.collision_mask);
+        let touching = on_ladder
+            || spatial_query
+                .shape_intersections(
+                    &Collider::capsule(config.collider.radius, capsule_height),
+                    transform.translation,
+                    transform.rotation,
+                    &filter,
+                )
+                .iter()
+                .any(|entity| ladder_query.contains(*entity));
+
+        gizmos.primitive_3d(
+            &Capsule3d::new(config.collider.radius, capsule_height),
+            Isometry3d::new(transform.translation, transform.rotation),
+            if touching { HIT } else { MISS },
+        );
+    }
+}
+
+/// Draws grounding, ledge, step-up and ladder probes and the velocity as gizmos.
+///
+/// Toggle with `LocomotionDebugDraw::toggle_key`.
+pub struct LocomotionDebugDrawPlugin;
+
+impl Plugin for LocomotionDebugDrawPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LocomotionDebugDraw>()
+            .add_systems(Update, toggle_debug_draw)
+            .add_systems(
+                Update,
+                (draw_ground_probe, draw_velocity, draw_step_up_probes)
+                    .after(toggle_debug_draw)
+                    .run_if(debug_draw_enabled),
+            );
+
+        #[cfg(feature = "ledge")]
+        app.add_systems(
+            Update,
+            draw_ledge_probes.after(toggle_debug_draw).run_if(debug_draw_enabled),
+        );
+
+        #[cfg(feature = "ladder")]
+        app.add_systems(
+            Update,
+            draw_ladder_volume.after(toggle_debug_draw).run_if(debug_draw_enabled),
+        );
+    }
+}
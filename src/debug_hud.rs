@@ -0,0 +1,179 @@
+use std::fmt::Write;
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::player::{
+    AirTime, CrouchInput, Crouching, ForcedSliding, GroundNormal, Grounded, JumpHeld,
+    LedgeClimbing, LedgeGrabbing, LocomotionDisabled, LocomotionState, MoveInput,
+    MovementEventKind, OnLadder, Player, PlayerMovementEvent, PlayerVelocity, Sliding,
+    SprintInput, Sprinting, Vaulting,
+};
+
+/// On-screen readout settings
+#[derive(Resource, Clone, Debug)]
+pub struct LocomotionDebugHud {
+    /// Whether the readout is shown
+    pub visible: bool,
+    /// Key that shows and hides the readout
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for LocomotionDebugHud {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            toggle_key: Some(KeyCode::F3),
+        }
+    }
+}
+
+/// Text node the readout is written to
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct DebugHudText;
+
+/// Spawns the readout in the top-left corner
+pub fn spawn_debug_hud(mut commands: Commands) {
+    commands.spawn((
+        DebugHudText,
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+    ));
+}
+
+/// Shows or hides the readout with `LocomotionDebugHud::toggle_key`
+pub fn toggle_debug_hud(
+    mut hud: ResMut<LocomotionDebugHud>,
+    mut text_query: Query<&mut Visibility, With<DebugHudText>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    if hud.toggle_key.is_some_and(|key| keyboard.just_pressed(key)) {
+        hud.visible = !hud.visible;
+    }
+    let visibility = if hud.visible {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut text_visibility in &mut text_query {
+        text_visibility.set_if_neq(visibility);
+    }
+}
+
+/// Writes speed, jump height, state, markers, ground angle, air time and
+/// input for every player into the readout
+#[allow(clippy::type_complexity)]
+pub fn update_debug_hud(
+    hud: Res<LocomotionDebugHud>,
+    mut events: MessageReader<PlayerMovementEvent>,
+    player_query: Query<
+        (
+            Entity,
+            (&PlayerVelocity, &LocomotionState, &AirTime, Option<&GroundNormal>),
+            (Option<&MoveInput>, Option<&SprintInput>, Option<&CrouchInput>, Option<&JumpHeld>),
+            (Has<Grounded>, Has<Sprinting>, Has<Crouching>, Has<Sliding>, Has<ForcedSliding>),
+            (
+                Has<LedgeGrabbing>,
+                Has<LedgeClimbing>,
+                Has<OnLadder>,
+                Has<Vaulting>,
+                Has<LocomotionDisabled>,
+            ),
+        ),
+        With<Player>,
+    >,
+    mut text_query: Query<&mut Text, With<DebugHudText>>,
+    mut jump_heights: Local<HashMap<Entity, f32>>,
+) {
+    for event in events.read() {
+        if let MovementEventKind::JumpApex { height } = event.kind {
+            jump_heights.insert(event.player, height);
+        }
+    }
+    if !hud.visible {
+        return;
+    }
+
+    let mut readout = String::new();
+    for (player, body, input, movement, traversal) in &player_query {
+        let (velocity, state, air_time, ground_normal) = body;
+        let (move_input, sprint, crouch, jump) = input;
+        let (grounded, sprinting, crouching, sliding, forced_sliding) = movement;
+        let (grabbing, climbing, on_ladder, vaulting, disabled) = traversal;
+
+        let horizontal = Vec2::new(velocity.x, velocity.z).length();
+        let jump_height = jump_heights.get(&player).copied().unwrap_or_default();
+        let markers = [
+            ("Grounded", grounded),
+            ("Sprinting", sprinting),
+            ("Crouching", crouching),
+            ("Sliding", sliding),
+            ("ForcedSliding", forced_sliding),
+            ("LedgeGrabbing", grabbing),
+            ("LedgeClimbing", climbing),
+            ("OnLadder", on_ladder),
+            ("Vaulting", vaulting),
+            ("LocomotionDisabled", disabled),
+        ];
+        let active: Vec<&str> =
+            markers.iter().filter(|(_, on)| *on).map(|(name, _)| *name).collect();
+        let held = [
+            ("sprint", sprint.is_some_and(|sprint| sprint.0)),
+            ("crouch", crouch.is_some_and(|crouch| crouch.0)),
+            ("jump", jump.is_some_and(|jump| jump.0)),
+        ];
+        let held: Vec<&str> = held.iter().filter(|(_, on)| *on).map(|(name, _)| *name).collect();
+
+        if !readout.is_empty() {
+            readout.push('\n');
+        }
+        let _ = writeln!(readout, "Player {player}: {state:?}");
+        let _ = writeln!(readout, "Speed: {horizontal:.1} m/s  Vertical: {:.1} m/s", velocity.y);
+        let _ = writeln!(readout, "Jump:  {jump_height:.2} m");
+        let _ = writeln!(readout, "Markers: {}", active.join(", "));
+        match ground_normal {
+            Some(normal) => {
+                let angle = normal.0.angle_between(Vec3::Y).to_degrees();
+                let _ = writeln!(readout, "Ground: {angle:.1}°");
+            }
+            None => {
+                let _ = writeln!(readout, "Air time: {:.2} s", air_time.duration);
+            }
+        }
+        let move_input = move_input.map_or(Vec2::ZERO, |input| input.0);
+        let _ = writeln!(
+            readout,
+            "Input: ({:.2}, {:.2}) {}",
+            move_input.x,
+            move_input.y,
+            held.join(" ")
+        );
+    }
+
+    for mut text in &mut text_query {
+        **text = readout.clone();
+    }
+}
+
+/// On-screen readout of each player's movement state, for tuning sessions
+pub struct LocomotionDebugHudPlugin;
+
+impl Plugin for LocomotionDebugHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LocomotionDebugHud>()
+            .add_systems(Startup, spawn_debug_hud)
+            .add_systems(Update, (toggle_debug_hud, update_debug_hud).chain());
+    }
+}
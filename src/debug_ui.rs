@@ -0,0 +1,369 @@
+use std::ops::RangeInclusive;
+
+use bevy::prelude::*;
+use bevy::window::{CursorOptions, PrimaryWindow};
+use bevy_egui::{egui, EguiContexts, EguiPlugin, EguiPrimaryContextPass};
+
+use crate::camera::{
+    grab_cursor, release_cursor, CameraConfig, CameraEasing, CameraRig, CursorGrabConfig,
+    FpsCamera,
+};
+use crate::player::{
+    Ability, Crouching, ForcedSliding, Grounded, LedgeClimbing, LedgeGrabbing, LocomotionDisabled,
+    LocomotionState, OnLadder, Player, PlayerConfig, PlayerVelocity, ResizePlayer, Sliding,
+    Sprinting, Vaulting,
+};
+
+/// Built-in `PlayerConfig` presets offered by the panel
+const PRESETS: [(&str, fn() -> PlayerConfig); 6] = [
+    ("default", PlayerConfig::default),
+    ("quake", PlayerConfig::quake),
+    ("source", PlayerConfig::source),
+    ("modern_military", PlayerConfig::modern_military),
+    ("walking_sim", PlayerConfig::walking_sim),
+    ("realistic", PlayerConfig::realistic),
+];
+
+/// Live tuning panel settings
+#[derive(Resource, Clone, Debug)]
+pub struct LocomotionDebugUi {
+    /// Whether the panel is shown
+    pub visible: bool,
+    /// Key that shows and hides the panel
+    pub toggle_key: KeyCode,
+    /// `CursorGrabConfig::enabled` before the panel took over the cursor
+    grab_enabled: bool,
+}
+
+impl Default for LocomotionDebugUi {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            toggle_key: KeyCode::F1,
+            grab_enabled: true,
+        }
+    }
+}
+
+/// Shows or hides the panel, releasing the cursor while it is open
+pub fn toggle_debug_ui(
+    mut ui: ResMut<LocomotionDebugUi>,
+    mut grab: ResMut<CursorGrabConfig>,
+    mut cursor_query: Query<&mut CursorOptions, With<PrimaryWindow>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    if !keyboard.just_pressed(ui.toggle_key) {
+        return;
+    }
+    let Ok(mut cursor) = cursor_query.single_mut() else {
+        return;
+    };
+
+    ui.visible = !ui.visible;
+    if ui.visible {
+        // Keep clicks on the panel from re-grabbing the cursor
+        ui.grab_enabled = grab.enabled;
+        grab.enabled = false;
+        release_cursor(&mut cursor);
+    } else {
+        grab.enabled = ui.grab_enabled;
+        if grab.enabled {
+            grab_cursor(&mut cursor);
+        }
+    }
+}
+
+fn slider(ui: &mut egui::Ui, label: &str, value: &mut f32, range: RangeInclusive<f32>) {
+    ui.add(egui::Slider::new(value, range).text(label));
+}
+
+fn angle_slider(ui: &mut egui::Ui, label: &str, radians: &mut f32, range: RangeInclusive<f32>) {
+    let mut degrees = radians.to_degrees();
+    if ui.add(egui::Slider::new(&mut degrees, range).text(label).suffix("°")).changed() {
+        *radians = degrees.to_radians();
+    }
+}
+
+fn player_config_ui(ui: &mut egui::Ui, config: &mut PlayerConfig) {
+    let movement = &mut config.movement;
+    ui.label("Movement");
+    slider(ui, "walk_speed", &mut movement.walk_speed, 0.0..=20.0);
+    slider(ui, "sprint_speed", &mut movement.sprint_speed, 0.0..=30.0);
+    ui.checkbox(&mut movement.auto_sprint, "auto_sprint");
+    slider(ui, "auto_sprint_threshold", &mut movement.auto_sprint_threshold, 0.0..=1.0);
+    slider(ui, "ground_accel", &mut movement.ground_accel, 0.0..=200.0);
+    slider(ui, "ground_friction", &mut movement.ground_friction, 0.0..=200.0);
+    slider(ui, "air_accel", &mut movement.air_accel, 0.0..=100.0);
+    slider(ui, "max_horizontal_speed", &mut movement.max_horizontal_speed, 0.0..=50.0);
+    slider(ui, "overspeed_decay", &mut movement.overspeed_decay, 0.0..=50.0);
+    slider(ui, "overspeed_limit", &mut movement.overspeed_limit, 0.0..=100.0);
+    slider(ui, "max_slope_angle", &mut movement.max_slope_angle, 0.0..=89.0);
+    slider(ui, "step_up_height", &mut movement.step_up_height, 0.0..=1.0);
+    slider(ui, "dash_buffer", &mut movement.dash_buffer, 0.0..=0.5);
+    slider(ui, "wall_hit_speed", &mut movement.wall_hit_speed, 0.0..=20.0);
+    slider(ui, "long_fall_time", &mut movement.long_fall_time, 0.0..=5.0);
+    slider(ui, "long_fall_speed", &mut movement.long_fall_speed, 0.0..=60.0);
+
+    let jump = &mut config.jump;
+    ui.label("Jump");
+    slider(ui, "velocity", &mut jump.velocity, 0.0..=20.0);
+    slider(ui, "cut_multiplier", &mut jump.cut_multiplier, 0.0..=1.0);
+    slider(ui, "coyote_time", &mut jump.coyote_time, 0.0..=0.5);
+    slider(ui, "buffer", &mut jump.buffer, 0.0..=0.5);
+
+    let crouch_slide = &mut config.crouch_slide;
+    ui.label("Crouch and slide");
+    slider(ui, "crouch_speed", &mut crouch_slide.crouch_speed, 0.0..=10.0);
+    slider(ui, "crouch_buffer", &mut crouch_slide.crouch_buffer, 0.0..=0.5);
+    slider(ui, "min_slide_speed", &mut crouch_slide.min_slide_speed, 0.0..=20.0);
+    slider(ui, "slide_duration", &mut crouch_slide.slide_duration, 0.0..=3.0);
+    slider(ui, "slide_friction", &mut crouch_slide.slide_friction, 0.1..=5.0);
+    slider(ui, "slide_boost", &mut crouch_slide.slide_boost, 1.0..=3.0);
+    slider(ui, "sprint_slide_grace", &mut crouch_slide.sprint_slide_grace, 0.0..=0.5);
+    slider(ui, "slide_jump_boost", &mut crouch_slide.slide_jump_boost, 0.0..=10.0);
+    slider(ui, "slide_jump_grace", &mut crouch_slide.slide_jump_grace, 0.0..=0.5);
+
+    let ledge = &mut config.ledge;
+    ui.label("Ledge");
+    slider(ui, "detect_reach", &mut ledge.detect_reach, 0.1..=2.0);
+    slider(ui, "climb_duration", &mut ledge.climb_duration, 0.05..=3.0);
+    slider(ui, "shuffle_speed", &mut ledge.shuffle_speed, 0.0..=5.0);
+    slider(ui, "shuffle_bob_amplitude", &mut ledge.shuffle_bob_amplitude, 0.0..=0.05);
+    slider(ui, "cooldown", &mut ledge.cooldown, 0.0..=2.0);
+    slider(ui, "grab_max_fall_speed", &mut ledge.grab_max_fall_speed, 0.0..=30.0);
+    ui.checkbox(&mut ledge.grab_ascending, "grab_ascending");
+    slider(ui, "grab_buffer", &mut ledge.grab_buffer, 0.0..=0.5);
+
+    ui.label("Ladder");
+    slider(ui, "climb_speed", &mut config.ladder.climb_speed, 0.0..=10.0);
+
+    let vault = &mut config.vault;
+    ui.label("Vault");
+    slider(ui, "detect_reach", &mut vault.detect_reach, 0.1..=2.0);
+    slider(ui, "min_height", &mut vault.min_height, 0.0..=2.0);
+    slider(ui, "max_height", &mut vault.max_height, 0.0..=3.0);
+    slider(ui, "max_depth", &mut vault.max_depth, 0.1..=3.0);
+    slider(ui, "clearance", &mut vault.clearance, 0.0..=0.5);
+    slider(ui, "min_speed", &mut vault.min_speed, 0.0..=20.0);
+    slider(ui, "min_duration", &mut vault.min_duration, 0.05..=2.0);
+    slider(ui, "exit_speed_multiplier", &mut vault.exit_speed_multiplier, 0.0..=2.0);
+
+    let footsteps = &mut config.footsteps;
+    ui.label("Footsteps");
+    slider(ui, "walk_stride", &mut footsteps.walk_stride, 0.3..=5.0);
+    slider(ui, "sprint_stride", &mut footsteps.sprint_stride, 0.3..=5.0);
+    slider(ui, "crouch_stride", &mut footsteps.crouch_stride, 0.3..=5.0);
+    slider(ui, "walk_volume", &mut footsteps.walk_volume, 0.0..=1.0);
+    slider(ui, "sprint_volume", &mut footsteps.sprint_volume, 0.0..=1.0);
+    slider(ui, "crouch_volume", &mut footsteps.crouch_volume, 0.0..=1.0);
+
+    let collider = &mut config.collider;
+    ui.label("Collider");
+    slider(ui, "stand_height", &mut collider.stand_height, 0.5..=3.0);
+    slider(ui, "crouch_height", &mut collider.crouch_height, 0.3..=3.0);
+    slider(ui, "radius", &mut collider.radius, 0.1..=1.0);
+
+    ui.label("Abilities");
+    for ability in Ability::ALL {
+        let mut rule = config.abilities.rule(ability);
+        ui.horizontal(|ui| {
+            ui.add(egui::DragValue::new(&mut rule.priority).prefix("priority "));
+            ui.add(egui::DragValue::new(&mut rule.groups).prefix("groups "));
+            ui.label(format!("{ability:?}"));
+        });
+        config.abilities = config.abilities.with_rule(ability, rule);
+    }
+}
+
+fn preset_ui(ui: &mut egui::Ui, config: &mut PlayerConfig) {
+    ui.horizontal_wrapped(|ui| {
+        for (name, preset) in PRESETS {
+            if ui.button(name).clicked() {
+                // Keep the collider so the body and its layers don't change
+                *config = PlayerConfig {
+                    collider: config.collider,
+                    ..preset()
+                };
+            }
+        }
+    });
+}
+
+fn fps_camera_ui(ui: &mut egui::Ui, camera: &mut FpsCamera) {
+    angle_slider(ui, "base_fov", &mut camera.base_fov, 30.0..=130.0);
+    angle_slider(ui, "sprint_fov", &mut camera.sprint_fov, 30.0..=130.0);
+    slider(ui, "fov_speed", &mut camera.fov_speed, 0.0..=30.0);
+    slider(ui, "head_bob_amplitude", &mut camera.head_bob_amplitude, 0.0..=0.1);
+    slider(ui, "head_bob_frequency", &mut camera.head_bob_frequency, 0.0..=30.0);
+    slider(ui, "head_bob_sway", &mut camera.head_bob_sway, 0.0..=0.1);
+    ui.checkbox(&mut camera.head_bob_sync_gait, "head_bob_sync_gait");
+}
+
+fn camera_config_ui(ui: &mut egui::Ui, config: &mut CameraConfig) {
+    ui.add(
+        egui::Slider::new(&mut config.sensitivity, 0.0005..=0.01)
+            .text("sensitivity")
+            .logarithmic(true),
+    );
+    slider(ui, "stick_sensitivity", &mut config.stick_sensitivity, 0.0..=10.0);
+    angle_slider(ui, "max_pitch", &mut config.max_pitch, 0.0..=90.0);
+    angle_slider(ui, "min_pitch", &mut config.min_pitch, -90.0..=0.0);
+    angle_slider(ui, "freelook_max_yaw", &mut config.freelook_max_yaw, 0.0..=180.0);
+    slider(ui, "freelook_return_speed", &mut config.freelook_return_speed, 0.0..=30.0);
+    slider(ui, "crouch_transition_time", &mut config.crouch_transition_time, 0.0..=1.0);
+    egui::ComboBox::from_label("crouch_easing")
+        .selected_text(format!("{:?}", config.crouch_easing))
+        .show_ui(ui, |ui| {
+            for easing in [
+                CameraEasing::Exponential,
+                CameraEasing::Linear,
+                CameraEasing::SmoothStep,
+            ] {
+                ui.selectable_value(&mut config.crouch_easing, easing, format!("{easing:?}"));
+            }
+        });
+    slider(ui, "slide_eye_offset", &mut config.slide_eye_offset, 0.0..=0.5);
+}
+
+/// Draws the tuning panel: live state and sliders for every player and its rig
+#[allow(clippy::type_complexity)]
+pub fn draw_debug_ui(
+    mut contexts: EguiContexts,
+    ui_state: Res<LocomotionDebugUi>,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut PlayerConfig,
+            &PlayerVelocity,
+            &LocomotionState,
+            Option<&CameraRig>,
+            (Has<Grounded>, Has<Sprinting>, Has<Crouching>, Has<Sliding>, Has<ForcedSliding>),
+            (
+                Has<LedgeGrabbing>,
+                Has<LedgeClimbing>,
+                Has<OnLadder>,
+                Has<Vaulting>,
+                Has<LocomotionDisabled>,
+            ),
+        ),
+        With<Player>,
+    >,
+    mut fps_query: Query<&mut FpsCamera>,
+    mut camera_config_query: Query<&mut CameraConfig>,
+    mut resize_writer: MessageWriter<ResizePlayer>,
+) -> Result {
+    if !ui_state.visible {
+        return Ok(());
+    }
+
+    let ctx = contexts.ctx_mut()?;
+    egui::Window::new("Locomotion").default_width(340.0).show(ctx, |ui| {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (player, mut config, velocity, state, rig, movement, traversal) in
+                &mut player_query
+            {
+                let (grounded, sprinting, crouching, sliding, forced_sliding) = movement;
+                let (grabbing, climbing, on_ladder, vaulting, disabled) = traversal;
+
+                ui.heading(format!("Player {player}"));
+                ui.label(format!("State: {state:?}"));
+                ui.label(format!(
+                    "Speed: {:.2} m/s horizontal, {:.2} m/s vertical",
+                    Vec2::new(velocity.x, velocity.z).length(),
+                    velocity.y
+                ));
+
+                let markers = [
+                    ("Grounded", grounded),
+                    ("Sprinting", sprinting),
+                    ("Crouching", crouching),
+                    ("Sliding", sliding),
+                    ("ForcedSliding", forced_sliding),
+                    ("LedgeGrabbing", grabbing),
+                    ("LedgeClimbing", climbing),
+                    ("OnLadder", on_ladder),
+                    ("Vaulting", vaulting),
+                    ("LocomotionDisabled", disabled),
+                ];
+                let active: Vec<&str> =
+                    markers.iter().filter(|(_, on)| *on).map(|(name, _)| *name).collect();
+                ui.label(format!("Markers: {}", active.join(", ")));
+
+                egui::CollapsingHeader::new("PlayerConfig")
+                    .id_salt((player, "player_config"))
+                    .show(ui, |ui| {
+                        // Edit a copy so change detection only fires on real edits
+                        let mut edited = *config;
+                        preset_ui(ui, &mut edited);
+                        player_config_ui(ui, &mut edited);
+                        if ui.button("Validate").clicked() {
+                            edited.validate();
+                        }
+                        // Size changes go through ResizePlayer so the feet stay planted
+                        let size = edited.collider;
+                        edited.collider.stand_height = config.collider.stand_height;
+                        edited.collider.crouch_height = config.collider.crouch_height;
+                        edited.collider.radius = config.collider.radius;
+                        if edited.collider != size {
+                            resize_writer.write(ResizePlayer {
+                                player,
+                                stand_height: size.stand_height,
+                                crouch_height: size.crouch_height,
+                                radius: size.radius,
+                            });
+                        }
+                        if edited != *config {
+                            *config = edited;
+                        }
+                    });
+
+                let Some(rig) = rig else {
+                    ui.separator();
+                    continue;
+                };
+
+                let mut cameras = fps_query.iter_many_mut(rig.entities());
+                while let Some(mut camera) = cameras.fetch_next() {
+                    // Edit a copy so untouched widgets don't mark it changed
+                    let mut edited = camera.clone();
+                    egui::CollapsingHeader::new("FpsCamera")
+                        .id_salt((player, "fps_camera"))
+                        .show(ui, |ui| fps_camera_ui(ui, &mut edited));
+                    if edited != *camera {
+                        *camera = edited;
+                    }
+                }
+
+                let mut configs = camera_config_query.iter_many_mut(rig.entities());
+                while let Some(mut camera_config) = configs.fetch_next() {
+                    let mut edited = camera_config.clone();
+                    egui::CollapsingHeader::new("CameraConfig")
+                        .id_salt((player, "camera_config"))
+                        .show(ui, |ui| camera_config_ui(ui, &mut edited));
+                    if edited != *camera_config {
+                        *camera_config = edited;
+                    }
+                }
+
+                ui.separator();
+            }
+        });
+    });
+
+    Ok(())
+}
+
+/// Adds the in-game tuning panel (toggle with `LocomotionDebugUi::toggle_key`)
+pub struct LocomotionDebugUiPlugin;
+
+impl Plugin for LocomotionDebugUiPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin::default());
+        }
+        app.init_resource::<LocomotionDebugUi>()
+            .add_message::<ResizePlayer>()
+            .add_systems(Update, toggle_debug_ui)
+            .add_systems(EguiPrimaryContextPass, draw_debug_ui);
+    }
+}
@@ -0,0 +1,198 @@
+use avian3d::prelude::*;
+use bevy::gltf::GltfExtras;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::scene::SceneInstanceReady;
+
+use crate::physics::GameLayer;
+#[cfg(feature = "ladder")]
+use crate::player::Ladder;
+#[cfg(feature = "ledge")]
+use crate::player::LedgeGrabbable;
+use crate::player::{ForceSlide, SurfaceMaterial};
+
+/// How glTF scenes are turned into locomotion geometry.
+///
+/// Nodes are tagged through their name (`Wall-ledge-surface=stone`, with
+/// Blender's `.001` suffixes ignored) or a glTF extras property such as
+/// `"locomotion": "ladder"`. Tags:
+///
+/// - `world`: plain collidable geometry
+/// - `ledge`: `LedgeGrabbable`
+/// - `ladder`: `Ladder`, as a sensor on the trigger layers
+/// - `slide`: `ForceSlide`
+/// - `trigger`: a sensor on the trigger layers
+/// - `surface=<name>`: `SurfaceMaterial`, also read from a `"surface"` extras property
+///
+/// Tags apply to the node and every mesh below it; nested nodes override
+/// their parents.
+#[derive(Resource, Clone, Debug)]
+pub struct GltfMarkerConfig {
+    /// Read tags from node names
+    pub names: bool,
+    /// glTF extras property holding space- or comma-separated tags
+    pub extras_key: String,
+    /// Build static colliders for tagged meshes that don't have one
+    pub colliders: bool,
+    /// Layers for solid tagged geometry
+    pub world_layers: CollisionLayers,
+    /// Layers for `ladder` and `trigger` sensors
+    pub trigger_layers: CollisionLayers,
+}
+
+impl Default for GltfMarkerConfig {
+    fn default() -> Self {
+        Self {
+            names: true,
+            extras_key: "locomotion".into(),
+            colliders: true,
+            world_layers: CollisionLayers::new(GameLayer::World, [GameLayer::Player]),
+            trigger_layers: CollisionLayers::new(GameLayer::Trigger, [GameLayer::Player]),
+        }
+    }
+}
+
+/// Tags parsed from one node
+#[derive(Clone, Debug, Default)]
+struct NodeTags {
+    world: bool,
+    ledge: bool,
+    ladder: bool,
+    slide: bool,
+    trigger: bool,
+    surface: Option<String>,
+}
+
+impl NodeTags {
+    fn is_empty(&self) -> bool {
+        let flags = [self.world, self.ledge, self.ladder, self.slide, self.trigger];
+        !flags.contains(&true) && self.surface.is_none()
+    }
+
+    fn sensor(&self) -> bool {
+        self.ladder || self.trigger
+    }
+
+    fn add(&mut self, tag: &str) {
+        let tag = tag.trim().to_ascii_lowercase();
+        if let Some(surface) = tag.strip_prefix("surface=") {
+            self.surface = Some(surface.to_string());
+            return;
+        }
+        match tag.as_str() {
+            "world" => self.world = true,
+            "ledge" => self.ledge = true,
+            "ladder" => self.ladder = true,
+            "slide" => self.slide = true,
+            "trigger" => self.trigger = true,
+            _ => {}
+        }
+    }
+
+    fn parse(config: &GltfMarkerConfig, name: Option<&Name>, extras: Option<&GltfExtras>) -> Self {
+        let mut tags = Self::default();
+        if let Some(name) = name.filter(|_| config.names) {
+            // `Ladder-ladder.001` → `ladder`
+            let name = name.as_str();
+            let name = match name.rsplit_once('.') {
+                Some((base, suffix)) if suffix.chars().all(|c| c.is_ascii_digit()) => base,
+                _ => name,
+            };
+            name.split('-').skip(1).for_each(|tag| tags.add(tag));
+        }
+
+        let extras = extras.and_then(|extras| {
+            serde_json::from_str::<serde_json::Value>(&extras.value).ok()
+        });
+        let property = |key: &str| {
+            extras.as_ref().and_then(|extras| extras.get(key)).and_then(|value| value.as_str())
+        };
+        if let Some(list) = property(&config.extras_key) {
+            list.split([' ', ',']).filter(|tag| !tag.is_empty()).for_each(|tag| tags.add(tag));
+        }
+        if let Some(surface) = property("surface") {
+            tags.surface = Some(surface.to_ascii_lowercase());
+        }
+        tags
+    }
+}
+
+/// Tags the nodes of a glTF scene once it has spawned.
+///
+/// `SurfaceMaterial` names are leaked once per distinct name, since the
+/// component holds a `&'static str`.
+pub fn tag_gltf_scene(
+    ready: On<SceneInstanceReady>,
+    mut commands: Commands,
+    config: Res<GltfMarkerConfig>,
+    children_query: Query<&Children>,
+    node_query: Query<(Option<&Name>, Option<&GltfExtras>)>,
+    mesh_query: Query<Has<Collider>, With<Mesh3d>>,
+    mut surfaces: Local<HashMap<String, &'static str>>,
+) {
+    // Breadth-first, so nested nodes are tagged after (and override) their parents
+    for node in children_query.iter_descendants(ready.event_target()) {
+        let Ok((name, extras)) = node_query.get(node) else {
+            continue;
+        };
+        let tags = NodeTags::parse(&config, name, extras);
+        if tags.is_empty() {
+            continue;
+        }
+        let surface = tags.surface.as_ref().map(|surface| {
+            *surfaces
+                .entry(surface.clone())
+                .or_insert_with(|| Box::leak(surface.clone().into_boxed_str()))
+        });
+        let layers = if tags.sensor() {
+            config.trigger_layers
+        } else {
+            config.world_layers
+        };
+
+        let meshes = std::iter::once(node).chain(children_query.iter_descendants(node));
+        for entity in meshes {
+            let mesh = mesh_query.get(entity).ok();
+            if entity != node && mesh.is_none() {
+                continue;
+            }
+            let mut entity = commands.entity(entity);
+            entity.insert(layers);
+            if mesh == Some(false) && config.colliders {
+                let constructor = if tags.sensor() {
+                    ColliderConstructor::ConvexHullFromMesh
+                } else {
+                    ColliderConstructor::TrimeshFromMesh
+                };
+                entity.insert((RigidBody::Static, constructor));
+            }
+            if tags.sensor() {
+                entity.insert(Sensor);
+            }
+            #[cfg(feature = "ledge")]
+            if tags.ledge {
+                entity.insert(LedgeGrabbable);
+            }
+            #[cfg(feature = "ladder")]
+            if tags.ladder {
+                entity.insert(Ladder);
+            }
+            if tags.slide {
+                entity.insert(ForceSlide);
+            }
+            if let Some(surface) = surface {
+                entity.insert(SurfaceMaterial(surface));
+            }
+        }
+    }
+}
+
+/// Turns glTF node names and extras into `Ladder`, `LedgeGrabbable`,
+/// `ForceSlide`, `SurfaceMaterial` and collision layers when scenes spawn
+pub struct LocomotionGltfPlugin;
+
+impl Plugin for LocomotionGltfPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GltfMarkerConfig>().add_observer(tag_gltf_scene);
+    }
+}
@@ -1,10 +1,50 @@
+#[cfg(feature = "audio-playback")]
+pub mod audio_playback;
 pub mod camera;
+#[cfg(feature = "debug-draw")]
+pub mod debug_draw;
+#[cfg(feature = "debug-hud")]
+pub mod debug_hud;
+#[cfg(feature = "debug-ui")]
+pub mod debug_ui;
+#[cfg(feature = "gltf-markers")]
+pub mod gltf_markers;
 pub mod physics;
 pub mod player;
+#[cfg(feature = "replicon")]
+pub mod replicon;
+#[cfg(feature = "rumble")]
+pub mod rumble;
+#[cfg(feature = "speedrun")]
+pub mod speedrun;
+#[cfg(feature = "test-gym")]
+pub mod test_gym;
+#[cfg(feature = "config-asset")]
+pub mod tuning;
 
+#[cfg(feature = "audio-playback")]
+pub use audio_playback::LocomotionAudioPlugin;
 pub use camera::CameraPlugin;
+#[cfg(feature = "debug-draw")]
+pub use debug_draw::LocomotionDebugDrawPlugin;
+#[cfg(feature = "debug-hud")]
+pub use debug_hud::LocomotionDebugHudPlugin;
+#[cfg(feature = "debug-ui")]
+pub use debug_ui::LocomotionDebugUiPlugin;
+#[cfg(feature = "gltf-markers")]
+pub use gltf_markers::LocomotionGltfPlugin;
 pub use physics::PhysicsPlugin;
-pub use player::PlayerPlugin;
+pub use player::{CoreMovementPlugin, PlayerPlugin};
+#[cfg(feature = "replicon")]
+pub use replicon::LocomotionReplicationPlugin;
+#[cfg(feature = "rumble")]
+pub use rumble::LocomotionRumblePlugin;
+#[cfg(feature = "speedrun")]
+pub use speedrun::SpeedrunPlugin;
+#[cfg(feature = "test-gym")]
+pub use test_gym::TestGymPlugin;
+#[cfg(feature = "config-asset")]
+pub use tuning::LocomotionTuningPlugin;
 
 use bevy::prelude::*;
 
@@ -16,22 +56,119 @@ impl Plugin for BevyLocomotionPlugin {
         if !app.is_plugin_added::<PhysicsPlugin>() {
             app.add_plugins(PhysicsPlugin);
         }
-        if !app.is_plugin_added::<PlayerPlugin>() {
+        // Apps that picked their own ability plugins already have the core
+        if !app.is_plugin_added::<PlayerPlugin>() && !app.is_plugin_added::<CoreMovementPlugin>() {
             app.add_plugins(PlayerPlugin);
         }
         if !app.is_plugin_added::<CameraPlugin>() {
             app.add_plugins(CameraPlugin);
         }
+        #[cfg(feature = "config-asset")]
+        if !app.is_plugin_added::<LocomotionTuningPlugin>() {
+            app.add_plugins(LocomotionTuningPlugin);
+        }
+        #[cfg(feature = "debug-draw")]
+        if !app.is_plugin_added::<LocomotionDebugDrawPlugin>() {
+            app.add_plugins(LocomotionDebugDrawPlugin);
+        }
+        #[cfg(feature = "debug-hud")]
+        if !app.is_plugin_added::<LocomotionDebugHudPlugin>() {
+            app.add_plugins(LocomotionDebugHudPlugin);
+        }
+        #[cfg(feature = "debug-ui")]
+        if !app.is_plugin_added::<LocomotionDebugUiPlugin>() {
+            app.add_plugins(LocomotionDebugUiPlugin);
+        }
+        #[cfg(feature = "audio-playback")]
+        if !app.is_plugin_added::<LocomotionAudioPlugin>() {
+            app.add_plugins(LocomotionAudioPlugin);
+        }
+        #[cfg(feature = "rumble")]
+        if !app.is_plugin_added::<LocomotionRumblePlugin>() {
+            app.add_plugins(LocomotionRumblePlugin);
+        }
+        #[cfg(feature = "speedrun")]
+        if !app.is_plugin_added::<SpeedrunPlugin>() {
+            app.add_plugins(SpeedrunPlugin);
+        }
+        #[cfg(feature = "gltf-markers")]
+        if !app.is_plugin_added::<LocomotionGltfPlugin>() {
+            app.add_plugins(LocomotionGltfPlugin);
+        }
     }
 }
 
 pub mod prelude {
-    pub use crate::camera::{CameraConfig, CameraPlugin, FpsCamera};
-    pub use crate::physics::{GameLayer, PhysicsPlugin};
+    pub use crate::camera::{
+        grab_cursor, release_cursor, AimAssist, AimAssistTarget, BodyAnchor, CameraConfig,
+        CameraLookAhead, CameraMode, CameraMotion, CameraMountMessage, CameraMounted, CameraPlugin,
+        CameraRecoil, CameraRig, CameraRigOf, CameraSet, CameraSmoothing, CinematicMessage,
+        CinematicOverride, CursorGrabConfig, DeathCameraConfig, DeathCameraMessage, DeathCameraMode,
+        FirstPersonAnchor, FirstPersonHidden, FpsCamera, HeadTracking, LookPose, PhotoModeConfig,
+        PhotoModeMessage, SplitScreenViewport, SpringArm, ThirdPersonConfig,
+    };
+    pub use crate::physics::{
+        can_stand, probe_ground, probe_ledge, probe_step, probe_vault, GameLayer, GroundHit,
+        LedgeHit, PhysicsPlugin, StepHit, VaultHit,
+    };
     pub use crate::player::{
-        spawn_player, Crouching, ForceSlide, ForcedSliding, Grounded, Ladder, LedgeClimbing,
-        LedgeGrabbable, LedgeGrabbing, OnLadder, Player, PlayerAudioMessage, PlayerConfig,
-        PlayerPlugin, PlayerVelocity, Sliding, Sprinting,
+        player_input, spawn_camera_rig, spawn_player, spawn_player_with_bindings,
+        spawn_player_with_device, spawn_player_without_input, Ability, AbilityArbiter,
+        AbilityConfig, AbilityConflict, AbilityRule, ActionPolicy, AutoSpawnPlayer, BoolAction,
+        Carried, Carrier, CarryMessage, Carryable, ColliderConfig, Cooldown, CooldownReady,
+        Cooldowns, CoreMovementPlugin, CrouchSlideConfig, Crouching, CrowdController, DeadzoneShape,
+        FootstepConfig, ForceSlide, ForceSlidePlugin, ForcedSliding, GroundEntity, GroundPoint,
+        Grounded, HoverChanged, InputBinding, InputBindings, InputContext, InputContextMessage,
+        InputDevice, InputPolicies, InputSuspended, IntentPlayback, IntentQueue, IntentRecorder,
+        IntentTrack, Interactable, Interacted, Interactor, JumpConfig, JumpPlugin, LadderConfig,
+        LedgeClimbing, LedgeConfig, LedgeGrabbing, LocomotionAnimState, LocomotionDeterminism,
+        LocomotionDisabled, LocomotionIntent, LocomotionMetrics, LocomotionObservers,
+        LocomotionSchedule, LocomotionSet, LocomotionSnapshot, LocomotionState, LocomotionTick,
+        Mount, MountEvent, MountMessage, MovementBasis, MovementConfig, MovementEventKind, OnLadder,
+        Player, PlayerBuilder, PlayerBundle, PlayerConfig, PlayerMovementEvent, PlayerPlugin,
+        PlayerVelocity, PredictionHistory, Reconcile, ReducedProbeRate, ResizePlayer, ResponseCurve,
+        Riding, Sliding, SpawnPlayer, SpeedThresholds, Sprinting, Stance, StateChanged,
+        StepUpPlugin, StickResponse, SurfaceMaterial, TeleportPlayer, TimeDilation, TransformTrack,
+        VaultConfig, Vaulting, VelocityMessage,
+    };
+    #[cfg(feature = "audio-events")]
+    pub use crate::player::{PlayerAudioMessage, PlayerSound, PlayerSoundKind};
+    #[cfg(feature = "ladder")]
+    pub use crate::player::{Ladder, LadderPlugin};
+    #[cfg(feature = "ledge")]
+    pub use crate::player::{LedgeGrabbable, LedgePlugin, RootMotionClimb};
+    #[cfg(feature = "slide")]
+    pub use crate::player::SlidePlugin;
+    #[cfg(feature = "vault")]
+    pub use crate::player::VaultPlugin;
+    #[cfg(feature = "audio-playback")]
+    pub use crate::audio_playback::{LocomotionAudioPlugin, LocomotionSounds, SoundClip};
+    #[cfg(feature = "debug-draw")]
+    pub use crate::debug_draw::{LocomotionDebugDraw, LocomotionDebugDrawPlugin};
+    #[cfg(feature = "debug-hud")]
+    pub use crate::debug_hud::{LocomotionDebugHud, LocomotionDebugHudPlugin};
+    #[cfg(feature = "debug-ui")]
+    pub use crate::debug_ui::{LocomotionDebugUi, LocomotionDebugUiPlugin};
+    #[cfg(feature = "gltf-markers")]
+    pub use crate::gltf_markers::{GltfMarkerConfig, LocomotionGltfPlugin};
+    #[cfg(feature = "replicon")]
+    pub use crate::replicon::{
+        ControlledBy, IntentMessage, LocallyControlled, LocomotionInterpolation, LocomotionPose,
+        LocomotionReplicationPlugin, ServerSnapshot, TickSync,
+    };
+    #[cfg(feature = "rumble")]
+    pub use crate::rumble::{LocomotionRumble, LocomotionRumblePlugin, RumbleEffect};
+    #[cfg(feature = "speedrun")]
+    pub use crate::speedrun::{
+        CourseFinish, CourseFinished, CourseGhost, CourseGhostStyle, CourseRecord, CourseStart,
+        CourseStarted, CourseTimer, SpeedrunPlugin,
+    };
+    #[cfg(feature = "test-gym")]
+    pub use crate::test_gym::{
+        spawn_calibration_course, CalibrationCourse, CalibrationRange, GymLabel, TestGym,
+        TestGymPlugin,
     };
+    #[cfg(feature = "config-asset")]
+    pub use crate::tuning::{LocomotionTuning, LocomotionTuningHandle, LocomotionTuningPlugin};
     pub use crate::BevyLocomotionPlugin;
 }
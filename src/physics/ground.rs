@@ -13,6 +13,15 @@ pub struct GroundHit {
     pub point: Vec3,
     pub normal: Vec3,
     pub distance: f32,
+    /// Collider that was hit
+    pub entity: Entity,
+}
+
+impl GroundHit {
+    /// Whether the surface is no steeper than `max_slope_angle` (degrees)
+    pub fn is_walkable(&self, max_slope_angle: f32) -> bool {
+        self.normal.dot(Vec3::Y) >= max_slope_angle.to_radians().cos()
+    }
 }
 
 /// Performs ground detection for a character
@@ -59,6 +68,7 @@ pub fn detect_ground(
                 point: hit.point1,
                 normal: hit.normal1,
                 distance: hit.distance,
+                entity: hit.entity,
             });
         }
     }
@@ -1,7 +1,9 @@
 mod ground;
 mod layers;
 mod plugin;
+mod probe;
 
 pub use ground::*;
 pub use layers::*;
 pub use plugin::PhysicsPlugin;
+pub use probe::*;
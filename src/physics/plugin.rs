@@ -1,13 +1,18 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
+use crate::player::LocomotionSchedule;
+
 /// Plugin that sets up the Avian3D physics engine
+///
+/// Avian runs in `LocomotionSchedule::physics` (`FixedPostUpdate` by default).
 pub struct PhysicsPlugin;
 
 impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
+        let schedule = app.world().get_resource::<LocomotionSchedule>().copied();
         app.add_plugins(
-            PhysicsPlugins::default()
+            PhysicsPlugins::new(schedule.unwrap_or_default().physics)
                 .with_length_unit(1.0), // 1 unit = 1 meter
         );
 
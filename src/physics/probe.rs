@@ -0,0 +1,260 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::GroundHit;
+use crate::player::PlayerConfig;
+
+/// Grabbable ledge found by `probe_ledge`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LedgeHit {
+    /// Point on the ledge's top surface at the wall
+    pub surface_point: Vec3,
+    /// Normal of the wall below the ledge
+    pub wall_normal: Vec3,
+    /// Wall collider, to check for `LedgeGrabbable`
+    pub wall: Entity,
+}
+
+/// Step found by `probe_step`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StepHit {
+    /// Point on the step's top surface
+    pub point: Vec3,
+    pub normal: Vec3,
+    /// How far the body is lifted to stand on the step
+    pub height: f32,
+    /// Step collider
+    pub entity: Entity,
+}
+
+/// Vaultable obstacle found by `probe_vault`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VaultHit {
+    /// Point on the obstacle's top surface at its near edge
+    pub surface_point: Vec3,
+    /// Normal of the obstacle's near face
+    pub wall_normal: Vec3,
+    /// Height of the top above the feet
+    pub height: f32,
+    /// Thickness of the obstacle along the probe direction
+    pub depth: f32,
+    /// Obstacle collider
+    pub wall: Entity,
+}
+
+/// Ground below a standing body centered at `position`, as grounding sees it.
+///
+/// `distance` is measured from `position`. The hit may be too steep to stand
+/// on; check it with `GroundHit::is_walkable(config.movement.max_slope_angle)`.
+pub fn probe_ground(
+    spatial_query: &SpatialQuery,
+    position: Vec3,
+    config: &PlayerConfig,
+) -> Option<GroundHit> {
+    // The capsule's curved bottom sits higher above slopes than flat ground.
+    // Vertical distance from center to slope = (halfHeight - radius) + radius/cos(angle).
+    // Using radius as the margin handles slopes up to ~60°.
+    let max_distance = config.collider.stand_height / 2.0 + config.collider.radius;
+    let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+    let hit = spatial_query.cast_ray(position, Dir3::NEG_Y, max_distance, true, &filter)?;
+    Some(GroundHit {
+        point: position - Vec3::Y * hit.distance,
+        normal: hit.normal,
+        distance: hit.distance,
+        entity: hit.entity,
+    })
+}
+
+/// Ledge a body centered at `position` and moving along `direction` would
+/// grab, ignoring jump input and cooldown.
+///
+/// Three rays: at head height it must be open, at chest height it must hit a
+/// wall, and down onto the wall top it must hit an upward surface between the
+/// body center and just above the head.
+pub fn probe_ledge(
+    spatial_query: &SpatialQuery,
+    position: Vec3,
+    direction: Dir3,
+    config: &PlayerConfig,
+) -> Option<LedgeHit> {
+    let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+    let half_height = config.collider.stand_height / 2.0;
+    let probe_dist = config.collider.radius + config.ledge.detect_reach;
+
+    // Ray 1: head height — must MISS (open air above ledge)
+    let head = position + Vec3::Y * half_height;
+    if spatial_query.cast_ray(head, direction, probe_dist, true, &filter).is_some() {
+        return None;
+    }
+
+    // Ray 2: chest height — must HIT (wall exists)
+    let chest = position + Vec3::Y * (half_height * 0.3);
+    let wall_hit = spatial_query.cast_ray(chest, direction, probe_dist, true, &filter)?;
+
+    // Ray 3: downward from above the wall hit point — must HIT with upward normal
+    let wall_point = chest + *direction * wall_hit.distance;
+    let top = Vec3::new(wall_point.x, head.y + 0.3, wall_point.z);
+    let ledge_hit = spatial_query.cast_ray(top, Dir3::NEG_Y, half_height * 2.0, true, &filter)?;
+    if ledge_hit.normal.dot(Vec3::Y) < 0.7 {
+        return None;
+    }
+
+    // The ledge must be between the body center and just above the head
+    let surface_y = top.y - ledge_hit.distance;
+    if surface_y < position.y || surface_y > position.y + half_height + 0.5 {
+        return None;
+    }
+
+    Some(LedgeHit {
+        surface_point: Vec3::new(wall_point.x, surface_y, wall_point.z),
+        wall_normal: wall_hit.normal,
+        wall: wall_hit.entity,
+    })
+}
+
+/// Step a grounded standing body centered at `position` would climb moving
+/// along `direction`.
+///
+/// Three rays: at the ankle it must hit an obstacle, at `step_up_height` it
+/// must be open, and down onto the obstacle it must hit an upward surface.
+pub fn probe_step(
+    spatial_query: &SpatialQuery,
+    position: Vec3,
+    direction: Dir3,
+    config: &PlayerConfig,
+) -> Option<StepHit> {
+    let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+    let half_height = config.collider.stand_height / 2.0;
+    let step_up_height = config.movement.step_up_height;
+    let probe_dist = config.collider.radius + 0.15;
+
+    // Ray 1: foot height (ankle) — must HIT (obstacle exists)
+    let foot = position + Vec3::Y * (-half_height + 0.05);
+    let foot_hit = spatial_query.cast_ray(foot, direction, probe_dist, true, &filter)?;
+
+    // Ray 2: step height — must MISS (space above obstacle)
+    let step = position + Vec3::Y * (-half_height + step_up_height);
+    if spatial_query.cast_ray(step, direction, probe_dist, true, &filter).is_some() {
+        return None;
+    }
+
+    // Ray 3: downward from step height at obstacle distance — must HIT with upward normal
+    let obstacle = foot + *direction * foot_hit.distance;
+    let top = Vec3::new(obstacle.x, step.y, obstacle.z);
+    let surface_hit = spatial_query.cast_ray(top, Dir3::NEG_Y, step_up_height, true, &filter)?;
+    if surface_hit.normal.dot(Vec3::Y) < 0.7 {
+        return None;
+    }
+
+    let surface_y = top.y - surface_hit.distance;
+    Some(StepHit {
+        point: Vec3::new(obstacle.x, surface_y, obstacle.z),
+        normal: surface_hit.normal,
+        height: surface_y + half_height - position.y,
+        entity: surface_hit.entity,
+    })
+}
+
+/// Obstacle a standing body centered at `position` would vault moving along
+/// `direction`, ignoring jump input and speed.
+///
+/// Three rays: above step height it must hit a wall, down onto the wall just
+/// past its face it must hit an upward top within the vault heights, and back
+/// toward the wall from `max_depth` beyond it must hit the far face. The body,
+/// lifted `clearance` above the top, must then fit over it.
+pub fn probe_vault(
+    spatial_query: &SpatialQuery,
+    position: Vec3,
+    direction: Dir3,
+    config: &PlayerConfig,
+) -> Option<VaultHit> {
+    let collider = &config.collider;
+    let vault = &config.vault;
+    let filter = SpatialQueryFilter::default().with_mask(collider.world_layer);
+    let half_height = collider.stand_height / 2.0;
+    let feet = position - Vec3::Y * half_height;
+    let probe_dist = collider.radius + vault.detect_reach;
+
+    // Ray 1: just above step height — must HIT a wall (too tall to step onto)
+    let low = feet + Vec3::Y * (config.movement.step_up_height + 0.05);
+    let wall_hit = spatial_query.cast_ray(low, direction, probe_dist, true, &filter)?;
+    if wall_hit.normal.dot(Vec3::Y).abs() > 0.7 {
+        return None;
+    }
+
+    // Ray 2: down onto the top just past the wall face — must HIT with upward
+    // normal. Starting inside a taller wall hits at once and fails the height check.
+    let wall_point = low + *direction * wall_hit.distance;
+    let top = Vec3::new(wall_point.x, feet.y + vault.max_height + 0.05, wall_point.z)
+        + *direction * 0.05;
+    let top_hit = spatial_query.cast_ray(top, Dir3::NEG_Y, top.y - low.y, true, &filter)?;
+    if top_hit.normal.dot(Vec3::Y) < 0.7 {
+        return None;
+    }
+    let surface_y = top.y - top_hit.distance;
+    let height = surface_y - feet.y;
+    if height < vault.min_height || height > vault.max_height {
+        return None;
+    }
+
+    // Ray 3: back toward the wall from past the thickest vault — must HIT the
+    // far face (starting inside means the obstacle is too deep)
+    let beyond = Vec3::new(wall_point.x, surface_y - 0.05, wall_point.z)
+        + *direction * vault.max_depth;
+    let far_hit = spatial_query.cast_ray(beyond, -direction, vault.max_depth, true, &filter)?;
+    if far_hit.distance <= 0.0 {
+        return None;
+    }
+    let depth = vault.max_depth - far_hit.distance;
+
+    // The body must rise above the near edge and fit over the top to past the
+    // far face. Slightly narrowed and lifted so the ground and wall don't count.
+    let shape =
+        Collider::capsule(collider.radius * 0.9, collider.stand_height - collider.radius * 2.0);
+    let apex = Vec3::new(wall_point.x, surface_y + vault.clearance + half_height, wall_point.z)
+        - *direction * collider.radius;
+    let start = position + Vec3::Y * 0.05;
+    let (rise, rise_length) = Dir3::new_and_length(apex - start).ok()?;
+    let path = [(start, rise, rise_length), (apex, direction, depth + collider.radius * 2.0)];
+    for (origin, dir, distance) in path {
+        let cast_config = ShapeCastConfig {
+            max_distance: distance,
+            ..default()
+        };
+        if spatial_query
+            .cast_shape(&shape, origin, Quat::IDENTITY, dir, &cast_config, &filter)
+            .is_some()
+        {
+            return None;
+        }
+    }
+
+    Some(VaultHit {
+        surface_point: Vec3::new(wall_point.x, surface_y, wall_point.z),
+        wall_normal: wall_hit.normal,
+        height,
+        depth,
+        wall: wall_hit.entity,
+    })
+}
+
+/// Whether a body with its feet at `feet` has room to stand up.
+///
+/// Checks the standing body above crouch height, slightly narrowed so walls
+/// it is pressed against don't count; this is the check for getting up from
+/// a crouch.
+pub fn can_stand(spatial_query: &SpatialQuery, feet: Vec3, config: &PlayerConfig) -> bool {
+    let collider = &config.collider;
+    let height_diff = collider.stand_height - collider.crouch_height;
+    let shape = Collider::capsule(collider.radius * 0.9, height_diff);
+    let filter = SpatialQueryFilter::default().with_mask(collider.world_layer);
+    let origin = feet + Vec3::Y * (collider.crouch_height + height_diff / 2.0);
+    let cast_config = ShapeCastConfig {
+        max_distance: 0.01,
+        ..default()
+    };
+
+    spatial_query
+        .cast_shape(&shape, origin, Quat::IDENTITY, Dir3::Y, &cast_config, &filter)
+        .is_none()
+}
@@ -0,0 +1,174 @@
+use avian3d::prelude::*;
+use bevy::ecs::system::{SystemParam, SystemState};
+use bevy::prelude::*;
+
+use super::cooldown::{Cooldown, Cooldowns};
+use super::prediction::Resimulating;
+use super::state::*;
+use crate::camera::{CameraPitch, CameraRig, LedgeClimbBob, LedgeShuffleBob};
+use crate::physics::can_stand;
+
+/// Sent when the arbiter settles a conflict between two abilities
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum AbilityConflict {
+    /// `ability` was refused because `active` outranks or ties it (sent every
+    /// tick the activation is attempted)
+    Denied {
+        player: Entity,
+        ability: Ability,
+        active: Ability,
+    },
+    /// `ability` was cancelled so the higher priority `by` could start
+    Preempted {
+        player: Entity,
+        ability: Ability,
+        by: Ability,
+    },
+}
+
+/// Decides whether an ability may start, from `PlayerConfig::abilities`.
+///
+/// Ability detection systems ask it right before inserting their state; it
+/// removes any lower priority ability in a shared group and reports every
+/// decision as an `AbilityConflict` (except on replayed ticks).
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct AbilityArbiter<'w, 's> {
+    active_query: Query<
+        'w,
+        's,
+        (
+            Has<Sliding>,
+            Has<ForcedSliding>,
+            Has<OnLadder>,
+            Has<LedgeGrabbing>,
+            Has<Vaulting>,
+        ),
+        With<Player>,
+    >,
+    writer: MessageWriter<'w, AbilityConflict>,
+    resimulating: Option<Res<'w, Resimulating>>,
+}
+
+impl AbilityArbiter<'_, '_> {
+    /// Abilities currently active on `player`
+    pub fn active(&self, player: Entity) -> impl Iterator<Item = Ability> {
+        let (slide, forced_slide, ladder, ledge, vault) =
+            self.active_query.get(player).unwrap_or_default();
+        let active = [slide, forced_slide, ladder, ledge, vault];
+        Ability::ALL.into_iter().zip(active).filter_map(|(ability, on)| on.then_some(ability))
+    }
+
+    /// Whether `player` may start `ability`.
+    ///
+    /// On success the abilities it outranks are cancelled through `commands`.
+    pub fn request(
+        &mut self,
+        commands: &mut Commands,
+        player: Entity,
+        ability: Ability,
+        config: &AbilityConfig,
+    ) -> bool {
+        let rule = config.rule(ability);
+        let conflicts = |other: &Ability| *other != ability && rule.excludes(&config.rule(*other));
+
+        let blocker = self
+            .active(player)
+            .filter(conflicts)
+            .find(|other| config.rule(*other).priority >= rule.priority);
+        if let Some(active) = blocker {
+            self.report(AbilityConflict::Denied {
+                player,
+                ability,
+                active,
+            });
+            return false;
+        }
+
+        let preempted: Vec<Ability> = self.active(player).filter(conflicts).collect();
+        for other in preempted {
+            cancel(commands, player, other);
+            self.report(AbilityConflict::Preempted {
+                player,
+                ability: other,
+                by: ability,
+            });
+        }
+        true
+    }
+
+    /// Replayed ticks already reported their decisions when first predicted
+    fn report(&mut self, conflict: AbilityConflict) {
+        if self.resimulating.is_none() {
+            self.writer.write(conflict);
+        }
+    }
+}
+
+/// Removes an ability's state components; a ledge is released with the same
+/// cleanup as dropping off it
+fn cancel(commands: &mut Commands, player: Entity, ability: Ability) {
+    match ability {
+        Ability::Slide => {
+            commands.entity(player).remove::<Sliding>();
+        }
+        Ability::ForcedSlide => {
+            commands.entity(player).remove::<ForcedSliding>();
+        }
+        Ability::Ladder => {
+            commands.entity(player).remove::<OnLadder>();
+        }
+        Ability::Ledge => release_ledge(commands, player),
+        Ability::Vault => {
+            commands.entity(player).remove::<Vaulting>();
+        }
+    }
+}
+
+/// Lets go of a ledge like dropping off it does: removes `LedgeGrabbing` and
+/// `LedgeClimbing`, clears the ledge camera bobs on the rig and starts the grab
+/// cooldown. A climbing player also stands up if there is room.
+///
+/// Queued, so it only needs `Commands`; does nothing if the player isn't on a
+/// ledge by then.
+pub(super) fn release_ledge(commands: &mut Commands, player: Entity) {
+    commands.queue(move |world: &mut World| {
+        let Ok(entity) = world.get_entity(player) else {
+            return;
+        };
+        let climbing = entity.contains::<LedgeClimbing>();
+        if !climbing && !entity.contains::<LedgeGrabbing>() {
+            return;
+        }
+        let (Some(config), Some(transform)) =
+            (entity.get::<PlayerConfig>().copied(), entity.get::<Transform>().copied())
+        else {
+            return;
+        };
+        let crouching = entity.contains::<Crouching>();
+        let pitch = entity.get::<CameraRig>().and_then(|rig| {
+            rig.entities()
+                .iter()
+                .copied()
+                .find(|&e| world.get::<CameraPitch>(e).is_some())
+        });
+
+        let stand_up = climbing && crouching && {
+            let feet = transform.translation - Vec3::Y * config.collider.crouch_height / 2.0;
+            let mut spatial_query = SystemState::<SpatialQuery>::new(world);
+            can_stand(&spatial_query.get_mut(world), feet, &config)
+        };
+
+        let mut entity = world.entity_mut(player);
+        entity.remove::<(LedgeGrabbing, LedgeClimbing)>();
+        if stand_up {
+            entity.remove::<Crouching>();
+        }
+        if let Some(mut cooldowns) = entity.get_mut::<Cooldowns>() {
+            cooldowns.start(Cooldown::LedgeGrab, config.ledge.cooldown);
+        }
+        if let Some(pitch) = pitch {
+            world.entity_mut(pitch).remove::<(LedgeShuffleBob, LedgeClimbBob)>();
+        }
+    });
+}
@@ -1,5 +1,8 @@
+use avian3d::prelude::*;
 use bevy::prelude::*;
 
+use super::determinism::LocomotionDeterminism;
+use super::events::{contact_normal, contact_position, surface_material, SurfaceMaterial};
 use super::state::*;
 
 /// Audio event messages emitted by the player controller.
@@ -7,13 +10,68 @@ use super::state::*;
 /// Consumers subscribe with `MessageReader<PlayerAudioMessage>` to trigger
 /// sound effects, particles, or other feedback.
 #[derive(Message, Clone, Debug)]
-pub enum PlayerAudioMessage {
-    Footstep { speed: f32 },
+pub struct PlayerAudioMessage {
+    /// Player the sound belongs to
+    pub player: Entity,
+    pub sound: PlayerSound,
+    /// Where the sound comes from: the ground contact under the player while
+    /// grounded, otherwise the bottom of its capsule
+    pub position: Vec3,
+    /// Normal of the ladder, ledge wall or ground being touched (`Vec3::Y` in the air)
+    pub normal: Vec3,
+    /// Entity the player is standing on, to pick surface-specific sounds
+    pub surface: Option<Entity>,
+    /// `SurfaceMaterial` of `surface`, if it has one
+    pub material: Option<SurfaceMaterial>,
+}
+
+/// Gameplay sounds reported by `PlayerAudioMessage`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlayerSound {
+    /// Horizontal speed in m/s, and the stance's `FootstepConfig` volume scale
+    Footstep { speed: f32, volume: f32 },
     Landed { impact_speed: f32 },
     Jumped,
+    /// Horizontal speed in m/s
+    SprintStart { speed: f32 },
+    /// Horizontal speed in m/s
+    SprintEnd { speed: f32 },
+    /// `slide` when crouching to start a slide
+    CrouchStart { slide: bool },
+    /// `slide` when standing up out of a slide
+    CrouchEnd { slide: bool },
+    SlideStart,
+    /// Sent every `SLIDE_LOOP_INTERVAL` while sliding or force-sliding, to drive a looping scrape
+    SlideLoop { speed: f32 },
+    SlideEnd,
+    LedgeGrabbed,
+    /// Hand placed while shuffling along a ledge: `1.0` right, `-1.0` left
+    LedgeShuffleStep { direction: f32 },
+    LedgeClimbStarted,
+    LedgeClimbFinished,
+    WallJumped,
+    SteppedUp,
+    LadderEnter,
+    LadderExit,
+    ForcedSlideStart,
+    ForcedSlideEnd,
+}
+
+/// `PlayerSound` without its data, for looking sounds up by kind
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PlayerSoundKind {
+    Footstep,
+    Landed,
+    Jumped,
+    SprintStart,
+    SprintEnd,
+    CrouchStart,
+    CrouchEnd,
     SlideStart,
+    SlideLoop,
     SlideEnd,
     LedgeGrabbed,
+    LedgeShuffleStep,
     LedgeClimbStarted,
     LedgeClimbFinished,
     WallJumped,
@@ -24,125 +82,266 @@ pub enum PlayerAudioMessage {
     ForcedSlideEnd,
 }
 
-/// Tracks previous-frame state for edge detection in audio event emission.
-#[derive(Resource, Default)]
+impl PlayerSound {
+    pub fn kind(&self) -> PlayerSoundKind {
+        match self {
+            Self::Footstep { .. } => PlayerSoundKind::Footstep,
+            Self::Landed { .. } => PlayerSoundKind::Landed,
+            Self::Jumped => PlayerSoundKind::Jumped,
+            Self::SprintStart { .. } => PlayerSoundKind::SprintStart,
+            Self::SprintEnd { .. } => PlayerSoundKind::SprintEnd,
+            Self::CrouchStart { .. } => PlayerSoundKind::CrouchStart,
+            Self::CrouchEnd { .. } => PlayerSoundKind::CrouchEnd,
+            Self::SlideStart => PlayerSoundKind::SlideStart,
+            Self::SlideLoop { .. } => PlayerSoundKind::SlideLoop,
+            Self::SlideEnd => PlayerSoundKind::SlideEnd,
+            Self::LedgeGrabbed => PlayerSoundKind::LedgeGrabbed,
+            Self::LedgeShuffleStep { .. } => PlayerSoundKind::LedgeShuffleStep,
+            Self::LedgeClimbStarted => PlayerSoundKind::LedgeClimbStarted,
+            Self::LedgeClimbFinished => PlayerSoundKind::LedgeClimbFinished,
+            Self::WallJumped => PlayerSoundKind::WallJumped,
+            Self::SteppedUp => PlayerSoundKind::SteppedUp,
+            Self::LadderEnter => PlayerSoundKind::LadderEnter,
+            Self::LadderExit => PlayerSoundKind::LadderExit,
+            Self::ForcedSlideStart => PlayerSoundKind::ForcedSlideStart,
+            Self::ForcedSlideEnd => PlayerSoundKind::ForcedSlideEnd,
+        }
+    }
+}
+
+/// Seconds between `PlayerSound::SlideLoop` updates
+pub const SLIDE_LOOP_INTERVAL: f32 = 0.1;
+
+/// Seconds of shuffling per hand placement: one cycle of the camera's shuffle bob
+const SHUFFLE_STEP_TIME: f32 = std::f32::consts::TAU / 10.0;
+
+/// Tracks previous-frame state for edge detection in audio event emission (lives on the player)
+#[derive(Component, Default)]
 pub struct AudioTracker {
     pub was_grounded: bool,
+    pub was_sprinting: bool,
+    pub was_crouching: bool,
     pub was_sliding: bool,
     pub was_ledge_grabbing: bool,
     pub was_ledge_climbing: bool,
     pub was_on_ladder: bool,
     pub was_forced_sliding: bool,
     pub last_vertical_velocity: f32,
-    pub footstep_timer: f32,
+    pub slide_loop_timer: f32,
+    /// Distance walked since the last footstep
+    pub stride_distance: f32,
+    /// Position last frame, to measure how far the player actually moved
+    pub last_position: Option<Vec3>,
+    /// Grabbed ledge point last frame
+    pub ledge_point: Option<Vec3>,
+    /// Distance shuffled since the last hand placement
+    pub shuffle_distance: f32,
 }
 
 /// Compares current player state against `AudioTracker` and emits
 /// `PlayerAudioMessage` events for state transitions.
+#[allow(clippy::type_complexity)]
 pub fn emit_player_audio_messages(
-    query: Query<
+    mut query: Query<
         (
-            &PlayerConfig,
-            &PlayerVelocity,
+            Entity,
+            (&Transform, &PlayerConfig, &PlayerVelocity),
+            &mut AudioTracker,
+            (Option<&GroundNormal>, Option<&GroundEntity>, Option<&GroundPoint>),
+            (Option<&LedgeGrabbing>, Option<&OnLadder>),
             Has<Grounded>,
+            Has<Sprinting>,
+            (Has<Crouching>, Has<PendingSlide>),
             Has<Sliding>,
-            Has<LedgeGrabbing>,
             Has<LedgeClimbing>,
-            Has<OnLadder>,
             Has<ForcedSliding>,
+            Option<&TimeDilation>,
         ),
-        With<Player>,
+        (With<Player>, Without<LocomotionDisabled>),
     >,
-    mut tracker: ResMut<AudioTracker>,
+    material_query: Query<&SurfaceMaterial>,
+    collider_query: Query<&ColliderOf>,
     mut writer: MessageWriter<PlayerAudioMessage>,
+    determinism: Res<LocomotionDeterminism>,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
+    let mut messages = Vec::new();
 
-    let Ok((config, velocity, grounded, sliding, ledge_grabbing, ledge_climbing, on_ladder, forced_sliding)) =
-        query.single()
-    else {
-        return;
-    };
+    for (
+        player,
+        (transform, config, velocity),
+        mut tracker,
+        (ground_normal, ground, ground_point),
+        (ledge, ladder),
+        grounded,
+        sprinting,
+        (crouching, pending_slide),
+        sliding,
+        ledge_climbing,
+        forced_sliding,
+        dilation,
+    ) in &mut query
+    {
+        let dt = player_dt(time.delta_secs(), dilation);
+        let ledge_grabbing = ledge.is_some();
+        let on_ladder = ladder.is_some();
+        let position = contact_position(transform, config, crouching || sliding, ground_point);
+        let normal = contact_normal(ground_normal, ledge, ladder);
+        let surface = ground.map(|ground| ground.0);
+        let material = surface
+            .and_then(|surface| surface_material(surface, &material_query, &collider_query));
+        let mut play = |sound| {
+            messages.push(PlayerAudioMessage {
+                player,
+                sound,
+                position,
+                normal,
+                surface,
+                material,
+            });
+        };
 
-    // --- Landing ---
-    if !tracker.was_grounded && grounded {
-        let impact_speed = (-tracker.last_vertical_velocity).max(0.0);
-        if impact_speed > 1.0 {
-            writer.write(PlayerAudioMessage::Landed { impact_speed });
+        // --- Landing ---
+        if !tracker.was_grounded && grounded {
+            let impact_speed = (-tracker.last_vertical_velocity).max(0.0);
+            if impact_speed > 1.0 {
+                play(PlayerSound::Landed { impact_speed });
+            }
+            tracker.stride_distance = 0.0;
         }
-        tracker.footstep_timer = 0.0;
-    }
 
-    // --- Jumped ---
-    if tracker.was_grounded && !grounded && velocity.y > 0.0 {
-        writer.write(PlayerAudioMessage::Jumped);
-    }
+        // --- Jumped ---
+        if tracker.was_grounded && !grounded && velocity.y > 0.0 {
+            play(PlayerSound::Jumped);
+        }
 
-    // --- Footsteps ---
-    if grounded {
+        // --- Footsteps ---
+        // Distance actually covered, so walls and slides don't produce steps. Capped by
+        // the player's own speed so teleports and moving platforms don't either.
         let h_speed = Vec2::new(velocity.x, velocity.z).length();
-        if h_speed > 0.5 {
-            let speed_ratio = h_speed / config.walk_speed;
-            let interval = 0.5 / speed_ratio;
-            tracker.footstep_timer += dt;
-            if tracker.footstep_timer >= interval {
-                tracker.footstep_timer -= interval;
-                writer.write(PlayerAudioMessage::Footstep { speed: h_speed });
+        let moved = tracker
+            .last_position
+            .map_or(0.0, |last| (transform.translation - last).xz().length());
+        if grounded && !sliding && h_speed > 0.5 {
+            let (stride, volume) = config.footsteps.for_stance(crouching, sprinting);
+            tracker.stride_distance += moved.min(h_speed * dt);
+            if tracker.stride_distance >= stride {
+                tracker.stride_distance -= stride;
+                play(PlayerSound::Footstep {
+                    speed: h_speed,
+                    volume,
+                });
             }
         } else {
-            tracker.footstep_timer = 0.0;
+            tracker.stride_distance = 0.0;
         }
-    }
 
-    // --- Slide ---
-    if !tracker.was_sliding && sliding {
-        writer.write(PlayerAudioMessage::SlideStart);
-    }
-    if tracker.was_sliding && !sliding {
-        writer.write(PlayerAudioMessage::SlideEnd);
-    }
+        // --- Sprint ---
+        if tracker.was_sprinting != sprinting {
+            let speed = Vec2::new(velocity.x, velocity.z).length();
+            play(if sprinting {
+                PlayerSound::SprintStart { speed }
+            } else {
+                PlayerSound::SprintEnd { speed }
+            });
+        }
 
-    // --- Wall jump (must check before ledge grab transition) ---
-    if tracker.was_ledge_grabbing && !ledge_grabbing && !ledge_climbing && velocity.y > 0.0 {
-        writer.write(PlayerAudioMessage::WallJumped);
-    }
+        // --- Crouch ---
+        if !tracker.was_crouching && crouching {
+            play(PlayerSound::CrouchStart {
+                slide: sliding || pending_slide,
+            });
+        }
+        if tracker.was_crouching && !crouching {
+            play(PlayerSound::CrouchEnd {
+                slide: tracker.was_sliding,
+            });
+        }
 
-    // --- Ledge grab ---
-    if !tracker.was_ledge_grabbing && ledge_grabbing {
-        writer.write(PlayerAudioMessage::LedgeGrabbed);
-    }
+        // --- Slide ---
+        if !tracker.was_sliding && sliding {
+            play(PlayerSound::SlideStart);
+        }
+        if tracker.was_sliding && !sliding {
+            play(PlayerSound::SlideEnd);
+        }
 
-    // --- Ledge climb ---
-    if !tracker.was_ledge_climbing && ledge_climbing {
-        writer.write(PlayerAudioMessage::LedgeClimbStarted);
-    }
-    if tracker.was_ledge_climbing && !ledge_climbing {
-        writer.write(PlayerAudioMessage::LedgeClimbFinished);
-    }
+        if sliding || forced_sliding {
+            tracker.slide_loop_timer += dt;
+            if tracker.slide_loop_timer >= SLIDE_LOOP_INTERVAL {
+                tracker.slide_loop_timer -= SLIDE_LOOP_INTERVAL;
+                play(PlayerSound::SlideLoop { speed: velocity.length() });
+            }
+        } else {
+            tracker.slide_loop_timer = 0.0;
+        }
 
-    // --- Ladder ---
-    if !tracker.was_on_ladder && on_ladder {
-        writer.write(PlayerAudioMessage::LadderEnter);
-    }
-    if tracker.was_on_ladder && !on_ladder {
-        writer.write(PlayerAudioMessage::LadderExit);
-    }
+        // --- Wall jump (must check before ledge grab transition) ---
+        if tracker.was_ledge_grabbing && !ledge_grabbing && !ledge_climbing && velocity.y > 0.0 {
+            play(PlayerSound::WallJumped);
+        }
 
-    // --- Forced slide ---
-    if !tracker.was_forced_sliding && forced_sliding {
-        writer.write(PlayerAudioMessage::ForcedSlideStart);
-    }
-    if tracker.was_forced_sliding && !forced_sliding {
-        writer.write(PlayerAudioMessage::ForcedSlideEnd);
+        // --- Ledge grab ---
+        if !tracker.was_ledge_grabbing && ledge_grabbing {
+            play(PlayerSound::LedgeGrabbed);
+        }
+
+        // --- Ledge shuffle ---
+        match (ledge, tracker.ledge_point) {
+            (Some(ledge), Some(last_point)) => {
+                // Right of a player facing the wall
+                let right = ledge.wall_normal.cross(Vec3::NEG_Y);
+                let moved = (ledge.surface_point - last_point).dot(right);
+                let stride = config.ledge.shuffle_speed * SHUFFLE_STEP_TIME;
+                tracker.shuffle_distance += moved.abs();
+                if moved != 0.0 && tracker.shuffle_distance >= stride {
+                    tracker.shuffle_distance -= stride;
+                    play(PlayerSound::LedgeShuffleStep {
+                        direction: moved.signum(),
+                    });
+                }
+            }
+            _ => tracker.shuffle_distance = 0.0,
+        }
+
+        // --- Ledge climb ---
+        if !tracker.was_ledge_climbing && ledge_climbing {
+            play(PlayerSound::LedgeClimbStarted);
+        }
+        if tracker.was_ledge_climbing && !ledge_climbing {
+            play(PlayerSound::LedgeClimbFinished);
+        }
+
+        // --- Ladder ---
+        if !tracker.was_on_ladder && on_ladder {
+            play(PlayerSound::LadderEnter);
+        }
+        if tracker.was_on_ladder && !on_ladder {
+            play(PlayerSound::LadderExit);
+        }
+
+        // --- Forced slide ---
+        if !tracker.was_forced_sliding && forced_sliding {
+            play(PlayerSound::ForcedSlideStart);
+        }
+        if tracker.was_forced_sliding && !forced_sliding {
+            play(PlayerSound::ForcedSlideEnd);
+        }
+
+        // --- Update tracker ---
+        tracker.was_grounded = grounded;
+        tracker.was_sprinting = sprinting;
+        tracker.was_crouching = crouching;
+        tracker.was_sliding = sliding;
+        tracker.was_ledge_grabbing = ledge_grabbing;
+        tracker.was_ledge_climbing = ledge_climbing;
+        tracker.was_on_ladder = on_ladder;
+        tracker.was_forced_sliding = forced_sliding;
+        tracker.last_vertical_velocity = velocity.y;
+        tracker.last_position = Some(transform.translation);
+        tracker.ledge_point = ledge.map(|ledge| ledge.surface_point);
     }
 
-    // --- Update tracker ---
-    tracker.was_grounded = grounded;
-    tracker.was_sliding = sliding;
-    tracker.was_ledge_grabbing = ledge_grabbing;
-    tracker.was_ledge_climbing = ledge_climbing;
-    tracker.was_on_ladder = on_ladder;
-    tracker.was_forced_sliding = forced_sliding;
-    tracker.last_vertical_velocity = velocity.y;
+    determinism.order(&mut messages, |message| message.player);
+    writer.write_batch(messages);
 }
@@ -0,0 +1,223 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+/// A single physical input that can be bound to an action
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+    /// Mouse motion (axis actions only)
+    MouseMotion,
+    /// Left gamepad stick (axis actions only)
+    LeftStick,
+    /// Right gamepad stick (axis actions only)
+    RightStick,
+}
+
+/// Which physical devices drive a player (lives on the player).
+///
+/// For local multiplayer, give each player its own device: bindings for other
+/// device kinds are dropped at spawn, and gamepad input is read only from the
+/// assigned gamepad.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InputDevice {
+    /// Keyboard, mouse, and every gamepad
+    #[default]
+    Any,
+    /// Keyboard and mouse only
+    KeyboardMouse,
+    /// A single gamepad entity
+    Gamepad(Entity),
+}
+
+impl InputDevice {
+    /// Whether this device assignment reads from `gamepad`
+    pub fn reads_gamepad(self, gamepad: Entity) -> bool {
+        match self {
+            Self::Any => true,
+            Self::KeyboardMouse => false,
+            Self::Gamepad(assigned) => assigned == gamepad,
+        }
+    }
+
+    /// Whether this device assignment reads keyboard, mouse, and touch
+    pub fn reads_keyboard_mouse(self) -> bool {
+        !matches!(self, Self::Gamepad(_))
+    }
+
+    fn accepts(self, binding: InputBinding) -> bool {
+        match binding {
+            InputBinding::Key(_) | InputBinding::Mouse(_) | InputBinding::MouseMotion => {
+                self.reads_keyboard_mouse()
+            }
+            InputBinding::Gamepad(_) | InputBinding::LeftStick | InputBinding::RightStick => {
+                self != Self::KeyboardMouse
+            }
+        }
+    }
+}
+
+/// The full binding set for the player actions.
+///
+/// With the `serde` feature this can be saved and loaded in any serde format so
+/// rebinds persist across sessions. Pass it to `spawn_player_with_bindings`;
+/// the active input backend maps it to its own binding types.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct InputBindings {
+    pub move_forward: Vec<InputBinding>,
+    pub move_back: Vec<InputBinding>,
+    pub move_left: Vec<InputBinding>,
+    pub move_right: Vec<InputBinding>,
+    /// Analog movement (sticks)
+    pub move_axis: Vec<InputBinding>,
+    /// Analog look (mouse motion, sticks)
+    pub look: Vec<InputBinding>,
+    pub jump: Vec<InputBinding>,
+    pub sprint: Vec<InputBinding>,
+    pub crouch: Vec<InputBinding>,
+    pub freelook: Vec<InputBinding>,
+    /// Use / interact
+    pub interact: Vec<InputBinding>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self {
+            move_forward: vec![InputBinding::Key(KeyCode::KeyW)],
+            move_back: vec![InputBinding::Key(KeyCode::KeyS)],
+            move_left: vec![InputBinding::Key(KeyCode::KeyA)],
+            move_right: vec![InputBinding::Key(KeyCode::KeyD)],
+            move_axis: vec![InputBinding::LeftStick],
+            look: vec![InputBinding::MouseMotion, InputBinding::RightStick],
+            jump: vec![
+                InputBinding::Key(KeyCode::Space),
+                InputBinding::Gamepad(GamepadButton::South),
+            ],
+            sprint: vec![
+                InputBinding::Key(KeyCode::ShiftLeft),
+                InputBinding::Gamepad(GamepadButton::LeftTrigger),
+            ],
+            crouch: vec![
+                InputBinding::Key(KeyCode::ControlLeft),
+                InputBinding::Gamepad(GamepadButton::RightThumb),
+            ],
+            freelook: vec![
+                InputBinding::Key(KeyCode::AltLeft),
+                InputBinding::Gamepad(GamepadButton::LeftThumb),
+            ],
+            interact: vec![
+                InputBinding::Key(KeyCode::KeyE),
+                InputBinding::Gamepad(GamepadButton::West),
+            ],
+        }
+    }
+}
+
+/// An input bound more than once in an `InputBindings` set
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DuplicateBinding {
+    pub binding: InputBinding,
+    /// Action that already uses the input
+    pub first: &'static str,
+    /// Action that binds it again
+    pub second: &'static str,
+}
+
+impl fmt::Display for DuplicateBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} is bound to both `{}` and `{}`",
+            self.binding, self.first, self.second
+        )
+    }
+}
+
+impl std::error::Error for DuplicateBinding {}
+
+impl InputBindings {
+    /// Bindings per action, in a fixed order with action names
+    pub fn actions(&self) -> [(&'static str, &[InputBinding]); 11] {
+        [
+            ("move_forward", &self.move_forward),
+            ("move_back", &self.move_back),
+            ("move_left", &self.move_left),
+            ("move_right", &self.move_right),
+            ("move_axis", &self.move_axis),
+            ("look", &self.look),
+            ("jump", &self.jump),
+            ("sprint", &self.sprint),
+            ("crouch", &self.crouch),
+            ("freelook", &self.freelook),
+            ("interact", &self.interact),
+        ]
+    }
+
+    /// This binding set with inputs from other device kinds removed
+    pub fn for_device(&self, device: InputDevice) -> Self {
+        let mut bindings = self.clone();
+        for inputs in bindings.actions_mut() {
+            inputs.retain(|&input| device.accepts(input));
+        }
+        bindings
+    }
+
+    fn actions_mut(&mut self) -> [&mut Vec<InputBinding>; 11] {
+        [
+            &mut self.move_forward,
+            &mut self.move_back,
+            &mut self.move_left,
+            &mut self.move_right,
+            &mut self.move_axis,
+            &mut self.look,
+            &mut self.jump,
+            &mut self.sprint,
+            &mut self.crouch,
+            &mut self.freelook,
+            &mut self.interact,
+        ]
+    }
+
+    /// Checks that no input is bound twice, across or within actions
+    pub fn validate(&self) -> Result<(), DuplicateBinding> {
+        let mut seen: Vec<(InputBinding, &'static str)> = Vec::new();
+        for (action, bindings) in self.actions() {
+            for &binding in bindings {
+                if let Some(&(_, first)) = seen.iter().find(|(b, _)| *b == binding) {
+                    return Err(DuplicateBinding {
+                        binding,
+                        first,
+                        second: action,
+                    });
+                }
+                seen.push((binding, action));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_gamepad_bindings_move_and_look() {
+        let gamepad = InputDevice::Gamepad(Entity::PLACEHOLDER);
+        let bindings = InputBindings::default().for_device(gamepad);
+
+        assert_eq!(bindings.move_axis, [InputBinding::LeftStick]);
+        assert_eq!(bindings.look, [InputBinding::RightStick]);
+        assert!(bindings.move_forward.is_empty());
+    }
+
+    #[test]
+    fn default_bindings_are_unique() {
+        assert_eq!(InputBindings::default().validate(), Ok(()));
+    }
+}
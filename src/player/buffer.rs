@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+use super::input::{CrouchInput, JumpPressed};
+use super::state::*;
+
+/// Inputs that can be pressed slightly before they become valid
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BufferedInput {
+    /// Crouch press, upgraded to a slide if slide conditions are met in time
+    Crouch,
+    /// Jump press while airborne, used to grab a ledge reached shortly after
+    LedgeGrab,
+    /// Dash press for dash abilities; press it from your own input handling
+    Dash,
+}
+
+impl BufferedInput {
+    const COUNT: usize = 3;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Seconds since each buffered input was last pressed (lives on the player).
+///
+/// Windows are configured per input in `PlayerConfig` (`crouch_slide.crouch_buffer`,
+/// `ledge.grab_buffer`, `movement.dash_buffer`). Jump keeps its own `JumpBuffer`.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputBuffer {
+    ages: [Option<f32>; BufferedInput::COUNT],
+    crouch_was_held: bool,
+}
+
+impl Default for InputBuffer {
+    fn default() -> Self {
+        Self {
+            ages: [None; BufferedInput::COUNT],
+            crouch_was_held: false,
+        }
+    }
+}
+
+impl InputBuffer {
+    /// Records a press of `input` now
+    pub fn press(&mut self, input: BufferedInput) {
+        self.ages[input.index()] = Some(0.0);
+    }
+
+    /// Whether `input` was pressed within `window` seconds
+    pub fn is_buffered(&self, input: BufferedInput, window: f32) -> bool {
+        self.ages[input.index()].is_some_and(|age| age <= window)
+    }
+
+    /// Consumes a buffered press of `input`, returning whether there was one
+    pub fn consume(&mut self, input: BufferedInput, window: f32) -> bool {
+        let buffered = self.is_buffered(input, window);
+        self.ages[input.index()] = None;
+        buffered
+    }
+
+    /// Drops a pending press without using it
+    pub fn clear(&mut self, input: BufferedInput) {
+        self.ages[input.index()] = None;
+    }
+}
+
+/// Ages buffered presses and records new crouch and ledge grab presses.
+/// Runs before the state systems that consume the buffer.
+#[allow(clippy::type_complexity)]
+pub fn update_input_buffer(
+    mut query: Query<
+        (
+            &mut InputBuffer,
+            &CrouchInput,
+            &JumpPressed,
+            Has<Grounded>,
+            Option<&TimeDilation>,
+        ),
+        (With<Player>, Without<LocomotionDisabled>),
+    >,
+    time: Res<Time>,
+) {
+    for (mut buffer, crouch, jump, grounded, dilation) in &mut query {
+        let dt = player_dt(time.delta_secs(), dilation);
+        for age in buffer.ages.iter_mut().flatten() {
+            *age += dt;
+        }
+
+        if crouch.0 && !buffer.crouch_was_held {
+            buffer.press(BufferedInput::Crouch);
+        }
+        buffer.crouch_was_held = crouch.0;
+
+        if jump.0 && !grounded {
+            buffer.press(BufferedInput::LedgeGrab);
+        }
+    }
+}
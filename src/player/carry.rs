@@ -0,0 +1,252 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::input::UsePressed;
+use super::interact::{Interactable, Interacted};
+use super::state::*;
+use crate::camera::{CameraRig, FpsCamera};
+use crate::physics::GameLayer;
+
+/// Marker for dynamic bodies a player can pick up with use.
+///
+/// Place it on a rigid body whose collider is on the same entity; its
+/// `CollisionLayers` are swapped while carried.
+#[derive(Component, Default)]
+#[require(Interactable, CollisionLayers)]
+pub struct Carryable;
+
+/// Carry and throw settings and state (opt-in: insert on a player).
+///
+/// Use picks up the hovered `Carryable` and drops it again; throw with
+/// `CarryMessage::Throw`.
+#[derive(Component, Clone, Debug)]
+pub struct Carrier {
+    /// Distance in front of the camera the object is held at
+    pub hold_distance: f32,
+    /// Spring stiffness pulling the object to the hold point (1/s)
+    pub stiffness: f32,
+    /// Fastest the spring moves the object (m/s)
+    pub max_speed: f32,
+    /// The object drops when it ends up farther than this from the hold point
+    pub break_distance: f32,
+    /// Heaviest body that can be picked up (kg)
+    pub max_mass: f32,
+    /// Speed along the view added to the player's velocity on a throw (m/s)
+    pub throw_speed: f32,
+    /// Object currently carried
+    pub carried: Option<Entity>,
+}
+
+impl Default for Carrier {
+    fn default() -> Self {
+        Self {
+            hold_distance: 1.5,
+            stiffness: 15.0,
+            max_speed: 20.0,
+            break_distance: 2.0,
+            max_mass: 40.0,
+            throw_speed: 10.0,
+            carried: None,
+        }
+    }
+}
+
+/// Lives on an object while it is carried.
+///
+/// The object is moved off the player's collision mask so it can't push the
+/// capsule or be stood on, and out of its probes; its layers and gravity are
+/// restored on release.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Carried {
+    /// Player carrying the object
+    pub by: Entity,
+    layers: CollisionLayers,
+    gravity: Option<GravityScale>,
+}
+
+/// Picks up, drops or throws objects from game code
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum CarryMessage {
+    /// Pick up `target` if the player carries nothing and it is a light enough `Carryable`
+    PickUp { player: Entity, target: Entity },
+    /// Let go, keeping the object's velocity
+    Drop { player: Entity },
+    /// Let go with the player's velocity plus `Carrier::throw_speed` along the view
+    Throw { player: Entity },
+}
+
+/// Turns use presses while carrying into `CarryMessage::Drop`, before they
+/// reach the interaction system
+pub fn drop_on_use(
+    mut query: Query<(Entity, &Carrier, &mut UsePressed), Without<LocomotionDisabled>>,
+    mut writer: MessageWriter<CarryMessage>,
+) {
+    for (player, carrier, mut use_pressed) in &mut query {
+        if use_pressed.0 && carrier.carried.is_some() {
+            use_pressed.0 = false;
+            writer.write(CarryMessage::Drop { player });
+        }
+    }
+}
+
+/// Turns interactions with a `Carryable` into `CarryMessage::PickUp`
+pub fn pick_up_on_interact(
+    mut reader: MessageReader<Interacted>,
+    mut writer: MessageWriter<CarryMessage>,
+    carrier_query: Query<(), With<Carrier>>,
+    carryable_query: Query<(), With<Carryable>>,
+) {
+    for interaction in reader.read() {
+        if carrier_query.contains(interaction.player)
+            && carryable_query.contains(interaction.target)
+        {
+            writer.write(CarryMessage::PickUp {
+                player: interaction.player,
+                target: interaction.target,
+            });
+        }
+    }
+}
+
+/// Where a player holds objects from, and the direction it looks
+fn view(
+    player: Entity,
+    transform: &Transform,
+    basis: &MovementBasis,
+    rig_query: &Query<&CameraRig>,
+    camera_query: &Query<&GlobalTransform, With<FpsCamera>>,
+) -> (Vec3, Vec3) {
+    rig_query
+        .get(player)
+        .ok()
+        .and_then(|rig| camera_query.iter_many(rig.entities()).next())
+        .map_or((transform.translation, basis.forward()), |camera| {
+            (camera.translation(), camera.forward().as_vec3())
+        })
+}
+
+/// Applies `CarryMessage`s
+#[allow(clippy::type_complexity)]
+pub fn handle_carry_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<CarryMessage>,
+    mut player_query: Query<
+        (&mut Carrier, &Transform, &MovementBasis, &PlayerVelocity, &PlayerConfig),
+        With<Player>,
+    >,
+    mut body_query: Query<
+        (
+            &RigidBody,
+            &mut CollisionLayers,
+            &mut LinearVelocity,
+            Option<&GravityScale>,
+            Option<&ComputedMass>,
+            Option<&Carried>,
+        ),
+        (With<Carryable>, Without<Player>),
+    >,
+    rig_query: Query<&CameraRig>,
+    camera_query: Query<&GlobalTransform, With<FpsCamera>>,
+) {
+    for message in reader.read() {
+        match *message {
+            CarryMessage::PickUp { player, target } => {
+                let Ok((mut carrier, _, _, _, config)) = player_query.get_mut(player) else {
+                    continue;
+                };
+                let Ok((body, mut layers, _, gravity, mass, carried)) = body_query.get_mut(target)
+                else {
+                    continue;
+                };
+                let too_heavy = mass.is_some_and(|mass| mass.value() > carrier.max_mass);
+                let taken = carrier.carried.is_some() || carried.is_some();
+                if taken || too_heavy || !body.is_dynamic() {
+                    continue;
+                }
+
+                commands.entity(target).insert((
+                    Carried {
+                        by: player,
+                        layers: *layers,
+                        gravity: gravity.copied(),
+                    },
+                    GravityScale(0.0),
+                ));
+                // Off the player's mask, so neither the capsule nor its probes see it
+                let mut memberships = layers.memberships & !config.collider.collision_mask;
+                if memberships == LayerMask::NONE {
+                    memberships = GameLayer::Default.into();
+                }
+                let filters = layers.filters & !config.collider.player_layer;
+                *layers = CollisionLayers::new(memberships, filters);
+                carrier.carried = Some(target);
+            }
+            CarryMessage::Drop { player } | CarryMessage::Throw { player } => {
+                let Ok((mut carrier, transform, basis, velocity, _)) = player_query.get_mut(player)
+                else {
+                    continue;
+                };
+                let Some(target) = carrier.carried.take() else {
+                    continue;
+                };
+                let Ok((_, mut layers, mut linear_velocity, _, _, Some(&carried))) =
+                    body_query.get_mut(target)
+                else {
+                    continue;
+                };
+
+                *layers = carried.layers;
+                let mut target_commands = commands.entity(target);
+                target_commands.remove::<Carried>();
+                match carried.gravity {
+                    Some(gravity) => target_commands.insert(gravity),
+                    None => target_commands.remove::<GravityScale>(),
+                };
+                if matches!(message, CarryMessage::Throw { .. }) {
+                    let (_, forward) = view(player, transform, basis, &rig_query, &camera_query);
+                    linear_velocity.0 = velocity.0 + forward * carrier.throw_speed;
+                }
+            }
+        }
+    }
+}
+
+/// Springs carried objects toward the hold point in front of the camera and
+/// drops them when they get stuck too far away.
+///
+/// The object keeps its own collisions with the world, so it can't be pushed
+/// through walls.
+#[allow(clippy::type_complexity)]
+pub fn hold_carried_objects(
+    mut player_query: Query<(Entity, &mut Carrier, &Transform, &MovementBasis), With<Player>>,
+    mut body_query: Query<
+        (&Transform, &mut LinearVelocity, &mut AngularVelocity),
+        (With<Carryable>, Without<Player>),
+    >,
+    rig_query: Query<&CameraRig>,
+    camera_query: Query<&GlobalTransform, With<FpsCamera>>,
+    mut writer: MessageWriter<CarryMessage>,
+    time: Res<Time>,
+) {
+    for (player, mut carrier, transform, basis) in &mut player_query {
+        let Some(target) = carrier.carried else {
+            continue;
+        };
+        let Ok((body, mut linear_velocity, mut angular_velocity)) = body_query.get_mut(target)
+        else {
+            // Despawned while carried
+            carrier.carried = None;
+            continue;
+        };
+
+        let (eye, forward) = view(player, transform, basis, &rig_query, &camera_query);
+        let offset = eye + forward * carrier.hold_distance - body.translation;
+        if offset.length() > carrier.break_distance {
+            writer.write(CarryMessage::Drop { player });
+            continue;
+        }
+        linear_velocity.0 = (offset * carrier.stiffness).clamp_length_max(carrier.max_speed);
+        // Settle spin so the object doesn't tumble in hand
+        angular_velocity.0 *= (-10.0 * time.delta_secs()).exp();
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+
+use super::prediction::Resimulating;
+use super::state::*;
+
+/// Abilities with a cooldown in `Cooldowns`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cooldown {
+    /// Re-grabbing a ledge after letting go, climbing up or wall jumping
+    /// (`ledge.cooldown`)
+    LedgeGrab,
+    /// For wall jump abilities; start it from your own systems
+    WallJump,
+    /// For dash abilities; start it from your own systems
+    Dash,
+    /// For grapple abilities; start it from your own systems
+    Grapple,
+}
+
+impl Cooldown {
+    const COUNT: usize = 4;
+
+    pub const ALL: [Cooldown; Self::COUNT] =
+        [Cooldown::LedgeGrab, Cooldown::WallJump, Cooldown::Dash, Cooldown::Grapple];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Remaining cooldown of each ability (lives on the player).
+///
+/// Timers run at the player's `TimeDilation` and pause with
+/// `LocomotionDisabled`. A `CooldownReady` message is sent when one runs out.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cooldowns {
+    /// Seconds left and the full duration, per `Cooldown`
+    timers: [(f32, f32); Cooldown::COUNT],
+}
+
+impl Cooldowns {
+    /// Starts (or restarts) `cooldown` for `duration` seconds
+    pub fn start(&mut self, cooldown: Cooldown, duration: f32) {
+        self.timers[cooldown.index()] = (duration.max(0.0), duration.max(0.0));
+    }
+
+    /// Ends `cooldown` now, without a `CooldownReady` message
+    pub fn clear(&mut self, cooldown: Cooldown) {
+        self.timers[cooldown.index()] = (0.0, 0.0);
+    }
+
+    /// Whether `cooldown` has run out
+    pub fn is_ready(&self, cooldown: Cooldown) -> bool {
+        self.remaining(cooldown) <= 0.0
+    }
+
+    /// Seconds until `cooldown` runs out
+    pub fn remaining(&self, cooldown: Cooldown) -> f32 {
+        self.timers[cooldown.index()].0
+    }
+
+    /// Fraction of `cooldown` still to wait, from 1.0 when started to 0.0 when
+    /// ready, for cooldown rings and bars
+    pub fn fraction(&self, cooldown: Cooldown) -> f32 {
+        let (remaining, duration) = self.timers[cooldown.index()];
+        if duration > 0.0 {
+            remaining / duration
+        } else {
+            0.0
+        }
+    }
+
+    /// Cooldowns still running
+    pub fn active(&self) -> impl Iterator<Item = Cooldown> + '_ {
+        Cooldown::ALL.into_iter().filter(|&cooldown| !self.is_ready(cooldown))
+    }
+}
+
+/// Sent when one of a player's cooldowns runs out
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CooldownReady {
+    pub player: Entity,
+    pub cooldown: Cooldown,
+}
+
+/// Counts cooldowns down and reports the ones that run out (not again on
+/// replayed ticks). Runs before the state systems that check them.
+#[allow(clippy::type_complexity)]
+pub fn tick_cooldowns(
+    mut query: Query<
+        (Entity, &mut Cooldowns, Option<&TimeDilation>),
+        (With<Player>, Without<LocomotionDisabled>),
+    >,
+    mut writer: MessageWriter<CooldownReady>,
+    resimulating: Option<Res<Resimulating>>,
+    time: Res<Time>,
+) {
+    for (player, mut cooldowns, dilation) in &mut query {
+        let dt = player_dt(time.delta_secs(), dilation);
+        for cooldown in Cooldown::ALL {
+            let (remaining, _) = &mut cooldowns.timers[cooldown.index()];
+            if *remaining <= 0.0 {
+                continue;
+            }
+            *remaining = (*remaining - dt).max(0.0);
+            if *remaining <= 0.0 && resimulating.is_none() {
+                writer.write(CooldownReady { player, cooldown });
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+/// Determinism settings for lockstep networking and reproducible replays.
+///
+/// Controller randomness is derived from `seed`, the player entity and the
+/// situation (never from a thread RNG), so identical inputs replay identically.
+///
+/// Not covered: commands and ability arbitration still follow query order
+/// (`stable_order` sorts only message writes), and movement accumulates in
+/// `f32` without a fixed-point mode, so replays are exact only on the same
+/// build and platform.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocomotionDeterminism {
+    /// Seed for cosmetic randomness such as the ledge climb roll direction
+    pub seed: u64,
+    /// Write per-player messages (`StateChanged`, `Interacted`, audio) in
+    /// `Entity` order instead of query iteration order. Observers of
+    /// `StateChanged` and `PlayerMovementEvent` fire in the same order.
+    pub stable_order: bool,
+}
+
+impl LocomotionDeterminism {
+    /// Pseudo-random bits for `player`, varied by `salt`
+    pub fn hash(&self, player: Entity, salt: u64) -> u64 {
+        splitmix64(self.seed ^ splitmix64(player.to_bits()) ^ salt)
+    }
+
+    /// Pseudo-random `1.0` or `-1.0` for `player`, varied by `salt`
+    pub fn sign(&self, player: Entity, salt: u64) -> f32 {
+        if self.hash(player, salt) & 1 == 0 {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// Sorts per-player messages by player when `stable_order` is set.
+    ///
+    /// The sort is stable, so each player's messages keep their order.
+    pub fn order<T>(&self, messages: &mut [T], player: impl Fn(&T) -> Entity) {
+        if self.stable_order {
+            messages.sort_by_key(player);
+        }
+    }
+}
+
+/// SplitMix64 finalizer: a cheap, well-mixed, platform-independent hash
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Salt from a world position, so the same situation always rolls the same value
+#[cfg(feature = "ledge")]
+pub(crate) fn position_salt(position: Vec3) -> u64 {
+    let [x, y, z] = position.to_array().map(f32::to_bits);
+    u64::from(x) ^ (u64::from(y) << 21) ^ (u64::from(z) << 42)
+}
@@ -0,0 +1,471 @@
+use avian3d::prelude::*;
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+
+use super::determinism::LocomotionDeterminism;
+use super::state::*;
+
+/// Every movement transition of a player, one message per edge.
+///
+/// Covers what gameplay, UI, analytics and animation usually rebuild with
+/// their own edge detection. Written in `LocomotionSet::ApplyVelocity`, and
+/// triggered on the player for observers when it has `LocomotionObservers`.
+#[derive(Message, EntityEvent, Clone, Copy, Debug, PartialEq)]
+pub struct PlayerMovementEvent {
+    #[event_target]
+    pub player: Entity,
+    pub kind: MovementEventKind,
+    /// Ground contact under the player while grounded, otherwise the bottom
+    /// of its capsule
+    pub position: Vec3,
+    /// Normal of the ladder, ledge wall or ground being touched (`Vec3::Y` in the air)
+    pub normal: Vec3,
+    /// Entity the player is standing on
+    pub surface: Option<Entity>,
+}
+
+/// Transitions reported by `PlayerMovementEvent`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MovementEventKind {
+    /// Horizontal speed in m/s
+    SprintStarted { speed: f32 },
+    /// Horizontal speed in m/s
+    SprintStopped { speed: f32 },
+    /// `slide` when crouching to start a slide
+    CrouchStarted { slide: bool },
+    /// `slide` when standing up out of a slide
+    CrouchStopped { slide: bool },
+    /// Touched down on `ground` falling at `impact_speed` (m/s), `fall_height` meters
+    /// below the highest point of an `air_time` second flight
+    Landed {
+        ground: Option<Entity>,
+        impact_speed: f32,
+        fall_height: f32,
+        air_time: f32,
+    },
+    /// Left the ground, by jumping or by walking off an edge (`jumped: false`)
+    LeftGround { jumped: bool },
+    /// Still falling after `movement.long_fall_time` in the air or past
+    /// `movement.long_fall_speed`, once per flight
+    LongFall { air_time: f32, speed: f32 },
+    /// Reached the top of a jump or launch, `height` meters above takeoff
+    JumpApex { height: f32 },
+    /// Jump released early; upward speed is now `speed` (m/s)
+    JumpCut { speed: f32 },
+    /// Crouch pressed in the air; the slide starts on landing
+    SlideQueued,
+    /// Horizontal speed in m/s
+    SlideStarted { speed: f32 },
+    /// Horizontal speed in m/s
+    SlideEnded { speed: f32 },
+    /// Horizontal speed in m/s
+    ForcedSlideStarted { speed: f32 },
+    /// Horizontal speed in m/s
+    ForcedSlideEnded { speed: f32 },
+    LedgeGrabbed,
+    /// Started shuffling or changed direction: `1.0` right, `-1.0` left
+    LedgeShuffleStarted { direction: f32 },
+    LedgeShuffleStopped,
+    /// Let go without climbing (dropped or wall jumped)
+    LedgeReleased { wall_jump: bool },
+    LedgeClimbStarted,
+    LedgeClimbFinished,
+    VaultStarted,
+    VaultFinished,
+    LadderEntered,
+    LadderExited,
+    /// Ran into a wall at `speed` m/s along its `normal`, at least `movement.wall_hit_speed`
+    HitWall { speed: f32, normal: Vec3 },
+    /// Stepped onto something `height` meters up
+    SteppedUp { height: f32 },
+    /// Walked or landed onto a different ground entity than the last one stood on
+    SurfaceChanged {
+        from: Option<Entity>,
+        to: Entity,
+        from_material: Option<SurfaceMaterial>,
+        to_material: Option<SurfaceMaterial>,
+    },
+    /// Total speed went above (`rising`) or back below one of the player's `SpeedThresholds`
+    SpeedThresholdCrossed { threshold: f32, rising: bool },
+}
+
+/// Speeds (m/s) that trigger `SpeedThresholdCrossed` events, for wind rush and
+/// speed lines.
+///
+/// Falling below a threshold needs `hysteresis` extra m/s, so hovering around
+/// it doesn't send a stream of events.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct SpeedThresholds {
+    /// Ascending speeds
+    pub thresholds: Vec<f32>,
+    pub hysteresis: f32,
+}
+
+impl SpeedThresholds {
+    pub fn new(thresholds: impl IntoIterator<Item = f32>) -> Self {
+        let mut thresholds: Vec<f32> = thresholds.into_iter().collect();
+        thresholds.sort_by(f32::total_cmp);
+        Self {
+            thresholds,
+            hysteresis: 0.5,
+        }
+    }
+}
+
+/// Material tag for surfaces ("grass", "stone", "metal"), for footsteps, decals and ambience.
+///
+/// Place it on the collider entity or on the rigid body that owns the collider.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SurfaceMaterial(pub &'static str);
+
+/// Looks up the `SurfaceMaterial` of a collider or of its rigid body
+pub(crate) fn surface_material(
+    surface: Entity,
+    material_query: &Query<&SurfaceMaterial>,
+    collider_query: &Query<&ColliderOf>,
+) -> Option<SurfaceMaterial> {
+    let body = collider_query.get(surface).map_or(surface, |collider| collider.body);
+    material_query.get(surface).or(material_query.get(body)).ok().copied()
+}
+
+/// Previous-tick state for `PlayerMovementEvent` edge detection (lives on the player)
+#[derive(Component, Clone, Debug, Default)]
+pub struct MovementTracker {
+    pub was_sprinting: bool,
+    pub was_crouching: bool,
+    pub was_grounded: bool,
+    pub was_pending_slide: bool,
+    pub was_sliding: bool,
+    pub was_forced_sliding: bool,
+    pub was_ledge_climbing: bool,
+    pub was_vaulting: bool,
+    pub was_on_ladder: bool,
+    pub was_jump_cut: bool,
+    pub was_against_wall: bool,
+    /// `LongFall` already sent for this flight
+    pub long_fall: bool,
+    /// Body height when last grounded, on a ledge or on a ladder
+    pub takeoff_y: f32,
+    /// Grabbed ledge point, to detect shuffling
+    pub ledge_point: Option<Vec3>,
+    /// Current shuffle direction, `0.0` while still
+    pub shuffle: f32,
+    pub last_vertical_velocity: f32,
+    /// Air time as of the previous tick, since grounding resets it on landing
+    pub last_air_time: AirTime,
+    /// Last ground entity stood on and its material, kept while airborne
+    pub last_surface: Option<(Entity, Option<SurfaceMaterial>)>,
+    /// How many `SpeedThresholds` the player is currently above
+    pub speed_band: usize,
+}
+
+/// Where the player touches the world: the ground contact while grounded,
+/// otherwise the bottom of the player's capsule at its current height
+pub(crate) fn contact_position(
+    transform: &Transform,
+    config: &PlayerConfig,
+    crouching: bool,
+    ground: Option<&GroundPoint>,
+) -> Vec3 {
+    ground.map_or_else(
+        || transform.translation - Vec3::Y * (config.collider.height(crouching) / 2.0),
+        |point| point.0,
+    )
+}
+
+/// Normal of the surface the player is touching: ladder, ledge wall or ground
+pub(crate) fn contact_normal(
+    ground: Option<&GroundNormal>,
+    ledge: Option<&LedgeGrabbing>,
+    ladder: Option<&OnLadder>,
+) -> Vec3 {
+    ladder
+        .map(|ladder| ladder.outward_normal)
+        .or(ledge.map(|ledge| ledge.wall_normal))
+        .or(ground.map(|ground| ground.0))
+        .unwrap_or(Vec3::Y)
+}
+
+/// Compares current player state against `MovementTracker` and writes a
+/// `PlayerMovementEvent` for each transition.
+#[allow(clippy::type_complexity)]
+pub fn emit_movement_events(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            (&Transform, &PlayerConfig, &PlayerVelocity, &AirTime),
+            &mut MovementTracker,
+            (Option<&GroundNormal>, Option<&GroundEntity>, Option<&GroundPoint>),
+            (Option<&LedgeGrabbing>, Option<&OnLadder>),
+            (Has<Sprinting>, Has<Crouching>, Has<Grounded>, Has<PendingSlide>),
+            (Has<Sliding>, Has<ForcedSliding>, Has<LedgeClimbing>, Has<Vaulting>, Has<JumpCut>),
+            (Has<LocomotionObservers>, Option<&SpeedThresholds>),
+        ),
+        (With<Player>, Without<LocomotionDisabled>),
+    >,
+    material_query: Query<&SurfaceMaterial>,
+    collider_query: Query<&ColliderOf>,
+    spatial_query: SpatialQuery,
+    mut writer: MessageWriter<PlayerMovementEvent>,
+    determinism: Res<LocomotionDeterminism>,
+) {
+    let mut events = Vec::new();
+    let mut observers = EntityHashSet::default();
+
+    for (
+        player,
+        (transform, config, velocity, air_time),
+        mut tracker,
+        (ground_normal, ground, ground_point),
+        (ledge, ladder),
+        (sprinting, crouching, grounded, pending_slide),
+        (sliding, forced_sliding, ledge_climbing, vaulting, jump_cut),
+        (observed, thresholds),
+    ) in &mut query
+    {
+        if observed {
+            observers.insert(player);
+        }
+        let on_ladder = ladder.is_some();
+        let position = contact_position(transform, config, crouching || sliding, ground_point);
+        let normal = contact_normal(ground_normal, ledge, ladder);
+        let surface = ground.map(|ground| ground.0);
+        let mut emit = |kind| {
+            events.push(PlayerMovementEvent {
+                player,
+                kind,
+                position,
+                normal,
+                surface,
+            });
+        };
+        let speed = Vec2::new(velocity.x, velocity.z).length();
+
+        // --- Sprint and crouch ---
+        if sprinting != tracker.was_sprinting {
+            emit(if sprinting {
+                MovementEventKind::SprintStarted { speed }
+            } else {
+                MovementEventKind::SprintStopped { speed }
+            });
+        }
+        if crouching != tracker.was_crouching {
+            emit(if crouching {
+                MovementEventKind::CrouchStarted {
+                    slide: sliding || pending_slide,
+                }
+            } else {
+                MovementEventKind::CrouchStopped {
+                    slide: tracker.was_sliding,
+                }
+            });
+        }
+
+        // --- Ground ---
+        if grounded && !tracker.was_grounded {
+            emit(MovementEventKind::Landed {
+                ground: surface,
+                impact_speed: (-tracker.last_vertical_velocity).max(0.0),
+                fall_height: (tracker.last_air_time.peak_y - transform.translation.y).max(0.0),
+                air_time: tracker.last_air_time.duration,
+            });
+        }
+        if !grounded && tracker.was_grounded {
+            emit(MovementEventKind::LeftGround {
+                jumped: velocity.y > 0.0,
+            });
+        }
+        let last_surface = tracker.last_surface.map(|(entity, _)| entity);
+        if let Some(to) = surface.filter(|&to| grounded && Some(to) != last_surface) {
+            let to_material = surface_material(to, &material_query, &collider_query);
+            emit(MovementEventKind::SurfaceChanged {
+                from: last_surface,
+                to,
+                from_material: tracker.last_surface.and_then(|(_, material)| material),
+                to_material,
+            });
+            tracker.last_surface = Some((to, to_material));
+        }
+
+        // --- Jump ---
+        let supported = grounded || ledge.is_some() || ledge_climbing || vaulting || on_ladder;
+        if supported {
+            tracker.takeoff_y = transform.translation.y;
+        } else if tracker.last_vertical_velocity > 0.0 && velocity.y <= 0.0 {
+            emit(MovementEventKind::JumpApex {
+                height: transform.translation.y - tracker.takeoff_y,
+            });
+        }
+        let fall_speed = -velocity.y;
+        let long_time = config.movement.long_fall_time > 0.0
+            && air_time.duration >= config.movement.long_fall_time;
+        let long_speed = config.movement.long_fall_speed > 0.0
+            && fall_speed >= config.movement.long_fall_speed;
+        if supported {
+            tracker.long_fall = false;
+        } else if fall_speed > 0.0 && (long_time || long_speed) && !tracker.long_fall {
+            tracker.long_fall = true;
+            emit(MovementEventKind::LongFall {
+                air_time: air_time.duration,
+                speed: fall_speed,
+            });
+        }
+        if jump_cut && !tracker.was_jump_cut {
+            emit(MovementEventKind::JumpCut { speed: velocity.y });
+        }
+
+        // --- Slide ---
+        if pending_slide && !tracker.was_pending_slide {
+            emit(MovementEventKind::SlideQueued);
+        }
+        if sliding != tracker.was_sliding {
+            emit(if sliding {
+                MovementEventKind::SlideStarted { speed }
+            } else {
+                MovementEventKind::SlideEnded { speed }
+            });
+        }
+        if forced_sliding != tracker.was_forced_sliding {
+            emit(if forced_sliding {
+                MovementEventKind::ForcedSlideStarted { speed }
+            } else {
+                MovementEventKind::ForcedSlideEnded { speed }
+            });
+        }
+
+        // --- Ledge ---
+        match (ledge, tracker.ledge_point) {
+            (Some(_), None) => emit(MovementEventKind::LedgeGrabbed),
+            (None, Some(_)) if !ledge_climbing => emit(MovementEventKind::LedgeReleased {
+                wall_jump: velocity.y > 0.0,
+            }),
+            _ => {}
+        }
+        let shuffle = match (ledge, tracker.ledge_point) {
+            (Some(ledge), Some(last_point)) => {
+                // Right of a player facing the wall
+                let right = ledge.wall_normal.cross(Vec3::NEG_Y);
+                let moved = (ledge.surface_point - last_point).dot(right);
+                if moved.abs() > 1e-4 {
+                    moved.signum()
+                } else {
+                    0.0
+                }
+            }
+            _ => 0.0,
+        };
+        if shuffle != tracker.shuffle {
+            emit(if shuffle != 0.0 {
+                MovementEventKind::LedgeShuffleStarted { direction: shuffle }
+            } else {
+                MovementEventKind::LedgeShuffleStopped
+            });
+        }
+        if ledge_climbing != tracker.was_ledge_climbing {
+            emit(if ledge_climbing {
+                MovementEventKind::LedgeClimbStarted
+            } else {
+                MovementEventKind::LedgeClimbFinished
+            });
+        }
+
+        // --- Vault ---
+        if vaulting != tracker.was_vaulting {
+            emit(if vaulting {
+                MovementEventKind::VaultStarted
+            } else {
+                MovementEventKind::VaultFinished
+            });
+        }
+
+        // --- Ladder ---
+        if on_ladder != tracker.was_on_ladder {
+            emit(if on_ladder {
+                MovementEventKind::LadderEntered
+            } else {
+                MovementEventKind::LadderExited
+            });
+        }
+
+        // --- Wall ---
+        // Probe only when fast enough to matter; touching the wall ends the impact
+        let horizontal = Vec3::new(velocity.x, 0.0, velocity.z);
+        let wall_hit_speed = config.movement.wall_hit_speed;
+        let climbing = ledge.is_some() || ledge_climbing || vaulting || on_ladder;
+        let wall = match Dir3::new(horizontal) {
+            Ok(direction) if wall_hit_speed > 0.0 && speed >= wall_hit_speed && !climbing => {
+                let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+                let max_slope_y = config.movement.max_slope_angle.to_radians().cos();
+                spatial_query
+                    .cast_ray(
+                        transform.translation,
+                        direction,
+                        config.collider.radius + 0.1,
+                        true,
+                        &filter,
+                    )
+                    .filter(|hit| hit.normal.y < max_slope_y)
+            }
+            _ => None,
+        };
+        if let Some(hit) = wall {
+            let speed = -horizontal.dot(hit.normal);
+            if !tracker.was_against_wall && speed >= wall_hit_speed {
+                emit(MovementEventKind::HitWall {
+                    speed,
+                    normal: hit.normal,
+                });
+            }
+        }
+
+        // --- Speed ---
+        if let Some(speeds) = thresholds {
+            let total_speed = velocity.length();
+            let thresholds = &speeds.thresholds;
+            tracker.speed_band = tracker.speed_band.min(thresholds.len());
+            while tracker.speed_band < thresholds.len()
+                && total_speed >= thresholds[tracker.speed_band]
+            {
+                emit(MovementEventKind::SpeedThresholdCrossed {
+                    threshold: thresholds[tracker.speed_band],
+                    rising: true,
+                });
+                tracker.speed_band += 1;
+            }
+            while tracker.speed_band > 0
+                && total_speed < thresholds[tracker.speed_band - 1] - speeds.hysteresis
+            {
+                tracker.speed_band -= 1;
+                emit(MovementEventKind::SpeedThresholdCrossed {
+                    threshold: thresholds[tracker.speed_band],
+                    rising: false,
+                });
+            }
+        }
+
+        // --- Update tracker ---
+        tracker.was_sprinting = sprinting;
+        tracker.was_crouching = crouching;
+        tracker.was_grounded = grounded;
+        tracker.was_pending_slide = pending_slide;
+        tracker.was_sliding = sliding;
+        tracker.was_forced_sliding = forced_sliding;
+        tracker.was_ledge_climbing = ledge_climbing;
+        tracker.was_vaulting = vaulting;
+        tracker.was_on_ladder = on_ladder;
+        tracker.was_jump_cut = jump_cut;
+        tracker.was_against_wall = wall.is_some();
+        tracker.ledge_point = ledge.map(|ledge| ledge.surface_point);
+        tracker.shuffle = shuffle;
+        tracker.last_vertical_velocity = velocity.y;
+        tracker.last_air_time = *air_time;
+    }
+
+    // Observers see the events in the same order as readers
+    determinism.order(&mut events, |event| event.player);
+    for &event in events.iter().filter(|event| observers.contains(&event.player)) {
+        commands.trigger(event);
+    }
+    writer.write_batch(events);
+}
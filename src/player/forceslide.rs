@@ -1,6 +1,7 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
+use super::arbitration::AbilityArbiter;
 use super::state::*;
 
 /// Marker component for world geometry that forces the player to slide downhill.
@@ -9,19 +10,27 @@ pub struct ForceSlide;
 
 /// Detects when a grounded player is standing on a `ForceSlide` surface and
 /// initiates forced sliding in the downhill direction.
+#[allow(clippy::type_complexity)]
 pub fn detect_forced_slide(
     mut commands: Commands,
     spatial_query: SpatialQuery,
     query: Query<
         (Entity, &Transform, &PlayerConfig),
-        (With<Player>, With<Grounded>, Without<ForcedSliding>),
+        (
+            With<Player>,
+            With<Grounded>,
+            Without<ForcedSliding>,
+            Without<CrowdController>,
+            Without<LocomotionDisabled>,
+        ),
     >,
     surface_query: Query<(), With<ForceSlide>>,
     gravity: Res<Gravity>,
+    mut arbiter: AbilityArbiter,
 ) {
     for (entity, transform, config) in &query {
-        let filter = SpatialQueryFilter::default().with_mask(config.world_layer);
-        let ground_check_dist = config.stand_height / 2.0 + 0.2;
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+        let ground_check_dist = config.collider.stand_height / 2.0 + 0.2;
 
         let hit = spatial_query.cast_ray(
             transform.translation,
@@ -53,35 +62,42 @@ pub fn detect_forced_slide(
         if direction.length_squared() < 0.01 {
             continue;
         }
+        if !arbiter.request(&mut commands, entity, Ability::ForcedSlide, &config.abilities) {
+            continue;
+        }
 
         commands.entity(entity).insert(ForcedSliding {
             direction,
             surface_normal: normal,
         });
-
-        // Remove voluntary sliding to avoid conflicts
-        commands.entity(entity).remove::<Sliding>();
     }
 }
 
 /// Accelerates the player in the downhill direction while on a `ForceSlide` surface.
 /// Removes `ForcedSliding` when the player leaves the surface.
+#[allow(clippy::type_complexity)]
 pub fn apply_forced_slide(
     mut commands: Commands,
     spatial_query: SpatialQuery,
     mut query: Query<
-        (Entity, &Transform, &PlayerConfig, &mut PlayerVelocity, &ForcedSliding),
-        With<Player>,
+        (
+            Entity,
+            &Transform,
+            &PlayerConfig,
+            &mut PlayerVelocity,
+            &ForcedSliding,
+            Option<&TimeDilation>,
+        ),
+        (With<Player>, Without<LocomotionDisabled>),
     >,
     surface_query: Query<(), With<ForceSlide>>,
     gravity: Res<Gravity>,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-
-    for (entity, transform, config, mut velocity, forced) in &mut query {
-        let filter = SpatialQueryFilter::default().with_mask(config.world_layer);
-        let ground_check_dist = config.stand_height / 2.0 + 0.2;
+    for (entity, transform, config, mut velocity, forced, dilation) in &mut query {
+        let dt = player_dt(time.delta_secs(), dilation);
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+        let ground_check_dist = config.collider.stand_height / 2.0 + 0.2;
 
         let hit = spatial_query.cast_ray(
             transform.translation,
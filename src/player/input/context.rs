@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+
+use super::{
+    CrouchInput, FreelookInput, JumpHeld, JumpPressed, LookInput, LookStickInput, MoveInput,
+    ReadInputSystems, SprintInput, UsePressed,
+};
+use crate::player::{InputBuffer, JumpBuffer, Player};
+
+/// What a player's input currently drives
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InputContext {
+    /// On-foot locomotion
+    #[default]
+    Gameplay,
+    /// A menu or pause screen has focus
+    Menu,
+    /// The player is driving or riding something
+    Vehicle,
+}
+
+/// Requests to change a player's active input context
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum InputContextMessage {
+    /// Make `context` the active context, remembering the previous one
+    Push {
+        player: Entity,
+        context: InputContext,
+    },
+    /// Return to the context that was active before the last push
+    Pop { player: Entity },
+}
+
+/// Stack of input contexts on a player; the last entry is active.
+///
+/// Locomotion input is read only while `Gameplay` is on top.
+#[derive(Component, Clone, Debug)]
+pub struct InputContextStack(Vec<InputContext>);
+
+impl Default for InputContextStack {
+    fn default() -> Self {
+        Self(vec![InputContext::Gameplay])
+    }
+}
+
+impl InputContextStack {
+    /// The active context
+    pub fn current(&self) -> InputContext {
+        self.0.last().copied().unwrap_or_default()
+    }
+}
+
+/// Present on a player whose locomotion input is suspended by another context
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[component(storage = "SparseSet")]
+pub struct InputSuspended;
+
+/// Applies `InputContextMessage`s and suspends or resumes the player context.
+///
+/// Jump presses and buffers are dropped on both edges, so the button that
+/// confirms a menu does not also jump.
+pub fn handle_input_context_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<InputContextMessage>,
+    mut query: Query<
+        (&mut InputContextStack, &mut JumpPressed, &mut JumpBuffer, &mut InputBuffer),
+        With<Player>,
+    >,
+) {
+    for msg in reader.read() {
+        let player = match *msg {
+            InputContextMessage::Push { player, .. } | InputContextMessage::Pop { player } => {
+                player
+            }
+        };
+        let Ok((mut stack, mut jump_pressed, mut jump_buffer, mut input_buffer)) =
+            query.get_mut(player)
+        else {
+            continue;
+        };
+
+        let was_gameplay = stack.current() == InputContext::Gameplay;
+        match *msg {
+            InputContextMessage::Push { context, .. } => stack.0.push(context),
+            InputContextMessage::Pop { .. } => {
+                // The base context is never popped
+                if stack.0.len() > 1 {
+                    stack.0.pop();
+                }
+            }
+        }
+        let is_gameplay = stack.current() == InputContext::Gameplay;
+        if was_gameplay == is_gameplay {
+            continue;
+        }
+
+        jump_pressed.0 = false;
+        *jump_buffer = JumpBuffer::default();
+        *input_buffer = InputBuffer::default();
+        if is_gameplay {
+            commands.entity(player).remove::<InputSuspended>();
+        } else {
+            commands.entity(player).insert(InputSuspended);
+        }
+    }
+}
+
+/// Zeroes the intent of suspended players after the input backends have run
+#[allow(clippy::type_complexity)]
+pub fn clear_suspended_input(
+    mut query: Query<
+        (
+            &mut MoveInput,
+            &mut LookInput,
+            &mut LookStickInput,
+            &mut SprintInput,
+            &mut CrouchInput,
+            &mut FreelookInput,
+            &mut JumpPressed,
+            &mut JumpHeld,
+            &mut UsePressed,
+        ),
+        (With<Player>, With<InputSuspended>),
+    >,
+) {
+    for (
+        mut move_input,
+        mut look_input,
+        mut look_stick,
+        mut sprint,
+        mut crouch,
+        mut freelook,
+        mut jump_pressed,
+        mut jump_held,
+        mut use_pressed,
+    ) in &mut query
+    {
+        move_input.0 = Vec2::ZERO;
+        look_input.0 = Vec2::ZERO;
+        look_stick.0 = Vec2::ZERO;
+        sprint.set_if_neq(SprintInput(false));
+        crouch.set_if_neq(CrouchInput(false));
+        freelook.set_if_neq(FreelookInput(false));
+        jump_pressed.0 = false;
+        jump_held.0 = false;
+        use_pressed.0 = false;
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    app.add_message::<InputContextMessage>();
+    app.add_systems(
+        PreUpdate,
+        (handle_input_context_messages, clear_suspended_input)
+            .chain()
+            .after(ReadInputSystems),
+    );
+}
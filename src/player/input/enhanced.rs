@@ -0,0 +1,367 @@
+use bevy::ecs::observer::On;
+use bevy::ecs::relationship::RelatedSpawner;
+use bevy::ecs::spawn::SpawnWith;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+use super::{
+    CrouchInput, FreelookInput, JumpHeld, JumpPressed, LookInput, LookStickInput, MoveInput,
+    ReadInputSystems, SprintInput, StickResponse, UsePressed,
+};
+use crate::player::{InputBinding, InputBindings, InputDevice, Player};
+
+/// Move in a direction (WASD)
+#[derive(Debug, InputAction)]
+#[action_output(Vec2)]
+pub struct MoveAction;
+
+/// Move with a stick (shaped by `StickResponse`)
+#[derive(Debug, InputAction)]
+#[action_output(Vec2)]
+pub struct MoveStickAction;
+
+/// Look around (mouse delta)
+#[derive(Debug, InputAction)]
+#[action_output(Vec2)]
+pub struct LookAction;
+
+/// Look around (stick deflection, applied as a turn rate)
+#[derive(Debug, InputAction)]
+#[action_output(Vec2)]
+pub struct LookStickAction;
+
+/// Jump action
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct JumpAction;
+
+/// Sprint action (hold)
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct SprintAction;
+
+/// Crouch action
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct CrouchAction;
+
+/// Freelook action (hold to look around without turning)
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct FreelookAction;
+
+/// Use / interact action
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct UseAction;
+
+/// Digital and stick movement of a player, combined into `MoveInput`
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct MoveSources {
+    pub digital: Vec2,
+    /// After `StickResponse`
+    pub stick: Vec2,
+}
+
+impl MoveSources {
+    fn write(&self, move_input: &mut MoveInput) {
+        move_input.0 = (self.digital + self.stick).clamp_length_max(1.0);
+    }
+}
+
+/// System to handle move input via observer
+pub fn handle_move_input(
+    trigger: On<Fire<MoveAction>>,
+    mut query: Query<(&mut MoveInput, &mut MoveSources)>,
+) {
+    if let Ok((mut move_input, mut sources)) = query.get_mut(trigger.event_target()) {
+        sources.digital = trigger.value;
+        sources.write(&mut move_input);
+    }
+}
+
+/// Clear digital movement when all movement keys are released
+pub fn handle_move_end(
+    trigger: On<Complete<MoveAction>>,
+    mut query: Query<(&mut MoveInput, &mut MoveSources)>,
+) {
+    if let Ok((mut move_input, mut sources)) = query.get_mut(trigger.event_target()) {
+        sources.digital = Vec2::ZERO;
+        sources.write(&mut move_input);
+    }
+}
+
+/// System to handle stick movement via observer; only the stick is shaped
+/// by `StickResponse`
+pub fn handle_move_stick_input(
+    trigger: On<Fire<MoveStickAction>>,
+    mut query: Query<(&mut MoveInput, &mut MoveSources, Option<&StickResponse>)>,
+) {
+    if let Ok((mut move_input, mut sources, response)) = query.get_mut(trigger.event_target()) {
+        sources.stick = response.map_or(trigger.value, |r| r.apply(trigger.value));
+        sources.write(&mut move_input);
+    }
+}
+
+/// Clear stick movement when the stick returns to center
+pub fn handle_move_stick_end(
+    trigger: On<Complete<MoveStickAction>>,
+    mut query: Query<(&mut MoveInput, &mut MoveSources)>,
+) {
+    if let Ok((mut move_input, mut sources)) = query.get_mut(trigger.event_target()) {
+        sources.stick = Vec2::ZERO;
+        sources.write(&mut move_input);
+    }
+}
+
+/// System to handle look input via observer
+pub fn handle_look_input(trigger: On<Fire<LookAction>>, mut query: Query<&mut LookInput>) {
+    if let Ok(mut look_input) = query.get_mut(trigger.event_target()) {
+        look_input.0 = trigger.value;
+    }
+}
+
+/// System to handle stick look input via observer
+pub fn handle_look_stick_input(
+    trigger: On<Fire<LookStickAction>>,
+    mut query: Query<&mut LookStickInput>,
+) {
+    if let Ok(mut look_stick) = query.get_mut(trigger.event_target()) {
+        look_stick.0 = trigger.value;
+    }
+}
+
+/// Clear stick look input when the stick returns to center
+pub fn handle_look_stick_end(
+    trigger: On<Complete<LookStickAction>>,
+    mut query: Query<&mut LookStickInput>,
+) {
+    if let Ok(mut look_stick) = query.get_mut(trigger.event_target()) {
+        look_stick.0 = Vec2::ZERO;
+    }
+}
+
+/// Handle sprint start
+pub fn handle_sprint_start(trigger: On<Start<SprintAction>>, mut query: Query<&mut SprintInput>) {
+    if let Ok(mut sprint) = query.get_mut(trigger.event_target()) {
+        sprint.0 = true;
+    }
+}
+
+/// Handle sprint end
+pub fn handle_sprint_end(trigger: On<Complete<SprintAction>>, mut query: Query<&mut SprintInput>) {
+    if let Ok(mut sprint) = query.get_mut(trigger.event_target()) {
+        sprint.0 = false;
+    }
+}
+
+/// Handle crouch start
+pub fn handle_crouch_start(trigger: On<Start<CrouchAction>>, mut query: Query<&mut CrouchInput>) {
+    if let Ok(mut crouch) = query.get_mut(trigger.event_target()) {
+        crouch.0 = true;
+    }
+}
+
+/// Handle crouch end
+pub fn handle_crouch_end(trigger: On<Complete<CrouchAction>>, mut query: Query<&mut CrouchInput>) {
+    if let Ok(mut crouch) = query.get_mut(trigger.event_target()) {
+        crouch.0 = false;
+    }
+}
+
+/// Handle freelook start
+pub fn handle_freelook_start(
+    trigger: On<Start<FreelookAction>>,
+    mut query: Query<&mut FreelookInput>,
+) {
+    if let Ok(mut freelook) = query.get_mut(trigger.event_target()) {
+        freelook.0 = true;
+    }
+}
+
+/// Handle freelook end
+pub fn handle_freelook_end(
+    trigger: On<Complete<FreelookAction>>,
+    mut query: Query<&mut FreelookInput>,
+) {
+    if let Ok(mut freelook) = query.get_mut(trigger.event_target()) {
+        freelook.0 = false;
+    }
+}
+
+/// Handle jump press
+pub fn handle_jump_start(
+    trigger: On<Start<JumpAction>>,
+    mut pressed_query: Query<&mut JumpPressed>,
+    mut held_query: Query<&mut JumpHeld>,
+) {
+    let entity = trigger.event_target();
+    if let Ok(mut jump) = pressed_query.get_mut(entity) {
+        jump.0 = true;
+    }
+    if let Ok(mut held) = held_query.get_mut(entity) {
+        held.0 = true;
+    }
+}
+
+/// Handle jump release
+pub fn handle_jump_end(trigger: On<Complete<JumpAction>>, mut query: Query<&mut JumpHeld>) {
+    if let Ok(mut held) = query.get_mut(trigger.event_target()) {
+        held.0 = false;
+    }
+}
+
+/// Handle use press
+pub fn handle_use_start(trigger: On<Start<UseAction>>, mut query: Query<&mut UsePressed>) {
+    if let Ok(mut use_pressed) = query.get_mut(trigger.event_target()) {
+        use_pressed.0 = true;
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    if !app.is_plugin_added::<EnhancedInputPlugin>() {
+        app.add_plugins(EnhancedInputPlugin);
+    }
+
+    // Register input context for player
+    app.add_input_context::<Player>();
+    app.configure_sets(PreUpdate, ReadInputSystems.after(EnhancedInputSystems::Apply));
+
+    // Input observers
+    app.add_observer(handle_move_input);
+    app.add_observer(handle_move_end);
+    app.add_observer(handle_move_stick_input);
+    app.add_observer(handle_move_stick_end);
+    app.add_observer(handle_look_input);
+    app.add_observer(handle_look_stick_input);
+    app.add_observer(handle_look_stick_end);
+    app.add_observer(handle_sprint_start);
+    app.add_observer(handle_sprint_end);
+    app.add_observer(handle_crouch_start);
+    app.add_observer(handle_crouch_end);
+    app.add_observer(handle_jump_start);
+    app.add_observer(handle_jump_end);
+    app.add_observer(handle_freelook_start);
+    app.add_observer(handle_freelook_end);
+    app.add_observer(handle_use_start);
+}
+
+impl InputBindings {
+    /// Builds the `bevy_enhanced_input` actions for the player from this binding set
+    pub fn to_actions(&self) -> impl Bundle + use<> {
+        let bindings = self.clone();
+        let actions = Actions::<Player>::spawn(SpawnWith(move |context: &mut ActionSpawner<Player>| {
+            let InputBindings {
+                move_forward,
+                move_back,
+                move_left,
+                move_right,
+                move_axis,
+                look,
+                jump,
+                sprint,
+                crouch,
+                freelook,
+                interact,
+            } = bindings;
+
+            context.spawn((
+                Action::<MoveAction>::new(),
+                Bindings::spawn(SpawnWith(move |spawner: &mut RelatedSpawner<BindingOf>| {
+                    for input in digital(&move_forward) {
+                        spawner.spawn((input, SwizzleAxis::YXZ));
+                    }
+                    for input in digital(&move_back) {
+                        spawner.spawn((input, SwizzleAxis::YXZ, Negate::all()));
+                    }
+                    for input in digital(&move_right) {
+                        spawner.spawn(input);
+                    }
+                    for input in digital(&move_left) {
+                        spawner.spawn((input, Negate::all()));
+                    }
+                })),
+            ));
+            context.spawn((
+                Action::<MoveStickAction>::new(),
+                Bindings::spawn(SpawnWith(move |spawner: &mut RelatedSpawner<BindingOf>| {
+                    spawn_axes(spawner, &move_axis, false);
+                })),
+            ));
+            // Mouse motion is a per-frame delta, sticks a turn rate
+            let (mouse_look, stick_look): (Vec<InputBinding>, Vec<InputBinding>) =
+                look.into_iter().partition(|&input| input == InputBinding::MouseMotion);
+            context.spawn((
+                Action::<LookAction>::new(),
+                Bindings::spawn(SpawnWith(move |spawner: &mut RelatedSpawner<BindingOf>| {
+                    spawn_axes(spawner, &mouse_look, true);
+                })),
+            ));
+            context.spawn((
+                Action::<LookStickAction>::new(),
+                Bindings::spawn(SpawnWith(move |spawner: &mut RelatedSpawner<BindingOf>| {
+                    // Mouse motion is positive downward, sticks upward
+                    spawn_axes(spawner, &stick_look, true);
+                })),
+            ));
+            context.spawn((Action::<JumpAction>::new(), button_bindings(jump)));
+            context.spawn((Action::<SprintAction>::new(), button_bindings(sprint)));
+            context.spawn((Action::<CrouchAction>::new(), button_bindings(crouch)));
+            context.spawn((Action::<FreelookAction>::new(), button_bindings(freelook)));
+            context.spawn((Action::<UseAction>::new(), button_bindings(interact)));
+        }));
+        (actions, MoveSources::default())
+    }
+}
+
+/// Routes gamepad input to the player's assigned gamepad
+pub(super) fn gamepad_device(device: InputDevice) -> GamepadDevice {
+    match device {
+        InputDevice::Any => GamepadDevice::Any,
+        InputDevice::KeyboardMouse => GamepadDevice::None,
+        InputDevice::Gamepad(gamepad) => GamepadDevice::Single(gamepad),
+    }
+}
+
+/// Maps digital inputs to bindings; axis-only inputs are skipped
+fn digital(inputs: &[InputBinding]) -> impl Iterator<Item = Binding> + '_ {
+    inputs.iter().filter_map(|input| match *input {
+        InputBinding::Key(key) => Some(Binding::from(key)),
+        InputBinding::Mouse(button) => Some(Binding::from(button)),
+        InputBinding::Gamepad(button) => Some(Binding::from(button)),
+        InputBinding::MouseMotion | InputBinding::LeftStick | InputBinding::RightStick => None,
+    })
+}
+
+/// Spawns 2D axis bindings; stick Y axes are swizzled onto the action's Y
+fn spawn_axes(
+    spawner: &mut RelatedSpawner<BindingOf>,
+    inputs: &[InputBinding],
+    invert_sticks: bool,
+) {
+    for input in inputs {
+        let (x, y) = match input {
+            InputBinding::MouseMotion => {
+                spawner.spawn(Binding::mouse_motion());
+                continue;
+            }
+            InputBinding::LeftStick => (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+            InputBinding::RightStick => (GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+            _ => continue,
+        };
+        spawner.spawn(Binding::from(x));
+        if invert_sticks {
+            spawner.spawn((Binding::from(y), SwizzleAxis::YXZ, Negate::all()));
+        } else {
+            spawner.spawn((Binding::from(y), SwizzleAxis::YXZ));
+        }
+    }
+}
+
+fn button_bindings(inputs: Vec<InputBinding>) -> impl Bundle {
+    Bindings::spawn(SpawnWith(move |spawner: &mut RelatedSpawner<BindingOf>| {
+        for input in digital(&inputs) {
+            spawner.spawn(input);
+        }
+    }))
+}
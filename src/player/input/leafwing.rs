@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use super::{
+    CrouchInput, FreelookInput, JumpHeld, JumpPressed, LookInput, LookStickInput, MoveInput,
+    ReadInputSystems, SprintInput, StickResponse, UsePressed,
+};
+use crate::player::{InputBinding, InputBindings, Player};
+
+/// Player actions for the leafwing-input-manager backend
+#[derive(Actionlike, PartialEq, Eq, Hash, Clone, Copy, Debug, Reflect)]
+pub enum LocomotionAction {
+    MoveForward,
+    MoveBack,
+    MoveLeft,
+    MoveRight,
+    /// Analog movement (sticks)
+    #[actionlike(DualAxis)]
+    MoveAxis,
+    /// Look delta (mouse motion)
+    #[actionlike(DualAxis)]
+    Look,
+    /// Look rate (sticks)
+    #[actionlike(DualAxis)]
+    LookStick,
+    Jump,
+    Sprint,
+    Crouch,
+    Freelook,
+    Use,
+}
+
+/// Copies leafwing action state into the player input components
+#[allow(clippy::type_complexity)]
+pub fn update_leafwing_input(
+    mut query: Query<
+        (
+            &ActionState<LocomotionAction>,
+            &mut MoveInput,
+            &mut LookInput,
+            &mut LookStickInput,
+            &mut SprintInput,
+            &mut CrouchInput,
+            &mut FreelookInput,
+            &mut JumpPressed,
+            &mut JumpHeld,
+            &mut UsePressed,
+            Option<&StickResponse>,
+        ),
+        With<Player>,
+    >,
+) {
+    for (
+        actions,
+        mut move_input,
+        mut look_input,
+        mut look_stick,
+        mut sprint,
+        mut crouch,
+        mut freelook,
+        mut jump_pressed,
+        mut jump_held,
+        mut use_pressed,
+        response,
+    ) in &mut query
+    {
+        let axis = |action: LocomotionAction| if actions.pressed(&action) { 1.0 } else { 0.0 };
+        let digital = Vec2::new(
+            axis(LocomotionAction::MoveRight) - axis(LocomotionAction::MoveLeft),
+            axis(LocomotionAction::MoveForward) - axis(LocomotionAction::MoveBack),
+        );
+        let stick = actions.axis_pair(&LocomotionAction::MoveAxis);
+        let stick = response.map_or(stick, |r| r.apply(stick));
+        move_input.0 = (digital + stick).clamp_length_max(1.0);
+
+        look_input.0 = actions.axis_pair(&LocomotionAction::Look);
+        look_stick.0 = actions.axis_pair(&LocomotionAction::LookStick);
+        sprint.0 = actions.pressed(&LocomotionAction::Sprint);
+        crouch.0 = actions.pressed(&LocomotionAction::Crouch);
+        freelook.0 = actions.pressed(&LocomotionAction::Freelook);
+
+        // Pressed is cleared by the FixedUpdate consumers, so only ever set it here
+        if actions.just_pressed(&LocomotionAction::Jump) {
+            jump_pressed.0 = true;
+        }
+        jump_held.0 = actions.pressed(&LocomotionAction::Jump);
+        if actions.just_pressed(&LocomotionAction::Use) {
+            use_pressed.0 = true;
+        }
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    if !app.is_plugin_added::<InputManagerPlugin<LocomotionAction>>() {
+        app.add_plugins(InputManagerPlugin::<LocomotionAction>::default());
+    }
+
+    app.configure_sets(PreUpdate, ReadInputSystems.after(InputManagerSystem::Update));
+    app.add_systems(PreUpdate, update_leafwing_input.in_set(ReadInputSystems));
+}
+
+impl InputBindings {
+    /// Builds the leafwing `InputMap` for the player from this binding set
+    pub fn to_input_map(&self) -> (InputMap<LocomotionAction>, ActionState<LocomotionAction>) {
+        let mut map = InputMap::default();
+
+        let buttons = [
+            (LocomotionAction::MoveForward, &self.move_forward),
+            (LocomotionAction::MoveBack, &self.move_back),
+            (LocomotionAction::MoveLeft, &self.move_left),
+            (LocomotionAction::MoveRight, &self.move_right),
+            (LocomotionAction::Jump, &self.jump),
+            (LocomotionAction::Sprint, &self.sprint),
+            (LocomotionAction::Crouch, &self.crouch),
+            (LocomotionAction::Freelook, &self.freelook),
+            (LocomotionAction::Use, &self.interact),
+        ];
+        for (action, inputs) in buttons {
+            for input in inputs {
+                match *input {
+                    InputBinding::Key(key) => {
+                        map.insert(action, key);
+                    }
+                    InputBinding::Mouse(button) => {
+                        map.insert(action, button);
+                    }
+                    InputBinding::Gamepad(button) => {
+                        map.insert(action, button);
+                    }
+                    InputBinding::MouseMotion
+                    | InputBinding::LeftStick
+                    | InputBinding::RightStick => {}
+                }
+            }
+        }
+
+        for input in &self.move_axis {
+            match input {
+                InputBinding::LeftStick => {
+                    map.insert_dual_axis(LocomotionAction::MoveAxis, GamepadStick::LEFT);
+                }
+                InputBinding::RightStick => {
+                    map.insert_dual_axis(LocomotionAction::MoveAxis, GamepadStick::RIGHT);
+                }
+                _ => {}
+            }
+        }
+
+        // Mouse motion is positive downward, sticks upward
+        for input in &self.look {
+            let stick = match input {
+                InputBinding::MouseMotion => {
+                    map.insert_dual_axis(LocomotionAction::Look, MouseMove::default());
+                    continue;
+                }
+                InputBinding::LeftStick => GamepadStick::LEFT,
+                InputBinding::RightStick => GamepadStick::RIGHT,
+                _ => continue,
+            };
+            map.insert_dual_axis(LocomotionAction::LookStick, stick.inverted_y());
+        }
+
+        (map, ActionState::default())
+    }
+}
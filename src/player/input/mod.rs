@@ -0,0 +1,384 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+mod context;
+#[cfg(all(feature = "enhanced-input", not(any(feature = "leafwing", feature = "raw-input"))))]
+mod enhanced;
+#[cfg(feature = "leafwing")]
+mod leafwing;
+#[cfg(all(feature = "raw-input", not(feature = "leafwing")))]
+mod raw;
+#[cfg(feature = "touch")]
+mod touch;
+
+pub use context::*;
+#[cfg(all(feature = "enhanced-input", not(any(feature = "leafwing", feature = "raw-input"))))]
+pub use enhanced::*;
+#[cfg(feature = "leafwing")]
+pub use leafwing::*;
+#[cfg(all(feature = "raw-input", not(feature = "leafwing")))]
+pub use raw::*;
+#[cfg(feature = "touch")]
+pub use touch::*;
+
+use super::{InputBindings, InputDevice};
+
+/// Input backends write the intent components in this set (`PreUpdate`)
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ReadInputSystems;
+
+/// Stores the current movement input vector
+#[derive(Component, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveInput(pub Vec2);
+
+/// Stores the current look input delta
+#[derive(Component, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LookInput(pub Vec2);
+
+/// Stores the current look stick deflection, in the same directions as
+/// `LookInput` (scaled by `CameraConfig::stick_sensitivity` per second)
+#[derive(Component, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LookStickInput(pub Vec2);
+
+/// Stores whether sprint is held
+#[derive(Component, Default, Deref, DerefMut, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SprintInput(pub bool);
+
+/// Stores whether crouch is held
+#[derive(Component, Default, Deref, DerefMut, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrouchInput(pub bool);
+
+/// Stores whether freelook is held
+#[derive(Component, Default, Deref, DerefMut, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreelookInput(pub bool);
+
+/// Stores whether jump was pressed this frame
+#[derive(Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JumpPressed(pub bool);
+
+/// Stores whether jump is currently held
+#[derive(Component, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JumpHeld(pub bool);
+
+/// Stores whether use was pressed (cleared once the interaction system sees it)
+#[derive(Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsePressed(pub bool);
+
+/// The controller's intent interface.
+///
+/// These components fully drive a player: input backends are just one writer.
+/// Bots, replays, and network code can write them directly on a player spawned
+/// without an input context (see `spawn_player_without_input`). `LookInput` is
+/// cleared in `Last`, so write it every frame; `LookStickInput` is held until
+/// rewritten. `JumpPressed` is consumed by the jump systems in `FixedUpdate`
+/// and `UsePressed` by the interaction system.
+#[derive(Bundle, Default)]
+pub struct LocomotionIntent {
+    pub move_input: MoveInput,
+    pub look_input: LookInput,
+    pub look_stick: LookStickInput,
+    pub sprint: SprintInput,
+    pub crouch: CrouchInput,
+    pub freelook: FreelookInput,
+    pub jump_pressed: JumpPressed,
+    pub jump_held: JumpHeld,
+    pub use_pressed: UsePressed,
+}
+
+impl LocomotionIntent {
+    /// Intent with the given movement direction and everything else idle
+    pub fn new(move_input: Vec2) -> Self {
+        Self {
+            move_input: MoveInput(move_input),
+            ..default()
+        }
+    }
+}
+
+/// Clears jump pressed flag each frame (should run at end of frame)
+pub fn clear_jump_pressed(mut query: Query<&mut JumpPressed>) {
+    for mut jump in &mut query {
+        jump.0 = false;
+    }
+}
+
+/// Clears look input each frame
+pub fn clear_look_input(mut query: Query<&mut LookInput>) {
+    for mut look in &mut query {
+        look.0 = Vec2::ZERO;
+    }
+}
+
+/// How the deadzone is measured on a stick
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeadzoneShape {
+    /// Each axis has its own deadzone (snaps to cardinal directions)
+    Axial,
+    /// Deadzone on the stick's distance from center (keeps diagonals smooth)
+    #[default]
+    Radial,
+}
+
+/// Maps stick deflection (0..1, after the deadzone) to output magnitude
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResponseCurve {
+    #[default]
+    Linear,
+    /// `t^exponent`; above 1 gives finer control near center
+    Power(f32),
+    /// Gentle at both ends, fastest through the middle
+    SmoothStep,
+}
+
+impl ResponseCurve {
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::Power(exponent) => t.powf(exponent),
+            Self::SmoothStep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// Deadzone and response curve for the movement stick (lives on the player).
+///
+/// Applied by the input backends before `MoveInput` is written. Digital input
+/// (keys, buttons) is full deflection and passes through unchanged.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StickResponse {
+    pub shape: DeadzoneShape,
+    /// Deflection below this is ignored
+    pub deadzone: f32,
+    /// Deflection above this counts as full
+    pub outer_deadzone: f32,
+    /// Minimum output once past the deadzone (counters game-side deadzones)
+    pub anti_deadzone: f32,
+    pub curve: ResponseCurve,
+}
+
+impl Default for StickResponse {
+    fn default() -> Self {
+        Self {
+            shape: DeadzoneShape::Radial,
+            deadzone: 0.15,
+            outer_deadzone: 0.95,
+            anti_deadzone: 0.0,
+            curve: ResponseCurve::Linear,
+        }
+    }
+}
+
+impl StickResponse {
+    /// Applies the deadzone and curve to a raw stick value
+    pub fn apply(&self, input: Vec2) -> Vec2 {
+        let output = match self.shape {
+            DeadzoneShape::Radial => {
+                let length = input.length();
+                input.normalize_or_zero() * self.magnitude(length)
+            }
+            DeadzoneShape::Axial => Vec2::new(
+                input.x.signum() * self.magnitude(input.x.abs()),
+                input.y.signum() * self.magnitude(input.y.abs()),
+            ),
+        };
+        output.clamp_length_max(1.0)
+    }
+
+    fn magnitude(&self, deflection: f32) -> f32 {
+        if deflection <= self.deadzone {
+            return 0.0;
+        }
+        let range = (self.outer_deadzone - self.deadzone).max(f32::EPSILON);
+        let t = ((deflection - self.deadzone) / range).min(1.0);
+        self.anti_deadzone + (1.0 - self.anti_deadzone) * self.curve.apply(t)
+    }
+}
+
+/// Boolean actions that support a hold/toggle policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoolAction {
+    Sprint,
+    Crouch,
+    Freelook,
+}
+
+/// How a boolean action's raw input maps to its effective state
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActionPolicy {
+    /// Active while held
+    #[default]
+    Hold,
+    /// Each press flips the state
+    Toggle,
+    /// Toggle that also releases on a conflicting state
+    /// (sprint: stopping or crouching; crouch: sprinting or jumping)
+    SmartToggle,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct PolicyState {
+    raw: bool,
+    active: bool,
+}
+
+/// Per-action hold/toggle policies for a player.
+///
+/// Backends and other writers always report the physically held state; the
+/// policy turns it into the effective `SprintInput` / `CrouchInput` /
+/// `FreelookInput` at the start of each fixed tick. Actions without an entry
+/// use `Hold`.
+#[derive(Component, Clone, Debug, Default)]
+pub struct InputPolicies {
+    pub policies: HashMap<BoolAction, ActionPolicy>,
+    states: HashMap<BoolAction, PolicyState>,
+}
+
+impl InputPolicies {
+    pub fn with(mut self, action: BoolAction, policy: ActionPolicy) -> Self {
+        self.policies.insert(action, policy);
+        self
+    }
+
+    pub fn policy(&self, action: BoolAction) -> ActionPolicy {
+        self.policies.get(&action).copied().unwrap_or_default()
+    }
+
+    /// Resolves one action; `written` is the component value if a writer changed it
+    fn resolve(&mut self, action: BoolAction, written: Option<bool>, conflict: bool) -> bool {
+        let policy = self.policy(action);
+        let state = self.states.entry(action).or_default();
+        let raw = written.unwrap_or(state.raw);
+        let pressed = raw && !state.raw;
+        state.raw = raw;
+
+        state.active = match policy {
+            ActionPolicy::Hold => raw,
+            ActionPolicy::Toggle => state.active ^ pressed,
+            ActionPolicy::SmartToggle => (state.active ^ pressed) && !(conflict && !pressed),
+        };
+        state.active
+    }
+}
+
+/// Applies `InputPolicies` to the boolean intent components.
+///
+/// A component counts as written when its change tick is newer than this
+/// system's last run, which works for both event-driven and per-frame writers.
+#[allow(clippy::type_complexity)]
+pub fn apply_input_policies(
+    mut query: Query<(
+        &mut InputPolicies,
+        Mut<SprintInput>,
+        Mut<CrouchInput>,
+        Mut<FreelookInput>,
+        &MoveInput,
+        &JumpPressed,
+    )>,
+) {
+    for (mut policies, mut sprint, mut crouch, mut freelook, move_input, jump) in &mut query {
+        let written = |changed: bool, value: bool| changed.then_some(value);
+        let sprint_written = written(sprint.is_changed(), sprint.0);
+        let crouch_written = written(crouch.is_changed(), crouch.0);
+        let freelook_written = written(freelook.is_changed(), freelook.0);
+
+        let sprint_pressed = sprint_written.unwrap_or(false)
+            && !policies.states.get(&BoolAction::Sprint).is_some_and(|s| s.raw);
+
+        let crouch_active = policies.resolve(
+            BoolAction::Crouch,
+            crouch_written,
+            sprint_pressed || jump.0,
+        );
+        let sprint_active = policies.resolve(
+            BoolAction::Sprint,
+            sprint_written,
+            move_input.length_squared() < 0.01 || crouch_active,
+        );
+        let freelook_active = policies.resolve(BoolAction::Freelook, freelook_written, false);
+
+        sprint.set_if_neq(SprintInput(sprint_active));
+        crouch.set_if_neq(CrouchInput(crouch_active));
+        freelook.set_if_neq(FreelookInput(freelook_active));
+    }
+}
+
+/// Registers input contexts, the enabled input backend, and touch controls.
+///
+/// Backend features are additive: with several enabled, `leafwing` wins over
+/// `raw-input`, which wins over the default `enhanced-input`, so adding a
+/// backend feature on top of the defaults switches to it.
+pub(crate) fn add_input_backend(app: &mut App) {
+    app.configure_sets(PreUpdate, ReadInputSystems.after(bevy::input::InputSystems));
+    context::build(app);
+
+    #[cfg(all(feature = "enhanced-input", not(any(feature = "leafwing", feature = "raw-input"))))]
+    enhanced::build(app);
+    #[cfg(feature = "leafwing")]
+    leafwing::build(app);
+    #[cfg(all(feature = "raw-input", not(feature = "leafwing")))]
+    raw::build(app);
+    #[cfg(feature = "touch")]
+    touch::build(app);
+}
+
+/// Input backend components for a newly spawned player
+#[cfg(all(feature = "enhanced-input", not(any(feature = "leafwing", feature = "raw-input"))))]
+pub(crate) fn input_bundle(
+    bindings: &InputBindings,
+    device: InputDevice,
+) -> impl Bundle + use<> {
+    (bindings.to_actions(), enhanced::gamepad_device(device))
+}
+
+/// Input backend components for a newly spawned player
+#[cfg(feature = "leafwing")]
+pub(crate) fn input_bundle(
+    bindings: &InputBindings,
+    device: InputDevice,
+) -> impl Bundle + use<> {
+    let (mut input_map, action_state) = bindings.to_input_map();
+    if let InputDevice::Gamepad(gamepad) = device {
+        input_map.set_gamepad(gamepad);
+    }
+    (input_map, action_state)
+}
+
+/// Input backend components for a newly spawned player
+#[cfg(all(feature = "raw-input", not(feature = "leafwing")))]
+pub(crate) fn input_bundle(
+    bindings: &InputBindings,
+    _device: InputDevice,
+) -> impl Bundle + use<> {
+    bindings.to_raw_bindings()
+}
+
+/// No backend enabled; bindings are ignored
+#[cfg(not(any(feature = "enhanced-input", feature = "leafwing", feature = "raw-input")))]
+pub(crate) fn input_bundle(
+    _bindings: &InputBindings,
+    _device: InputDevice,
+) -> impl Bundle + use<> {
+}
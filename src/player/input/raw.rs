@@ -0,0 +1,154 @@
+use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::prelude::*;
+
+use super::{
+    CrouchInput, FreelookInput, JumpHeld, JumpPressed, LookInput, LookStickInput, MoveInput,
+    ReadInputSystems, SprintInput, StickResponse, UsePressed,
+};
+use crate::player::{InputBinding, InputBindings, InputDevice, Player};
+
+/// Binding set read by the raw input backend (lives on the player)
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut)]
+pub struct RawInputBindings(pub InputBindings);
+
+/// Device state shared by all raw input reads in a frame
+struct RawDevices<'a> {
+    keys: &'a ButtonInput<KeyCode>,
+    mouse_buttons: &'a ButtonInput<MouseButton>,
+    mouse_motion: Vec2,
+    gamepads: Vec<&'a Gamepad>,
+}
+
+impl RawDevices<'_> {
+    fn pressed(&self, inputs: &[InputBinding]) -> bool {
+        inputs.iter().any(|input| match *input {
+            InputBinding::Key(key) => self.keys.pressed(key),
+            InputBinding::Mouse(button) => self.mouse_buttons.pressed(button),
+            InputBinding::Gamepad(button) => self.gamepads.iter().any(|g| g.pressed(button)),
+            _ => false,
+        })
+    }
+
+    fn just_pressed(&self, inputs: &[InputBinding]) -> bool {
+        inputs.iter().any(|input| match *input {
+            InputBinding::Key(key) => self.keys.just_pressed(key),
+            InputBinding::Mouse(button) => self.mouse_buttons.just_pressed(button),
+            InputBinding::Gamepad(button) => self.gamepads.iter().any(|g| g.just_pressed(button)),
+            _ => false,
+        })
+    }
+
+    /// Sums axis inputs; `invert_sticks` flips stick Y to match mouse motion
+    fn axis(&self, inputs: &[InputBinding], invert_sticks: bool) -> Vec2 {
+        let stick_sign = if invert_sticks { Vec2::new(1.0, -1.0) } else { Vec2::ONE };
+        inputs
+            .iter()
+            .map(|input| match *input {
+                InputBinding::MouseMotion => self.mouse_motion,
+                InputBinding::LeftStick => {
+                    self.gamepads.iter().map(|g| g.left_stick()).sum::<Vec2>() * stick_sign
+                }
+                InputBinding::RightStick => {
+                    self.gamepads.iter().map(|g| g.right_stick()).sum::<Vec2>() * stick_sign
+                }
+                _ => Vec2::ZERO,
+            })
+            .sum()
+    }
+}
+
+/// Reads keyboard, mouse, and gamepads directly into the player input components
+#[allow(clippy::type_complexity)]
+pub fn update_raw_input(
+    mut query: Query<
+        (
+            &RawInputBindings,
+            &mut MoveInput,
+            &mut LookInput,
+            &mut LookStickInput,
+            &mut SprintInput,
+            &mut CrouchInput,
+            &mut FreelookInput,
+            &mut JumpPressed,
+            &mut JumpHeld,
+            &mut UsePressed,
+            Option<&StickResponse>,
+            Option<&InputDevice>,
+        ),
+        With<Player>,
+    >,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    gamepads: Query<(Entity, &Gamepad)>,
+) {
+    for (
+        bindings,
+        mut move_input,
+        mut look_input,
+        mut look_stick,
+        mut sprint,
+        mut crouch,
+        mut freelook,
+        mut jump_pressed,
+        mut jump_held,
+        mut use_pressed,
+        response,
+        device,
+    ) in &mut query
+    {
+        let device = device.copied().unwrap_or_default();
+        let devices = RawDevices {
+            keys: &keys,
+            mouse_buttons: &mouse_buttons,
+            mouse_motion: mouse_motion.delta,
+            gamepads: gamepads
+                .iter()
+                .filter(|(entity, _)| device.reads_gamepad(*entity))
+                .map(|(_, gamepad)| gamepad)
+                .collect(),
+        };
+
+        let axis = |inputs: &[InputBinding]| if devices.pressed(inputs) { 1.0 } else { 0.0 };
+        let digital = Vec2::new(
+            axis(&bindings.move_right) - axis(&bindings.move_left),
+            axis(&bindings.move_forward) - axis(&bindings.move_back),
+        );
+        let stick = devices.axis(&bindings.move_axis, false);
+        let stick = response.map_or(stick, |r| r.apply(stick));
+        move_input.0 = (digital + stick).clamp_length_max(1.0);
+
+        // Mouse motion is a per-frame delta, sticks a turn rate
+        let (mouse_look, stick_look): (Vec<InputBinding>, Vec<InputBinding>) = bindings
+            .look
+            .iter()
+            .copied()
+            .partition(|&input| input == InputBinding::MouseMotion);
+        // Mouse motion is positive downward, sticks upward
+        look_input.0 = devices.axis(&mouse_look, true);
+        look_stick.0 = devices.axis(&stick_look, true);
+        sprint.0 = devices.pressed(&bindings.sprint);
+        crouch.0 = devices.pressed(&bindings.crouch);
+        freelook.0 = devices.pressed(&bindings.freelook);
+
+        // Pressed is cleared by the FixedUpdate consumers, so only ever set it here
+        if devices.just_pressed(&bindings.jump) {
+            jump_pressed.0 = true;
+        }
+        jump_held.0 = devices.pressed(&bindings.jump);
+        if devices.just_pressed(&bindings.interact) {
+            use_pressed.0 = true;
+        }
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    app.add_systems(PreUpdate, update_raw_input.in_set(ReadInputSystems));
+}
+
+impl InputBindings {
+    /// Builds the raw backend binding component for the player
+    pub fn to_raw_bindings(&self) -> RawInputBindings {
+        RawInputBindings(self.clone())
+    }
+}
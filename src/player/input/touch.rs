@@ -0,0 +1,253 @@
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::{
+    CrouchInput, JumpHeld, JumpPressed, LookInput, MoveInput, ReadInputSystems, SprintInput,
+    StickResponse, UsePressed,
+};
+use crate::player::{InputDevice, Player};
+
+/// Action triggered by an on-screen touch button
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TouchAction {
+    Jump,
+    Crouch,
+    Sprint,
+    Use,
+}
+
+/// On-screen touch button
+#[derive(Clone, Debug)]
+pub struct TouchButton {
+    pub action: TouchAction,
+    /// Button center measured from the bottom-right corner in logical pixels
+    pub anchor: Vec2,
+    /// Hit radius in logical pixels
+    pub radius: f32,
+    pub label: String,
+}
+
+/// Touch controls layout and tuning.
+///
+/// The left half of the screen is a floating virtual stick for movement, the
+/// right half is drag-to-look, and `buttons` sit on top of both.
+#[derive(Resource, Clone, Debug)]
+pub struct TouchControls {
+    /// Stick travel for full deflection in logical pixels
+    pub stick_radius: f32,
+    /// Look input per logical pixel dragged, relative to mouse motion
+    pub look_sensitivity: f32,
+    pub buttons: Vec<TouchButton>,
+    /// Spawn UI nodes for the buttons
+    pub show_buttons: bool,
+}
+
+impl Default for TouchControls {
+    fn default() -> Self {
+        Self {
+            stick_radius: 70.0,
+            look_sensitivity: 1.0,
+            buttons: vec![
+                TouchButton {
+                    action: TouchAction::Jump,
+                    anchor: Vec2::new(90.0, 90.0),
+                    radius: 45.0,
+                    label: "Jump".into(),
+                },
+                TouchButton {
+                    action: TouchAction::Crouch,
+                    anchor: Vec2::new(190.0, 60.0),
+                    radius: 40.0,
+                    label: "Crouch".into(),
+                },
+                TouchButton {
+                    action: TouchAction::Sprint,
+                    anchor: Vec2::new(70.0, 190.0),
+                    radius: 40.0,
+                    label: "Sprint".into(),
+                },
+            ],
+            show_buttons: true,
+        }
+    }
+}
+
+/// Which touch is doing what this frame
+#[derive(Resource, Default, Debug)]
+pub struct TouchInputState {
+    /// Stick touch and where it started
+    pub stick: Option<(u64, Vec2)>,
+    pub look: Option<u64>,
+    /// Held buttons by touch id
+    pub buttons: Vec<(u64, TouchAction)>,
+}
+
+/// Marker for touch button UI nodes
+#[derive(Component)]
+pub struct TouchButtonNode;
+
+/// Maps touches to the player input components.
+///
+/// Components are only written while touches are active (plus one frame on
+/// release), so other backends keep working on devices with both. Players
+/// assigned to a gamepad are skipped.
+#[allow(clippy::type_complexity)]
+pub fn update_touch_input(
+    touches: Res<Touches>,
+    controls: Res<TouchControls>,
+    mut state: ResMut<TouchInputState>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut query: Query<
+        (
+            &mut MoveInput,
+            &mut LookInput,
+            &mut SprintInput,
+            &mut CrouchInput,
+            &mut JumpPressed,
+            &mut JumpHeld,
+            &mut UsePressed,
+            Option<&StickResponse>,
+            Option<&InputDevice>,
+        ),
+        With<Player>,
+    >,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let size = Vec2::new(window.width(), window.height());
+    let was_active = state.stick.is_some() || state.look.is_some() || !state.buttons.is_empty();
+
+    // Release ended touches
+    for touch in touches.iter_just_released().chain(touches.iter_just_canceled()) {
+        let id = touch.id();
+        if state.stick.is_some_and(|(stick, _)| stick == id) {
+            state.stick = None;
+        }
+        if state.look == Some(id) {
+            state.look = None;
+        }
+        state.buttons.retain(|(button, _)| *button != id);
+    }
+
+    // Assign new touches: buttons first, then stick (left half) or look (right half)
+    let mut jump_pressed = false;
+    let mut use_button_pressed = false;
+    for touch in touches.iter_just_pressed() {
+        let position = touch.position();
+        let button = controls.buttons.iter().find(|button| {
+            let center = size - button.anchor;
+            position.distance(center) <= button.radius
+        });
+
+        if let Some(button) = button {
+            state.buttons.push((touch.id(), button.action));
+            jump_pressed |= button.action == TouchAction::Jump;
+            use_button_pressed |= button.action == TouchAction::Use;
+        } else if position.x < size.x / 2.0 {
+            if state.stick.is_none() {
+                state.stick = Some((touch.id(), position));
+            }
+        } else if state.look.is_none() {
+            state.look = Some(touch.id());
+        }
+    }
+
+    let active = state.stick.is_some() || state.look.is_some() || !state.buttons.is_empty();
+    if !active && !was_active {
+        return;
+    }
+
+    let move_vec = state
+        .stick
+        .and_then(|(id, origin)| touches.get_pressed(id).map(|t| t.position() - origin))
+        .map(|offset| {
+            // Screen Y points down; forward is up
+            let stick = Vec2::new(offset.x, -offset.y) / controls.stick_radius.max(1.0);
+            stick.clamp_length_max(1.0)
+        })
+        .unwrap_or(Vec2::ZERO);
+
+    let look_delta = state
+        .look
+        .and_then(|id| touches.get_pressed(id))
+        .map(|t| t.delta() * controls.look_sensitivity)
+        .unwrap_or(Vec2::ZERO);
+
+    let held = |action: TouchAction| state.buttons.iter().any(|(_, a)| *a == action);
+
+    for (
+        mut move_input,
+        mut look_input,
+        mut sprint,
+        mut crouch,
+        mut jump,
+        mut jump_held,
+        mut use_pressed,
+        response,
+        device,
+    ) in &mut query
+    {
+        if device.is_some_and(|device| !device.reads_keyboard_mouse()) {
+            continue;
+        }
+        move_input.0 = response.map_or(move_vec, |r| r.apply(move_vec));
+        look_input.0 += look_delta;
+        sprint.0 = held(TouchAction::Sprint);
+        crouch.0 = held(TouchAction::Crouch);
+        jump_held.0 = held(TouchAction::Jump);
+        if jump_pressed {
+            jump.0 = true;
+        }
+        if use_button_pressed {
+            use_pressed.0 = true;
+        }
+    }
+}
+
+/// Spawns UI nodes for the touch buttons
+pub fn spawn_touch_buttons(mut commands: Commands, controls: Res<TouchControls>) {
+    if !controls.show_buttons {
+        return;
+    }
+
+    for button in &controls.buttons {
+        commands
+            .spawn((
+                TouchButtonNode,
+                BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.15)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(button.anchor.x - button.radius),
+                    bottom: Val::Px(button.anchor.y - button.radius),
+                    width: Val::Px(button.radius * 2.0),
+                    height: Val::Px(button.radius * 2.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ))
+            .with_child((
+                Text::new(button.label.clone()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+            ));
+    }
+}
+
+/// Registers the touch backend
+pub(super) fn build(app: &mut App) {
+    app.init_resource::<TouchControls>();
+    app.init_resource::<TouchInputState>();
+    app.add_systems(Startup, spawn_touch_buttons);
+    // After the device backend, which overwrites the intent every frame
+    let system = update_touch_input.in_set(ReadInputSystems);
+    #[cfg(all(feature = "raw-input", not(feature = "leafwing")))]
+    let system = system.after(super::update_raw_input);
+    #[cfg(feature = "leafwing")]
+    let system = system.after(super::update_leafwing_input);
+    app.add_systems(PreUpdate, system);
+}
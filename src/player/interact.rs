@@ -0,0 +1,123 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::determinism::LocomotionDeterminism;
+use super::input::UsePressed;
+use super::state::*;
+use crate::camera::{CameraRigOf, FpsCamera};
+use crate::physics::GameLayer;
+
+/// Marker for entities the player can use (doors, buttons, pickups).
+///
+/// Place it on the collider entity or on the rigid body that owns the collider.
+#[derive(Component, Default)]
+pub struct Interactable;
+
+/// Interaction reach and hover state (lives on the player)
+#[derive(Component, Clone, Debug)]
+pub struct Interactor {
+    /// Maximum distance from the camera to an interactable
+    pub range: f32,
+    /// Layers the interaction ray hits; anything it hits first blocks the interactable
+    pub mask: LayerMask,
+    /// Interactable currently under the crosshair
+    pub hovered: Option<Entity>,
+}
+
+impl Default for Interactor {
+    fn default() -> Self {
+        Self {
+            range: 2.5,
+            mask: LayerMask::from([GameLayer::World, GameLayer::Trigger]),
+            hovered: None,
+        }
+    }
+}
+
+/// Sent when a player presses use while hovering an interactable
+#[derive(Message, Clone, Copy, Debug)]
+pub struct Interacted {
+    pub player: Entity,
+    pub target: Entity,
+}
+
+/// Sent when the interactable under a player's crosshair changes
+#[derive(Message, Clone, Copy, Debug)]
+pub struct HoverChanged {
+    pub player: Entity,
+    pub previous: Option<Entity>,
+    pub current: Option<Entity>,
+}
+
+/// Casts a ray along each player's camera and tracks the hovered interactable
+pub fn update_interaction_hover(
+    spatial_query: SpatialQuery,
+    mut player_query: Query<(Entity, &mut Interactor), With<Player>>,
+    camera_query: Query<(&GlobalTransform, &CameraRigOf), With<FpsCamera>>,
+    interactable_query: Query<(), With<Interactable>>,
+    collider_query: Query<&ColliderOf>,
+    mut hover_writer: MessageWriter<HoverChanged>,
+) {
+    for (camera_transform, rig_of) in &camera_query {
+        let player = rig_of.0;
+        let Ok((_, mut interactor)) = player_query.get_mut(player) else {
+            continue;
+        };
+
+        let filter = SpatialQueryFilter::default()
+            .with_mask(interactor.mask)
+            .with_excluded_entities([player]);
+
+        let hovered = spatial_query
+            .cast_ray(
+                camera_transform.translation(),
+                camera_transform.forward(),
+                interactor.range,
+                true,
+                &filter,
+            )
+            .and_then(|hit| {
+                let body = collider_query.get(hit.entity).ok().map(|c| c.body);
+                [Some(hit.entity), body]
+                    .into_iter()
+                    .flatten()
+                    .find(|entity| interactable_query.contains(*entity))
+            });
+
+        if hovered != interactor.hovered {
+            hover_writer.write(HoverChanged {
+                player,
+                previous: interactor.hovered,
+                current: hovered,
+            });
+            interactor.hovered = hovered;
+        }
+    }
+}
+
+/// Sends `Interacted` for use presses and clears them
+#[allow(clippy::type_complexity)]
+pub fn handle_use_input(
+    mut query: Query<
+        (Entity, &Interactor, &mut UsePressed),
+        (With<Player>, Without<LocomotionDisabled>),
+    >,
+    mut interacted_writer: MessageWriter<Interacted>,
+    determinism: Res<LocomotionDeterminism>,
+) {
+    let mut interactions = Vec::new();
+
+    for (player, interactor, mut use_pressed) in &mut query {
+        if !use_pressed.0 {
+            continue;
+        }
+        use_pressed.0 = false;
+
+        if let Some(target) = interactor.hovered {
+            interactions.push(Interacted { player, target });
+        }
+    }
+
+    determinism.order(&mut interactions, |interaction| interaction.player);
+    interacted_writer.write_batch(interactions);
+}
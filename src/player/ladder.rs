@@ -1,6 +1,7 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
+use super::arbitration::AbilityArbiter;
 use super::input::{JumpPressed, MoveInput};
 use super::state::*;
 
@@ -15,28 +16,30 @@ pub struct Ladder;
 ///
 /// The player must be pressing up (`move_input.y > 0.5`) while overlapping
 /// a `Ladder` entity.
+#[allow(clippy::type_complexity)]
 pub fn detect_ladder(
     mut commands: Commands,
     spatial_query: SpatialQuery,
     query: Query<
-        (Entity, &Transform, &PlayerConfig, &MoveInput),
-        (With<Player>, Without<OnLadder>),
+        (Entity, &Transform, &PlayerConfig, &MoveInput, Option<&ReducedProbeRate>),
+        (With<Player>, Without<OnLadder>, Without<CrowdController>, Without<LocomotionDisabled>),
     >,
     ladder_query: Query<&Transform, With<Ladder>>,
+    mut arbiter: AbilityArbiter,
 ) {
-    for (entity, transform, config, move_input) in &query {
+    for (entity, transform, config, move_input, probe_rate) in &query {
         // Must be pressing up to grab ladder
-        if move_input.y < 0.5 {
+        if move_input.y < 0.5 || !probe_due(probe_rate) {
             continue;
         }
 
-        let capsule_height = config.stand_height - config.radius * 2.0;
-        let shape = Collider::capsule(config.radius, capsule_height);
+        let capsule_height = config.collider.stand_height - config.collider.radius * 2.0;
+        let shape = Collider::capsule(config.collider.radius, capsule_height);
         let shape_pos = transform.translation;
         let shape_rot = transform.rotation;
 
         let filter = SpatialQueryFilter::default()
-            .with_mask(config.collision_mask);
+            .with_mask(config.collider.collision_mask);
 
         let intersections = spatial_query.shape_intersections(
             &shape,
@@ -59,7 +62,9 @@ pub fn detect_ladder(
                 continue;
             }
 
-            commands.entity(entity).insert(OnLadder { outward_normal });
+            if arbiter.request(&mut commands, entity, Ability::Ladder, &config.abilities) {
+                commands.entity(entity).insert(OnLadder { outward_normal });
+            }
             break;
         }
     }
@@ -68,6 +73,7 @@ pub fn detect_ladder(
 /// Applies ladder movement: climb up/down with move input, jump to dismount.
 ///
 /// Removes `OnLadder` when the player jumps off or leaves the ladder volume.
+#[allow(clippy::type_complexity)]
 pub fn apply_ladder_movement(
     mut commands: Commands,
     spatial_query: SpatialQuery,
@@ -80,20 +86,29 @@ pub fn apply_ladder_movement(
             &OnLadder,
             &MoveInput,
             &mut JumpPressed,
+            &mut CoyoteTime,
         ),
-        With<Player>,
+        (With<Player>, Without<LocomotionDisabled>),
     >,
     ladder_query: Query<(), With<Ladder>>,
 ) {
-    for (entity, transform, config, mut velocity, on_ladder, move_input, mut jump_pressed) in
-        &mut query
+    for (
+        entity,
+        transform,
+        config,
+        mut velocity,
+        on_ladder,
+        move_input,
+        mut jump_pressed,
+        mut coyote,
+    ) in &mut query
     {
         // Check still overlapping a ladder
-        let capsule_height = config.stand_height - config.radius * 2.0;
-        let shape = Collider::capsule(config.radius, capsule_height);
+        let capsule_height = config.collider.stand_height - config.collider.radius * 2.0;
+        let shape = Collider::capsule(config.collider.radius, capsule_height);
 
         let filter = SpatialQueryFilter::default()
-            .with_mask(config.collision_mask);
+            .with_mask(config.collider.collision_mask);
 
         let intersections = spatial_query.shape_intersections(
             &shape,
@@ -107,6 +122,9 @@ pub fn apply_ladder_movement(
             .any(|e| ladder_query.get(*e).is_ok());
 
         if !still_on_ladder {
+            // Climbing off the top counts as just leaving the ground, so a jump
+            // pressed right after the ladder ends still registers via coyote time
+            coyote.timer = 0.0;
             commands.entity(entity).remove::<OnLadder>();
             continue;
         }
@@ -114,13 +132,13 @@ pub fn apply_ladder_movement(
         // Jump to dismount
         if jump_pressed.0 {
             jump_pressed.0 = false;
-            velocity.0 = on_ladder.outward_normal * config.jump_velocity * 0.4
-                + Vec3::Y * config.jump_velocity;
+            velocity.0 = on_ladder.outward_normal * config.jump.velocity * 0.4
+                + Vec3::Y * config.jump.velocity;
             commands.entity(entity).remove::<OnLadder>();
             continue;
         }
 
         // Climb: vertical movement from input Y
-        velocity.0 = Vec3::Y * move_input.y * config.ladder_climb_speed;
+        velocity.0 = Vec3::Y * move_input.y * config.ladder.climb_speed;
     }
 }
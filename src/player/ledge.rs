@@ -1,10 +1,14 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
-use rand::prelude::*;
 
+use super::arbitration::{release_ledge, AbilityArbiter};
+use super::buffer::{BufferedInput, InputBuffer};
+use super::cooldown::{Cooldown, Cooldowns};
+use super::determinism::{position_salt, LocomotionDeterminism};
 use super::input::{CrouchInput, JumpPressed, MoveInput};
 use super::state::*;
-use crate::camera::{CameraPitch, CameraYaw, LedgeClimbBob, LedgeGrabBounce, LedgeShuffleBob};
+use crate::camera::{CameraPitch, CameraRig, LedgeClimbBob, LedgeGrabBounce, LedgeShuffleBob};
+use crate::physics::{can_stand, probe_ledge};
 
 /// Marker component for walls that allow ledge grabs.
 ///
@@ -12,12 +16,41 @@ use crate::camera::{CameraPitch, CameraYaw, LedgeClimbBob, LedgeGrabBounce, Ledg
 #[derive(Component)]
 pub struct LedgeGrabbable;
 
-/// Detects ledge grabs using a three-ray approach.
+/// Drives the ledge climb from an animation's root motion instead of the
+/// built-in two-phase lerp.
 ///
-/// When the player is airborne and moving toward a wall:
-/// 1. Ray 1 (head height, forward) must MISS (open air above ledge)
-/// 2. Ray 2 (chest height, forward) must HIT (wall exists)
-/// 3. Ray 3 (downward from above wall hit) must HIT with upward normal (ledge surface)
+/// While the player has `LedgeClimbing`, add each frame's root translation to
+/// `delta` (world space). It is applied every fixed tick, stopping short of
+/// world geometry, then cleared. Set `finished` when the clip ends to move the
+/// rest of the way to `LedgeClimbing::end_pos` (again stopping at geometry)
+/// and release the ledge.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct RootMotionClimb {
+    /// Root translation accumulated since the last fixed tick
+    pub delta: Vec3,
+    /// Set when the climb animation has finished
+    pub finished: bool,
+    /// Seconds after which the climb ends even if `finished` is never set
+    pub timeout: f32,
+}
+
+impl Default for RootMotionClimb {
+    fn default() -> Self {
+        Self {
+            delta: Vec3::ZERO,
+            finished: false,
+            timeout: 3.0,
+        }
+    }
+}
+
+/// Gap kept between the climbing capsule and geometry it is moved against
+const ROOT_MOTION_SKIN: f32 = 0.01;
+
+/// Detects ledge grabs when the player is airborne and moving toward a
+/// `LedgeGrabbable` wall, using the three rays of `probe_ledge`.
+#[allow(clippy::type_complexity)]
 pub fn detect_ledge_grab(
     mut commands: Commands,
     spatial_query: SpatialQuery,
@@ -27,36 +60,53 @@ pub fn detect_ledge_grab(
             &Transform,
             &PlayerConfig,
             &PlayerVelocity,
-            &mut LedgeCooldown,
+            &Cooldowns,
             &mut JumpPressed,
+            &mut JumpBuffer,
+            &mut InputBuffer,
+            Option<&CameraRig>,
+        ),
+        (
+            Without<Grounded>,
+            Without<LedgeGrabbing>,
+            Without<CrowdController>,
+            Without<LocomotionDisabled>,
         ),
-        (Without<Grounded>, Without<LedgeGrabbing>, Without<OnLadder>),
     >,
     ledge_query: Query<(), With<LedgeGrabbable>>,
     pitch_query: Query<Entity, With<CameraPitch>>,
-    time: Res<Time>,
+    mut arbiter: AbilityArbiter,
 ) {
-    let dt = time.delta_secs();
-
-    for (entity, transform, config, velocity, mut cooldown, mut jump_pressed) in &mut query {
-        let filter = SpatialQueryFilter::default().with_mask(config.world_layer);
-        cooldown.timer += dt;
-        if cooldown.timer < config.ledge_cooldown {
+    for (
+        entity,
+        transform,
+        config,
+        velocity,
+        cooldowns,
+        mut jump_pressed,
+        mut jump_buffer,
+        mut input_buffer,
+        rig,
+    ) in &mut query
+    {
+        if !cooldowns.is_ready(Cooldown::LedgeGrab) {
             continue;
         }
 
-        // Only grab when jump is pressed
-        if !jump_pressed.0 {
+        // Only grab when jump is pressed (or was pressed within the grab buffer)
+        if !jump_pressed.0
+            && !input_buffer.is_buffered(BufferedInput::LedgeGrab, config.ledge.grab_buffer)
+        {
             continue;
         }
 
         // Must be falling (unless ascending grabs are enabled)
-        if !config.ledge_grab_ascending && velocity.y > 0.0 {
+        if !config.ledge.grab_ascending && velocity.y > 0.0 {
             continue;
         }
 
         // Reject if falling too fast
-        if config.ledge_grab_max_fall_speed > 0.0 && velocity.y < -config.ledge_grab_max_fall_speed
+        if config.ledge.grab_max_fall_speed > 0.0 && velocity.y < -config.ledge.grab_max_fall_speed
         {
             continue;
         }
@@ -72,77 +122,31 @@ pub fn detect_ledge_grab(
             Err(_) => continue,
         };
 
-        let half_height = config.stand_height / 2.0;
-        let center = transform.translation;
-        let probe_dist = config.radius + config.ledge_detect_reach;
-
-        // Ray 1: head height — must MISS (open air above ledge)
-        let ray1_origin = center + Vec3::Y * half_height;
-        let ray1_hit = spatial_query.cast_ray(
-            ray1_origin,
-            forward_dir,
-            probe_dist,
-            true,
-            &filter,
-        );
-        if ray1_hit.is_some() {
-            continue;
-        }
-
-        // Ray 2: chest height — must HIT (wall exists)
-        let ray2_origin = center + Vec3::Y * (half_height * 0.3);
-        let ray2_hit = spatial_query.cast_ray(
-            ray2_origin,
-            forward_dir,
-            probe_dist,
-            true,
-            &filter,
-        );
-        let Some(wall_hit) = ray2_hit else {
+        let Some(ledge) = probe_ledge(&spatial_query, transform.translation, forward_dir, config)
+        else {
             continue;
         };
-
         // Wall must have LedgeGrabbable marker
-        if ledge_query.get(wall_hit.entity).is_err() {
+        if ledge_query.get(ledge.wall).is_err() {
             continue;
         }
 
-        // Ray 3: downward from above the wall hit point — must HIT with upward normal
-        let wall_point = ray2_origin + h_vel.normalize() * wall_hit.distance;
-        let ray3_origin = Vec3::new(wall_point.x, ray1_origin.y + 0.3, wall_point.z);
-        let ray3_hit = spatial_query.cast_ray(
-            ray3_origin,
-            Dir3::NEG_Y,
-            half_height * 2.0,
-            true,
-            &filter,
-        );
-        let Some(ledge_hit) = ray3_hit else {
-            continue;
-        };
-
-        // Validate: surface normal is mostly upward
-        if ledge_hit.normal.dot(Vec3::Y) < 0.7 {
-            continue;
-        }
-
-        let surface_y = ray3_origin.y - ledge_hit.distance;
-
-        // Validate: ledge height is between player center and above head
-        let min_y = center.y;
-        let max_y = center.y + half_height + 0.5;
-        if surface_y < min_y || surface_y > max_y {
+        if !arbiter.request(&mut commands, entity, Ability::Ledge, &config.abilities) {
             continue;
         }
 
+        // The press is spent on the grab; don't let it jump after the climb
         jump_pressed.0 = false;
+        jump_buffer.buffered = false;
+        input_buffer.clear(BufferedInput::LedgeGrab);
         commands.entity(entity).insert(LedgeGrabbing {
-            surface_point: Vec3::new(wall_point.x, surface_y, wall_point.z),
-            wall_normal: wall_hit.normal,
+            surface_point: ledge.surface_point,
+            wall_normal: ledge.wall_normal,
         });
 
         // Camera bounce on grab
-        if let Ok(pitch_entity) = pitch_query.single() {
+        let pitch = rig.and_then(|rig| pitch_query.iter_many(rig.entities()).next());
+        if let Some(pitch_entity) = pitch {
             commands.entity(pitch_entity).insert(LedgeGrabBounce {
                 elapsed: 0.0,
                 duration: 0.4,
@@ -157,59 +161,69 @@ pub fn detect_ledge_grab(
 /// - Jump (looking away): wall jump off wall
 /// - Crouch / backward / strafe while not facing wall: drop
 /// - Strafe while facing wall: shuffle sideways along ledge
+#[allow(clippy::type_complexity)]
 pub fn apply_ledge_grab(
     mut commands: Commands,
     spatial_query: SpatialQuery,
-    mut query: Query<(
-        Entity,
-        &mut Transform,
-        &PlayerConfig,
-        &mut PlayerVelocity,
-        &mut LedgeGrabbing,
-        &mut JumpPressed,
-        &CrouchInput,
-        &MoveInput,
-        &mut LedgeCooldown,
-    )>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &PlayerConfig,
+            &mut PlayerVelocity,
+            &mut LedgeGrabbing,
+            &mut JumpPressed,
+            &CrouchInput,
+            &MoveInput,
+            &mut Cooldowns,
+            &MovementBasis,
+            Option<&CameraRig>,
+            Option<&TimeDilation>,
+        ),
+        Without<LocomotionDisabled>,
+    >,
     pitch_query: Query<(Entity, Option<&LedgeShuffleBob>), With<CameraPitch>>,
-    yaw_query: Query<&Transform, (With<CameraYaw>, Without<LedgeGrabbing>)>,
+    determinism: Res<LocomotionDeterminism>,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-    let yaw_transform = yaw_query.single().ok();
-    let look_forward = yaw_transform
-        .map(|t| Vec3::new(t.forward().x, 0.0, t.forward().z).normalize_or_zero());
-
-    for (entity, mut transform, config, mut velocity, mut ledge, mut jump_pressed, crouch_input, move_input, mut cooldown) in
-        &mut query
+    for (
+        entity,
+        mut transform,
+        config,
+        mut velocity,
+        mut ledge,
+        mut jump_pressed,
+        crouch_input,
+        move_input,
+        mut cooldowns,
+        basis,
+        rig,
+        dilation,
+    ) in &mut query
     {
-        let half_height = config.stand_height / 2.0;
-        let wall_normal_h = Vec3::new(ledge.wall_normal.x, 0.0, ledge.wall_normal.z).normalize_or_zero();
+        let dt = player_dt(time.delta_secs(), dilation);
+        let fwd = basis.forward();
+        let pitch = rig.and_then(|rig| pitch_query.iter_many(rig.entities()).next());
+        let half_height = config.collider.stand_height / 2.0;
+        let wall_normal_h =
+            Vec3::new(ledge.wall_normal.x, 0.0, ledge.wall_normal.z).normalize_or_zero();
         let wall_into = -wall_normal_h;
-        let facing_wall = look_forward
-            .map(|fwd| fwd.dot(wall_into) > 0.25)
-            .unwrap_or(true);
+        let facing_wall = fwd.dot(wall_into) > 0.25;
 
         // Helper: drop from ledge and clean up shuffle bob
         macro_rules! drop_ledge {
             () => {{
-                commands.entity(entity).remove::<LedgeGrabbing>();
-                cooldown.timer = 0.0;
-                if let Ok((pitch_entity, _)) = pitch_query.single() {
-                    commands.entity(pitch_entity).remove::<LedgeShuffleBob>();
-                }
+                release_ledge(&mut commands, entity);
                 continue;
             }};
         }
 
         // Walking backward (away from wall) → drop
         if move_input.y < -0.5 {
-            if let Some(fwd) = look_forward {
-                let right = Vec3::new(-fwd.z, 0.0, fwd.x);
-                let move_dir = (fwd * move_input.y + right * move_input.x).normalize_or_zero();
-                if move_dir.dot(wall_normal_h) > 0.25 {
-                    drop_ledge!();
-                }
+            let right = Vec3::new(-fwd.z, 0.0, fwd.x);
+            let move_dir = (fwd * move_input.y + right * move_input.x).normalize_or_zero();
+            if move_dir.dot(wall_normal_h) > 0.25 {
+                drop_ledge!();
             }
         }
 
@@ -222,7 +236,7 @@ pub fn apply_ledge_grab(
         if jump_pressed.0 {
             jump_pressed.0 = false;
 
-            if let Ok((pitch_entity, _)) = pitch_query.single() {
+            if let Some((pitch_entity, _)) = pitch {
                 commands.entity(pitch_entity).remove::<LedgeShuffleBob>();
             }
 
@@ -230,9 +244,9 @@ pub fn apply_ledge_grab(
                 // Climb: begin animated ledge climb
                 let start_pos = transform.translation;
                 let end_pos = Vec3::new(
-                    ledge.surface_point.x + wall_into.x * (config.radius + 0.1),
+                    ledge.surface_point.x + wall_into.x * (config.collider.radius + 0.1),
                     ledge.surface_point.y + half_height,
-                    ledge.surface_point.z + wall_into.z * (config.radius + 0.1),
+                    ledge.surface_point.z + wall_into.z * (config.collider.radius + 0.1),
                 );
 
                 velocity.0 = Vec3::ZERO;
@@ -242,22 +256,24 @@ pub fn apply_ledge_grab(
                     end_pos,
                     wall_normal: ledge.wall_normal,
                     elapsed: 0.0,
-                    duration: config.ledge_climb_duration,
+                    duration: config.ledge.climb_duration,
                 });
 
-                if let Ok((pitch_entity, _)) = pitch_query.single() {
-                    let roll_sign = if rand::thread_rng().gen_bool(0.5) { 1.0 } else { -1.0 };
+                if let Some((pitch_entity, _)) = pitch {
+                    let roll_sign =
+                        determinism.sign(entity, position_salt(ledge.surface_point));
                     commands.entity(pitch_entity).insert(LedgeClimbBob {
                         elapsed: 0.0,
-                        duration: config.ledge_climb_duration,
+                        duration: config.ledge.climb_duration,
                         roll_sign,
                     });
                 }
             } else {
                 // Wall jump: launch away from wall
-                velocity.0 = wall_normal_h * config.jump_velocity * 0.6 + Vec3::Y * config.jump_velocity;
+                let jump_velocity = config.jump.velocity;
+                velocity.0 = wall_normal_h * jump_velocity * 0.6 + Vec3::Y * jump_velocity;
                 commands.entity(entity).remove::<LedgeGrabbing>();
-                cooldown.timer = 0.0;
+                cooldowns.start(Cooldown::LedgeGrab, config.ledge.cooldown);
             }
 
             continue;
@@ -270,51 +286,49 @@ pub fn apply_ledge_grab(
 
         // Strafing while facing wall → shuffle along ledge
         if move_input.x.abs() > 0.1 && facing_wall {
-            if let Some(fwd) = look_forward {
-                let wall_tangent = wall_normal_h.cross(Vec3::Y).normalize_or_zero();
-                let cam_right = Vec3::new(-fwd.z, 0.0, fwd.x);
-                let tangent_dot = (cam_right * move_input.x).dot(wall_tangent);
-
-                if tangent_dot.abs() > 0.01 {
-                    let shuffle_dir = wall_tangent * tangent_dot.signum();
-                    let shuffle_delta = shuffle_dir * config.ledge_shuffle_speed * dt;
-
-                    // Verify ledge still exists at the new position
-                    let new_point = ledge.surface_point + shuffle_delta;
-                    let ray_origin = Vec3::new(new_point.x, ledge.surface_point.y + 0.3, new_point.z);
-                    let filter = SpatialQueryFilter::default().with_mask(config.world_layer);
-                    let ray_hit = spatial_query.cast_ray(
-                        ray_origin,
-                        Dir3::NEG_Y,
-                        half_height,
-                        true,
-                        &filter,
-                    );
-
-                    let valid = ray_hit
-                        .filter(|hit| hit.normal.dot(Vec3::Y) > 0.7);
-
-                    if let Some(hit) = valid {
-                        let new_y = ray_origin.y - hit.distance;
-                        ledge.surface_point = Vec3::new(new_point.x, new_y, new_point.z);
-
-                        // Advance shuffle bob
-                        if let Ok((pitch_entity, shuffle_bob)) = pitch_query.single() {
-                            let current_timer = shuffle_bob.map(|b| b.timer).unwrap_or(0.0);
-                            commands.entity(pitch_entity).insert(LedgeShuffleBob {
-                                timer: current_timer + dt,
-                                amplitude: config.ledge_shuffle_bob_amplitude,
-                            });
-                        }
-                    } else {
-                        // No valid ledge surface — drop off the edge
-                        drop_ledge!();
+            let wall_tangent = wall_normal_h.cross(Vec3::Y).normalize_or_zero();
+            let cam_right = Vec3::new(-fwd.z, 0.0, fwd.x);
+            let tangent_dot = (cam_right * move_input.x).dot(wall_tangent);
+
+            if tangent_dot.abs() > 0.01 {
+                let shuffle_dir = wall_tangent * tangent_dot.signum();
+                let shuffle_delta = shuffle_dir * config.ledge.shuffle_speed * dt;
+
+                // Verify ledge still exists at the new position
+                let new_point = ledge.surface_point + shuffle_delta;
+                let ray_origin = Vec3::new(new_point.x, ledge.surface_point.y + 0.3, new_point.z);
+                let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+                let ray_hit = spatial_query.cast_ray(
+                    ray_origin,
+                    Dir3::NEG_Y,
+                    half_height,
+                    true,
+                    &filter,
+                );
+
+                let valid = ray_hit
+                    .filter(|hit| hit.normal.dot(Vec3::Y) > 0.7);
+
+                if let Some(hit) = valid {
+                    let new_y = ray_origin.y - hit.distance;
+                    ledge.surface_point = Vec3::new(new_point.x, new_y, new_point.z);
+
+                    // Advance shuffle bob
+                    if let Some((pitch_entity, shuffle_bob)) = pitch {
+                        let current_timer = shuffle_bob.map(|b| b.timer).unwrap_or(0.0);
+                        commands.entity(pitch_entity).insert(LedgeShuffleBob {
+                            timer: current_timer + dt,
+                            amplitude: config.ledge.shuffle_bob_amplitude,
+                        });
                     }
+                } else {
+                    // No valid ledge surface — drop off the edge
+                    drop_ledge!();
                 }
             }
         } else {
             // Not shuffling — remove bob if present
-            if let Ok((pitch_entity, Some(_))) = pitch_query.single() {
+            if let Some((pitch_entity, Some(_))) = pitch {
                 commands.entity(pitch_entity).remove::<LedgeShuffleBob>();
             }
         }
@@ -325,28 +339,36 @@ pub fn apply_ledge_grab(
         let target_y = ledge.surface_point.y - half_height;
         transform.translation.y = target_y;
 
-        let wall_contact = Vec3::new(ledge.surface_point.x, transform.translation.y, ledge.surface_point.z);
-        let snapped = wall_contact + wall_normal_h * config.radius;
+        let wall_contact =
+            Vec3::new(ledge.surface_point.x, transform.translation.y, ledge.surface_point.z);
+        let snapped = wall_contact + wall_normal_h * config.collider.radius;
         transform.translation.x = snapped.x;
         transform.translation.z = snapped.z;
     }
 }
 
 /// Animates the two-phase ledge climb: up then forward, using smoothstep interpolation.
+#[allow(clippy::type_complexity)]
 pub fn animate_ledge_climb(
     mut commands: Commands,
-    mut query: Query<(
-        Entity,
-        &mut Transform,
-        &mut PlayerVelocity,
-        &mut LedgeClimbing,
-        &mut LedgeCooldown,
-    )>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &PlayerConfig,
+            &mut PlayerVelocity,
+            &mut LedgeClimbing,
+            &mut Cooldowns,
+            Option<&TimeDilation>,
+        ),
+        (Without<RootMotionClimb>, Without<LocomotionDisabled>),
+    >,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-
-    for (entity, mut transform, mut velocity, mut climb, mut cooldown) in &mut query {
+    for (entity, mut transform, config, mut velocity, mut climb, mut cooldowns, dilation) in
+        &mut query
+    {
+        let dt = player_dt(time.delta_secs(), dilation);
         climb.elapsed += dt;
         let t = (climb.elapsed / climb.duration).clamp(0.0, 1.0);
 
@@ -362,7 +384,8 @@ pub fn animate_ledge_climb(
         if t <= 0.5 {
             // Phase 1: move upward (t 0→0.5 maps to 0→1)
             let phase = ease(t * 2.0);
-            transform.translation.y = climb.start_pos.y + (climb.end_pos.y - climb.start_pos.y) * phase;
+            transform.translation.y =
+                climb.start_pos.y + (climb.end_pos.y - climb.start_pos.y) * phase;
             // XZ stays at start
             transform.translation.x = climb.start_pos.x;
             transform.translation.z = climb.start_pos.z;
@@ -371,8 +394,10 @@ pub fn animate_ledge_climb(
             let phase = ease((t - 0.5) * 2.0);
             // Y is already at end height
             transform.translation.y = climb.end_pos.y;
-            transform.translation.x = climb.start_pos.x + (climb.end_pos.x - climb.start_pos.x) * phase;
-            transform.translation.z = climb.start_pos.z + (climb.end_pos.z - climb.start_pos.z) * phase;
+            transform.translation.x =
+                climb.start_pos.x + (climb.end_pos.x - climb.start_pos.x) * phase;
+            transform.translation.z =
+                climb.start_pos.z + (climb.end_pos.z - climb.start_pos.z) * phase;
         }
 
         // Keep velocity zeroed during animation
@@ -380,10 +405,96 @@ pub fn animate_ledge_climb(
 
         // Finished
         if t >= 1.0 {
-            commands.entity(entity).remove::<LedgeClimbing>();
-            commands.entity(entity).remove::<LedgeGrabbing>();
-            commands.entity(entity).remove::<Crouching>();
-            cooldown.timer = 0.0;
+            commands.entity(entity).remove::<(LedgeClimbing, LedgeGrabbing, Crouching)>();
+            cooldowns.start(Cooldown::LedgeGrab, config.ledge.cooldown);
+        }
+    }
+}
+
+/// Moves climbing players with `RootMotionClimb` by their animation's root
+/// motion, validated with a shape cast so the capsule can't enter geometry.
+#[allow(clippy::type_complexity)]
+pub fn apply_root_motion_climb(
+    mut commands: Commands,
+    spatial_query: SpatialQuery,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &PlayerConfig,
+            &mut PlayerVelocity,
+            &mut LedgeClimbing,
+            &mut Cooldowns,
+            &mut RootMotionClimb,
+            Has<Crouching>,
+            Option<&TimeDilation>,
+        ),
+        Without<LocomotionDisabled>,
+    >,
+    time: Res<Time>,
+) {
+    for (
+        entity,
+        mut transform,
+        config,
+        mut velocity,
+        mut climb,
+        mut cooldowns,
+        mut root_motion,
+        crouching,
+        dilation,
+    ) in &mut query
+    {
+        let dt = player_dt(time.delta_secs(), dilation);
+        climb.elapsed += dt;
+        velocity.0 = Vec3::ZERO;
+
+        let delta = std::mem::take(&mut root_motion.delta);
+        transform.translation =
+            sweep_climb(&spatial_query, config, crouching, transform.translation, delta);
+
+        if root_motion.finished || climb.elapsed >= root_motion.timeout {
+            // Stops short of end_pos if the animation left the body blocked
+            let remaining = climb.end_pos - transform.translation;
+            transform.translation =
+                sweep_climb(&spatial_query, config, crouching, transform.translation, remaining);
+            root_motion.finished = false;
+            commands.entity(entity).remove::<(LedgeClimbing, LedgeGrabbing)>();
+            // Stand up only where there's room, like releasing the ledge does
+            if crouching {
+                let feet = transform.translation - Vec3::Y * config.collider.crouch_height / 2.0;
+                if can_stand(&spatial_query, feet, config) {
+                    commands.entity(entity).remove::<Crouching>();
+                }
+            }
+            cooldowns.start(Cooldown::LedgeGrab, config.ledge.cooldown);
         }
     }
 }
+
+/// Moves the climbing body from `from` by `delta`, stopping short of world
+/// geometry
+fn sweep_climb(
+    spatial_query: &SpatialQuery,
+    config: &PlayerConfig,
+    crouching: bool,
+    from: Vec3,
+    delta: Vec3,
+) -> Vec3 {
+    let Ok((direction, distance)) = Dir3::new_and_length(delta) else {
+        return from;
+    };
+    // Slightly thinner than the body so the wall being climbed doesn't block it
+    let collider = &config.collider;
+    let length = collider.height(crouching) - collider.radius * 2.0;
+    let shape = Collider::capsule(collider.radius * 0.9, length.max(0.1));
+    let filter = SpatialQueryFilter::default().with_mask(collider.world_layer);
+    let cast_config = ShapeCastConfig {
+        max_distance: distance,
+        ..default()
+    };
+    let allowed = spatial_query
+        .cast_shape(&shape, from, Quat::IDENTITY, direction, &cast_config, &filter)
+        .map_or(distance, |hit| (hit.distance - ROOT_MOTION_SKIN).max(0.0));
+    from + *direction * allowed
+}
@@ -0,0 +1,121 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use super::events::{MovementEventKind, PlayerMovementEvent};
+use super::state::*;
+
+/// Running movement statistics for analytics, achievements and automated
+/// playtest reports (opt-in: insert on a player).
+///
+/// Counts come from `PlayerMovementEvent`s and times from the player's own
+/// clock, so `TimeDilation` is respected. `reset` starts a new session.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct LocomotionMetrics {
+    /// Horizontal meters actually covered (teleports are not counted)
+    pub distance: f32,
+    pub jumps: u32,
+    pub landings: u32,
+    pub slides: u32,
+    pub ledge_grabs: u32,
+    pub ladder_climbs: u32,
+    pub wall_hits: u32,
+    /// Highest total speed in m/s
+    pub max_speed: f32,
+    /// Highest fall in meters
+    pub max_fall_height: f32,
+    /// Landings from `long_fall_height` meters or more
+    pub long_falls: u32,
+    /// Fall height in meters that counts as a long fall
+    pub long_fall_height: f32,
+    /// Seconds spent in each `LocomotionState`
+    pub state_time: HashMap<LocomotionState, f32>,
+    /// Seconds tracked since the last reset
+    pub elapsed: f32,
+    last_position: Option<Vec3>,
+}
+
+impl Default for LocomotionMetrics {
+    fn default() -> Self {
+        Self {
+            distance: 0.0,
+            jumps: 0,
+            landings: 0,
+            slides: 0,
+            ledge_grabs: 0,
+            ladder_climbs: 0,
+            wall_hits: 0,
+            max_speed: 0.0,
+            max_fall_height: 0.0,
+            long_falls: 0,
+            long_fall_height: 5.0,
+            state_time: HashMap::default(),
+            elapsed: 0.0,
+            last_position: None,
+        }
+    }
+}
+
+impl LocomotionMetrics {
+    pub fn with_long_fall_height(mut self, meters: f32) -> Self {
+        self.long_fall_height = meters;
+        self
+    }
+
+    /// Seconds spent in `state` since the last reset
+    pub fn time_in(&self, state: LocomotionState) -> f32 {
+        self.state_time.get(&state).copied().unwrap_or_default()
+    }
+
+    /// Clears every statistic, keeping `long_fall_height`
+    pub fn reset(&mut self) {
+        *self = Self::default().with_long_fall_height(self.long_fall_height);
+    }
+}
+
+/// Accumulates `LocomotionMetrics` from this tick's movement events and state
+pub fn update_locomotion_metrics(
+    mut reader: MessageReader<PlayerMovementEvent>,
+    mut query: Query<(
+        &mut LocomotionMetrics,
+        &Transform,
+        &PlayerVelocity,
+        &LocomotionState,
+        Option<&TimeDilation>,
+    )>,
+    time: Res<Time>,
+) {
+    for event in reader.read() {
+        let Ok((mut metrics, ..)) = query.get_mut(event.player) else {
+            continue;
+        };
+        match event.kind {
+            MovementEventKind::LeftGround { jumped: true } => metrics.jumps += 1,
+            MovementEventKind::Landed { fall_height, .. } => {
+                metrics.landings += 1;
+                metrics.max_fall_height = metrics.max_fall_height.max(fall_height);
+                if fall_height >= metrics.long_fall_height {
+                    metrics.long_falls += 1;
+                }
+            }
+            MovementEventKind::SlideStarted { .. } => metrics.slides += 1,
+            MovementEventKind::LedgeGrabbed => metrics.ledge_grabs += 1,
+            MovementEventKind::LadderEntered => metrics.ladder_climbs += 1,
+            MovementEventKind::HitWall { .. } => metrics.wall_hits += 1,
+            _ => {}
+        }
+    }
+
+    for (mut metrics, transform, velocity, state, dilation) in &mut query {
+        let dt = player_dt(time.delta_secs(), dilation);
+        let speed = velocity.length();
+        // Capped by the player's own speed so teleports don't count
+        let moved = metrics
+            .last_position
+            .map_or(0.0, |last| (transform.translation - last).xz().length());
+        metrics.distance += moved.min(velocity.xz().length() * dt);
+        metrics.last_position = Some(transform.translation);
+        metrics.max_speed = metrics.max_speed.max(speed);
+        *metrics.state_time.entry(*state).or_default() += dt;
+        metrics.elapsed += dt;
+    }
+}
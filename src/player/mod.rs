@@ -1,19 +1,84 @@
+#[cfg(feature = "audio-events")]
 pub mod audio;
+mod arbitration;
+mod bindings;
+mod buffer;
+mod carry;
+mod cooldown;
 mod crouch;
+mod determinism;
+mod events;
 mod forceslide;
 pub mod input;
+mod interact;
 mod jump;
+#[cfg(feature = "ladder")]
 mod ladder;
+#[cfg(feature = "ledge")]
 mod ledge;
+mod metrics;
+mod mount;
 mod movement;
+pub(crate) mod prediction;
 pub(crate) mod plugin;
+mod recording;
+mod resize;
+#[cfg(feature = "slide")]
+mod slide;
+mod snapshot;
 mod state;
 mod stepup;
+mod teleport;
+#[cfg(feature = "vault")]
+mod vault;
 
-pub use audio::PlayerAudioMessage;
+#[cfg(feature = "audio-events")]
+pub use audio::{PlayerAudioMessage, PlayerSound, PlayerSoundKind, SLIDE_LOOP_INTERVAL};
+pub use arbitration::{AbilityArbiter, AbilityConflict};
+pub use bindings::{DuplicateBinding, InputBinding, InputBindings, InputDevice};
+pub use buffer::{BufferedInput, InputBuffer};
+pub use carry::{Carried, Carrier, CarryMessage, Carryable};
+pub use cooldown::{Cooldown, CooldownReady, Cooldowns};
+pub use determinism::LocomotionDeterminism;
+pub use events::{
+    MovementEventKind, MovementTracker, PlayerMovementEvent, SpeedThresholds, SurfaceMaterial,
+};
 pub use forceslide::ForceSlide;
-pub use input::{LookInput, MoveInput};
+pub use input::{
+    ActionPolicy, BoolAction, CrouchInput, DeadzoneShape, FreelookInput, InputContext,
+    InputContextMessage, InputContextStack, InputPolicies, InputSuspended, JumpHeld, JumpPressed,
+    LocomotionIntent, LookInput, LookStickInput, MoveInput, ResponseCurve, SprintInput,
+    StickResponse, UsePressed,
+};
+pub use interact::{HoverChanged, Interactable, Interacted, Interactor};
+#[cfg(feature = "ladder")]
 pub use ladder::Ladder;
-pub use ledge::LedgeGrabbable;
-pub use plugin::{spawn_player, PlayerPlugin};
+#[cfg(feature = "ledge")]
+pub use ledge::{LedgeGrabbable, RootMotionClimb};
+#[cfg(feature = "ladder")]
+pub use plugin::LadderPlugin;
+#[cfg(feature = "ledge")]
+pub use plugin::LedgePlugin;
+#[cfg(feature = "slide")]
+pub use plugin::SlidePlugin;
+#[cfg(feature = "vault")]
+pub use plugin::VaultPlugin;
+pub use plugin::{
+    player_input, spawn_camera_rig, spawn_player, spawn_player_with_bindings,
+    spawn_player_with_device, spawn_player_without_input, AutoSpawnPlayer, CoreMovementPlugin,
+    ForceSlidePlugin, JumpPlugin, LocomotionSchedule, LocomotionSet, PlayerBuilder, PlayerBundle,
+    PlayerPlugin, SpawnPlayer, StepUpPlugin,
+};
+pub use prediction::{
+    resimulate, step_player, IntentQueue, LocomotionTick, PredictedTick, PredictionHistory,
+    Reconcile, Resimulating, TickedIntent,
+};
+pub use recording::{
+    IntentFrame, IntentPlayback, IntentRecorder, IntentTrack, TransformFrame, TransformTrack,
+};
+pub use metrics::LocomotionMetrics;
+pub use mount::{Mount, MountEvent, MountMessage, Riding};
+pub use resize::ResizePlayer;
+pub use snapshot::LocomotionSnapshot;
 pub use state::*;
+pub use teleport::TeleportPlayer;
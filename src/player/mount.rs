@@ -0,0 +1,236 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::arbitration::release_ledge;
+use super::carry::{Carrier, CarryMessage};
+use super::prediction::Resimulating;
+use super::state::*;
+use super::teleport::TeleportPlayer;
+use crate::camera::CameraMountMessage;
+
+/// Gap kept between the ground and a player placed at an exit point
+const EXIT_SKIN: f32 = 0.05;
+
+/// Vehicle, turret or seat a player can ride (lives on the mount entity).
+///
+/// Offsets and exits are in the mount's local space.
+#[derive(Component, Clone, Debug)]
+pub struct Mount {
+    /// Where the parked body is held while riding
+    pub seat: Vec3,
+    /// Where the camera rig is attached
+    pub camera_offset: Vec3,
+    /// Ground positions to step out at, tried in order
+    pub exits: Vec<Vec3>,
+    /// Keep look input while riding (turrets); turn off for mounts that steer
+    /// the camera themselves
+    pub look: bool,
+}
+
+impl Default for Mount {
+    fn default() -> Self {
+        Self {
+            seat: Vec3::ZERO,
+            camera_offset: Vec3::new(0.0, 0.6, 0.0),
+            exits: vec![Vec3::new(-1.5, 0.0, 0.0), Vec3::new(1.5, 0.0, 0.0)],
+            look: true,
+        }
+    }
+}
+
+/// Present on a player riding a `Mount`.
+///
+/// The controller is suspended with `LocomotionDisabled`, the body and its
+/// collider are disabled and parked at the seat, and the camera rig is
+/// attached to the mount.
+#[derive(Component, Clone, Copy, Debug)]
+#[component(storage = "SparseSet")]
+pub struct Riding {
+    pub mount: Entity,
+    /// Copied from `Mount::look`
+    pub look: bool,
+    /// Whether the player was already disabled, so exiting leaves it disabled
+    was_disabled: bool,
+}
+
+/// Requests to get on and off mounts
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum MountMessage {
+    /// Ride `mount`; ignored while already riding
+    Enter { player: Entity, mount: Entity },
+    /// Step off at the first clear exit point
+    Exit { player: Entity },
+}
+
+/// Sent when a player gets on or off a mount
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum MountEvent {
+    Entered { player: Entity, mount: Entity },
+    /// Placed at `position` (body center)
+    Exited { player: Entity, mount: Entity, position: Vec3 },
+    /// Every exit point was blocked; the player is still riding
+    ExitBlocked { player: Entity, mount: Entity },
+}
+
+/// First clear body position for an exit at `ground` (world space).
+///
+/// A blocked spot is retried `step_up_height` higher, to clear curbs and
+/// debris, and a clear one is settled onto the ground below.
+fn exit_position(
+    ground: Vec3,
+    config: &PlayerConfig,
+    filter: &SpatialQueryFilter,
+    spatial_query: &SpatialQuery,
+) -> Option<Vec3> {
+    let shape = config.collider.capsule(false);
+    let center = ground + Vec3::Y * (config.collider.stand_height / 2.0 + EXIT_SKIN);
+    let lift = config.movement.step_up_height;
+    let clear = [center, center + Vec3::Y * lift].into_iter().find(|&position| {
+        spatial_query.shape_intersections(&shape, position, Quat::IDENTITY, filter).is_empty()
+    })?;
+
+    let cast_config = ShapeCastConfig::from_max_distance(lift + 1.0);
+    let drop = spatial_query
+        .cast_shape(&shape, clear, Quat::IDENTITY, Dir3::NEG_Y, &cast_config, filter)
+        .map_or(0.0, |hit| (hit.distance - EXIT_SKIN).max(0.0));
+    Some(clear - Vec3::Y * drop)
+}
+
+/// Applies `MountMessage`s at the start of the fixed tick; replayed ticks
+/// send no `MountEvent`s
+#[allow(clippy::type_complexity)]
+pub fn handle_mount_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<MountMessage>,
+    player_query: Query<
+        (
+            &Transform,
+            &PlayerConfig,
+            Option<&Riding>,
+            Has<LocomotionDisabled>,
+            Option<&Carrier>,
+        ),
+        With<Player>,
+    >,
+    mount_query: Query<(&Mount, &GlobalTransform)>,
+    spatial_query: SpatialQuery,
+    (mut camera_writer, mut teleport_writer, mut carry_writer, mut event_writer): (
+        MessageWriter<CameraMountMessage>,
+        MessageWriter<TeleportPlayer>,
+        MessageWriter<CarryMessage>,
+        MessageWriter<MountEvent>,
+    ),
+    resimulating: Option<Res<Resimulating>>,
+) {
+    let replaying = resimulating.is_some();
+    for message in reader.read() {
+        match *message {
+            MountMessage::Enter { player, mount } => {
+                let Ok((_, _, riding, disabled, carrier)) = player_query.get(player) else {
+                    continue;
+                };
+                let Ok((mount_config, _)) = mount_query.get(mount) else {
+                    continue;
+                };
+                if riding.is_some() {
+                    continue;
+                }
+
+                if carrier.is_some_and(|carrier| carrier.carried.is_some()) {
+                    carry_writer.write(CarryMessage::Drop { player });
+                }
+                // Abilities don't carry over into the seat
+                commands.entity(player).remove::<(
+                    JumpCut,
+                    Sliding,
+                    ForcedSliding,
+                    PendingSlide,
+                    OnLadder,
+                    Vaulting,
+                )>();
+                release_ledge(&mut commands, player);
+                commands.entity(player).insert((
+                    Riding {
+                        mount,
+                        look: mount_config.look,
+                        was_disabled: disabled,
+                    },
+                    LocomotionDisabled,
+                    RigidBodyDisabled,
+                    ColliderDisabled,
+                ));
+                camera_writer.write(CameraMountMessage::Attach {
+                    player,
+                    mount,
+                    offset: mount_config.camera_offset,
+                });
+                if !replaying {
+                    event_writer.write(MountEvent::Entered { player, mount });
+                }
+            }
+            MountMessage::Exit { player } => {
+                let Ok((transform, config, Some(&riding), ..)) = player_query.get(player) else {
+                    continue;
+                };
+                let mount = riding.mount;
+                let exits: Vec<Vec3> = match mount_query.get(mount) {
+                    Ok((mount_config, global)) => {
+                        let exits = mount_config.exits.iter();
+                        exits.map(|&exit| global.transform_point(exit)).collect()
+                    }
+                    // Mount despawned: step off where the body was parked
+                    Err(_) => {
+                        vec![transform.translation - Vec3::Y * config.collider.stand_height / 2.0]
+                    }
+                };
+                let filter = SpatialQueryFilter::default()
+                    .with_mask(config.collider.world_layer)
+                    .with_excluded_entities([player]);
+                let position = exits
+                    .into_iter()
+                    .find_map(|ground| exit_position(ground, config, &filter, &spatial_query));
+                let Some(position) = position else {
+                    if !replaying {
+                        event_writer.write(MountEvent::ExitBlocked { player, mount });
+                    }
+                    continue;
+                };
+
+                let mut player_commands = commands.entity(player);
+                player_commands.remove::<(Riding, RigidBodyDisabled, ColliderDisabled)>();
+                if !riding.was_disabled {
+                    player_commands.remove::<LocomotionDisabled>();
+                }
+                camera_writer.write(CameraMountMessage::Detach { player });
+                teleport_writer.write(TeleportPlayer {
+                    player,
+                    position,
+                    facing: None,
+                });
+                if !replaying {
+                    event_writer.write(MountEvent::Exited {
+                        player,
+                        mount,
+                        position,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Keeps riders' parked bodies at their seat, so the player's position stays
+/// meaningful to audio, AI and networking while riding
+pub fn park_riders(
+    mut player_query: Query<(&Riding, &mut Transform, &mut Position), With<Player>>,
+    mount_query: Query<(&Mount, &GlobalTransform), Without<Player>>,
+) {
+    for (riding, mut transform, mut position) in &mut player_query {
+        let Ok((mount, global)) = mount_query.get(riding.mount) else {
+            continue;
+        };
+        let seat = global.transform_point(mount.seat);
+        transform.translation = seat;
+        position.0 = seat;
+    }
+}
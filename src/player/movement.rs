@@ -1,11 +1,17 @@
 use avian3d::prelude::*;
+use bevy::ecs::entity::EntityHashSet;
 use bevy::prelude::*;
 
-use super::input::MoveInput;
+use super::arbitration::release_ledge;
+use super::determinism::LocomotionDeterminism;
+use super::input::{JumpPressed, MoveInput, UsePressed};
+use super::prediction::Resimulating;
 use super::state::*;
-use crate::camera::CameraYaw;
+use crate::camera::{CameraRig, CameraYaw};
+use crate::physics::{can_stand, probe_ground};
 
 /// Updates grounded state via raycast
+#[allow(clippy::type_complexity)]
 pub fn update_grounded_state(
     mut commands: Commands,
     spatial_query: SpatialQuery,
@@ -13,108 +19,159 @@ pub fn update_grounded_state(
         Entity,
         &Transform,
         &PlayerConfig,
-        &PlayerVelocity,
+        &mut PlayerVelocity,
         &mut CoyoteTime,
         &mut AirTime,
         Option<&Grounded>,
-    )>,
+        Option<&mut GroundNormal>,
+        Option<&mut GroundEntity>,
+        Option<&mut GroundPoint>,
+        Option<&mut CrowdController>,
+        Option<&ReducedProbeRate>,
+        Option<&TimeDilation>,
+    ), (Without<Vaulting>, Without<LocomotionDisabled>)>,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-
-    for (entity, transform, config, player_vel, mut coyote, mut air_time, was_grounded) in &mut query {
-        // Raycast from center of capsule downward
+    for (entity, transform, config, mut player_vel, mut coyote, mut air_time, was_grounded, ground_normal, ground_entity, ground_point, crowd, probe_rate, dilation) in &mut query {
+        let dt = player_dt(time.delta_secs(), dilation);
         let ray_origin = transform.translation;
-        let ray_dir = Dir3::NEG_Y;
-        // The capsule's curved bottom sits higher above slopes than flat ground.
-        // Vertical distance from center to slope = (halfHeight - radius) + radius/cos(angle).
-        // Using radius as the margin handles slopes up to ~60°.
-        let ground_check_dist = config.stand_height / 2.0 + config.radius;
-
-        let filter = SpatialQueryFilter::default()
-            .with_mask(config.world_layer);
-
-        let hit = spatial_query.cast_ray(
-            ray_origin,
-            ray_dir,
-            ground_check_dist,
-            true,
-            &filter,
-        );
-
-        let min_ground_normal_y = config.max_slope_angle.to_radians().cos();
-
-        let is_grounded = hit.as_ref()
-            .is_some_and(|h| {
-                h.distance < ground_check_dist
+        // `probe_ground`'s reach, which cached crowd hits can fall outside of
+        let ground_check_dist = config.collider.stand_height / 2.0 + config.collider.radius;
+
+        // Crowd controllers reuse the last probe's ground between probes
+        let hit = match crowd {
+            Some(crowd) if !probe_due(probe_rate) => crowd
+                .ground
+                .map(|(ground_y, normal, ground)| (ray_origin.y - ground_y, normal, ground)),
+            crowd => {
+                let hit = probe_ground(&spatial_query, ray_origin, config)
+                    .map(|h| (h.distance, h.normal, h.entity));
+                if let Some(mut crowd) = crowd {
+                    crowd.ground = hit.map(|(distance, normal, ground)| {
+                        (ray_origin.y - distance, normal, ground)
+                    });
+                }
+                hit
+            }
+        };
+
+        let min_ground_normal_y = config.movement.max_slope_angle.to_radians().cos();
+
+        let is_grounded = hit
+            .is_some_and(|(distance, normal, _)| {
+                distance < ground_check_dist
                     && player_vel.y < 1.0
-                    && h.normal.dot(Vec3::Y) >= min_ground_normal_y
+                    && normal.dot(Vec3::Y) >= min_ground_normal_y
             });
 
         if is_grounded {
-            let normal = hit.unwrap().normal;
-            commands.entity(entity).insert(GroundNormal(normal));
-            if was_grounded.is_none() {
-                commands.entity(entity).insert(Grounded);
+            let (hit_distance, hit_normal, hit_entity) = hit.unwrap();
+            // Straight below the center, like `probe_ground` reports it
+            let hit_point = ray_origin - Vec3::Y * hit_distance;
+            // Update in place, and only touch the archetype on landing
+            match (ground_normal, ground_entity, ground_point) {
+                (Some(mut ground_normal), Some(mut ground_entity), Some(mut ground_point)) => {
+                    ground_normal.set_if_neq(GroundNormal(hit_normal));
+                    ground_entity.set_if_neq(GroundEntity(hit_entity));
+                    ground_point.set_if_neq(GroundPoint(hit_point));
+                    if was_grounded.is_none() {
+                        commands.entity(entity).insert(Grounded);
+                    }
+                }
+                _ => {
+                    commands.entity(entity).insert((
+                        Grounded,
+                        GroundNormal(hit_normal),
+                        GroundEntity(hit_entity),
+                        GroundPoint(hit_point),
+                    ));
+                }
             }
             coyote.timer = 0.0;
             air_time.duration = 0.0;
+            air_time.peak_y = transform.translation.y;
+
+            // Reset vertical velocity when grounded (so gravity doesn't accumulate)
+            if player_vel.y < 0.0 {
+                player_vel.y = 0.0;
+            }
         } else {
-            commands.entity(entity).remove::<GroundNormal>();
-            if was_grounded.is_some() {
-                commands.entity(entity).remove::<Grounded>();
+            if was_grounded.is_some()
+                || ground_normal.is_some()
+                || ground_entity.is_some()
+                || ground_point.is_some()
+            {
+                commands
+                    .entity(entity)
+                    .remove::<(Grounded, GroundNormal, GroundEntity, GroundPoint)>();
             }
             coyote.timer += dt;
             air_time.duration += dt;
+            air_time.peak_y = air_time.peak_y.max(transform.translation.y);
         }
     }
 }
 
+/// Copies the camera rig's yaw into `MovementBasis`.
+///
+/// Players without a rig keep the basis their AI wrote.
+pub fn sync_movement_basis(
+    mut query: Query<(&CameraRig, &mut MovementBasis), Without<LocomotionDisabled>>,
+    yaw_query: Query<&Transform, (With<CameraYaw>, Without<Player>)>,
+) {
+    for (rig, mut basis) in &mut query {
+        let Some(yaw) = yaw_query.iter_many(rig.entities()).next() else {
+            continue;
+        };
+        basis.set_if_neq(MovementBasis(yaw.rotation.to_euler(EulerRot::YXZ).0));
+    }
+}
+
 /// Applies ground movement - sets horizontal velocity
+#[allow(clippy::type_complexity)]
 pub fn ground_movement(
     mut query: Query<
         (
+            &MovementBasis,
             &MoveInput,
             &PlayerConfig,
             &mut PlayerVelocity,
             Has<Sprinting>,
             Has<Crouching>,
+            Option<&TimeDilation>,
+        ),
+        (
+            With<Grounded>,
+            Without<Sliding>,
+            Without<ForcedSliding>,
+            Without<OnLadder>,
+            Without<Vaulting>,
+            Without<CrowdController>,
+            Without<LocomotionDisabled>,
         ),
-        (With<Grounded>, Without<Sliding>, Without<ForcedSliding>, Without<OnLadder>),
     >,
-    yaw_query: Query<&Transform, With<CameraYaw>>,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-
-    let Ok(yaw_transform) = yaw_query.single() else {
-        return;
-    };
-
-    for (input, config, mut velocity, sprinting, crouching) in &mut query {
-        let forward = yaw_transform.forward().as_vec3();
-        let right = yaw_transform.right().as_vec3();
-
-        // Flatten to horizontal
-        let forward = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
-        let right = Vec3::new(right.x, 0.0, right.z).normalize_or_zero();
-
+    for (basis, input, config, mut velocity, sprinting, crouching, dilation) in &mut query {
+        let dt = player_dt(time.delta_secs(), dilation);
+        let (forward, right) = (basis.forward(), basis.right());
         let move_dir = (forward * input.y + right * input.x).normalize_or_zero();
         let target_speed = if crouching {
-            config.crouch_speed
+            config.crouch_slide.crouch_speed
         } else if sprinting {
-            config.sprint_speed
+            config.movement.sprint_speed
         } else {
-            config.walk_speed
+            config.movement.walk_speed
         };
 
-        let target = move_dir * target_speed;
+        // Partial stick deflection walks slower
+        let target = move_dir * target_speed * input.length().min(1.0);
         let current = Vec3::new(velocity.x, 0.0, velocity.z);
 
         let accel = if input.length_squared() > 0.01 {
-            config.ground_accel
+            config.movement.ground_accel
         } else {
-            config.ground_friction
+            config.movement.ground_friction
         };
 
         let new_vel = current.move_towards(target, accel * dt);
@@ -124,41 +181,47 @@ pub fn ground_movement(
 }
 
 /// Applies air movement with reduced control
+#[allow(clippy::type_complexity)]
 pub fn air_movement(
     mut query: Query<
-        (&MoveInput, &PlayerConfig, &mut PlayerVelocity),
-        (Without<Grounded>, Without<LedgeGrabbing>, Without<LedgeClimbing>, Without<OnLadder>),
+        (
+            &MovementBasis,
+            &MoveInput,
+            &PlayerConfig,
+            &mut PlayerVelocity,
+            Option<&TimeDilation>,
+        ),
+        (
+            Without<Grounded>,
+            Without<LedgeGrabbing>,
+            Without<LedgeClimbing>,
+            Without<OnLadder>,
+            Without<Vaulting>,
+            Without<CrowdController>,
+            Without<LocomotionDisabled>,
+        ),
     >,
-    yaw_query: Query<&Transform, With<CameraYaw>>,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-
-    let Ok(yaw_transform) = yaw_query.single() else {
-        return;
-    };
-
-    for (input, config, mut velocity) in &mut query {
+    for (basis, input, config, mut velocity, dilation) in &mut query {
         if input.length_squared() < 0.01 {
             continue;
         }
+        let dt = player_dt(time.delta_secs(), dilation);
 
-        let forward = yaw_transform.forward().as_vec3();
-        let right = yaw_transform.right().as_vec3();
-        let forward = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
-        let right = Vec3::new(right.x, 0.0, right.z).normalize_or_zero();
-
+        let (forward, right) = (basis.forward(), basis.right());
         let move_dir = (forward * input.y + right * input.x).normalize_or_zero();
 
         // Use ground accel when resting on an edge (near-zero vertical velocity)
         let accel = if velocity.y.abs() < 0.5 {
-            config.ground_accel
+            config.movement.ground_accel
         } else {
-            config.air_accel
+            config.movement.air_accel
         };
 
         let current_speed = velocity.dot(move_dir);
-        let add_speed = (config.walk_speed - current_speed).max(0.0);
+        let wish_speed = config.movement.walk_speed * input.length().min(1.0);
+        let add_speed = (wish_speed - current_speed).max(0.0);
         let accel_speed = (accel * dt).min(add_speed);
 
         velocity.x += move_dir.x * accel_speed;
@@ -166,35 +229,167 @@ pub fn air_movement(
     }
 }
 
+/// Walks `CrowdController`s toward their input with one acceleration, on the
+/// ground and in the air alike
+#[allow(clippy::type_complexity)]
+pub fn crowd_movement(
+    mut query: Query<
+        (
+            &MovementBasis,
+            &MoveInput,
+            &PlayerConfig,
+            &mut PlayerVelocity,
+            Has<Sprinting>,
+            Has<Crouching>,
+            Option<&TimeDilation>,
+        ),
+        (With<CrowdController>, Without<LocomotionDisabled>),
+    >,
+    time: Res<Time>,
+) {
+    for (basis, input, config, mut velocity, sprinting, crouching, dilation) in &mut query {
+        let dt = player_dt(time.delta_secs(), dilation);
+        let move_dir = (basis.forward() * input.y + basis.right() * input.x).normalize_or_zero();
+        let target_speed = if crouching {
+            config.crouch_slide.crouch_speed
+        } else if sprinting {
+            config.movement.sprint_speed
+        } else {
+            config.movement.walk_speed
+        };
+
+        let target = move_dir * target_speed * input.length().min(1.0);
+        let new_vel = Vec3::new(velocity.x, 0.0, velocity.z)
+            .move_towards(target, config.movement.ground_accel * dt);
+        velocity.x = new_vel.x;
+        velocity.z = new_vel.z;
+    }
+}
+
 /// Applies gravity when not grounded
+#[allow(clippy::type_complexity)]
 pub fn apply_gravity(
-    mut query: Query<&mut PlayerVelocity, (Without<Grounded>, Without<LedgeGrabbing>, Without<LedgeClimbing>, Without<OnLadder>)>,
+    mut query: Query<
+        (&mut PlayerVelocity, Option<&TimeDilation>),
+        (
+            Without<Grounded>,
+            Without<LedgeGrabbing>,
+            Without<LedgeClimbing>,
+            Without<OnLadder>,
+            Without<Vaulting>,
+            Without<LocomotionDisabled>,
+        ),
+    >,
     gravity: Res<Gravity>,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-    for mut velocity in &mut query {
-        velocity.0 += gravity.0 * dt;
+    for (mut velocity, dilation) in &mut query {
+        velocity.0 += gravity.0 * player_dt(time.delta_secs(), dilation);
     }
 }
 
-/// Syncs PlayerVelocity to Avian's LinearVelocity, projecting onto ground surface when grounded
-pub fn apply_velocity(
+/// Applies `VelocityMessage`s on top of this tick's movement
+#[allow(clippy::type_complexity)]
+pub fn apply_velocity_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<VelocityMessage>,
     mut query: Query<
-        (&mut PlayerVelocity, &PlayerConfig, &mut LinearVelocity, Option<&Grounded>, Option<&GroundNormal>),
+        (&Transform, &PlayerConfig, &mut PlayerVelocity, &mut CoyoteTime, Has<Crouching>),
         With<Player>,
     >,
+    spatial_query: SpatialQuery,
 ) {
-    for (mut player_vel, config, mut lin_vel, grounded, ground_normal) in &mut query {
-        // Clamp horizontal speed
-        if config.max_horizontal_speed > 0.0 {
-            let h_speed = Vec2::new(player_vel.x, player_vel.z).length();
-            if h_speed > config.max_horizontal_speed {
-                let scale = config.max_horizontal_speed / h_speed;
-                player_vel.x *= scale;
-                player_vel.z *= scale;
+    for msg in reader.read() {
+        match *msg {
+            VelocityMessage::Set { player, velocity } => {
+                if let Ok((_, _, mut player_vel, _, _)) = query.get_mut(player) {
+                    player_vel.0 = velocity;
+                }
+            }
+            VelocityMessage::Add { player, velocity } => {
+                if let Ok((_, _, mut player_vel, _, _)) = query.get_mut(player) {
+                    player_vel.0 += velocity;
+                }
+            }
+            VelocityMessage::Launch { player, velocity } => {
+                let Ok((transform, config, mut player_vel, mut coyote, crouching)) =
+                    query.get_mut(player)
+                else {
+                    continue;
+                };
+                player_vel.0 = velocity;
+                // No coyote jump out of a launch
+                coyote.timer = config.jump.coyote_time;
+                commands.entity(player).remove::<(
+                    Grounded,
+                    GroundNormal,
+                    GroundEntity,
+                    GroundPoint,
+                    JumpCut,
+                    Sliding,
+                    ForcedSliding,
+                    PendingSlide,
+                    OnLadder,
+                    Vaulting,
+                )>();
+                release_ledge(&mut commands, player);
+                // Stand up only where there's room, like releasing crouch does
+                if crouching {
+                    let feet =
+                        transform.translation - Vec3::Y * config.collider.crouch_height / 2.0;
+                    if can_stand(&spatial_query, feet, config) {
+                        commands.entity(player).remove::<Crouching>();
+                    }
+                }
             }
         }
+    }
+}
+
+/// Horizontal speed after the speed cap: clamped, or decayed toward the cap at
+/// `overspeed_decay` when soft capping
+fn capped_speed(speed: f32, movement: &MovementConfig, dt: f32) -> f32 {
+    let cap = movement.max_horizontal_speed;
+    if cap <= 0.0 || speed <= cap {
+        return speed;
+    }
+    if movement.overspeed_decay <= 0.0 {
+        return cap;
+    }
+    let speed = (speed - movement.overspeed_decay * dt).max(cap);
+    if movement.overspeed_limit > 0.0 {
+        speed.min(movement.overspeed_limit.max(cap))
+    } else {
+        speed
+    }
+}
+
+/// Syncs PlayerVelocity to Avian's LinearVelocity, projecting onto ground surface when grounded
+#[allow(clippy::type_complexity)]
+pub fn apply_velocity(
+    mut query: Query<
+        (
+            &mut PlayerVelocity,
+            &PlayerConfig,
+            &mut LinearVelocity,
+            Option<&Grounded>,
+            Option<&GroundNormal>,
+            Option<&TimeDilation>,
+        ),
+        (With<Player>, Without<LocomotionDisabled>),
+    >,
+    time: Res<Time>,
+) {
+    for (mut player_vel, config, mut lin_vel, grounded, ground_normal, dilation) in &mut query {
+        // Cap horizontal speed
+        let h_speed = Vec2::new(player_vel.x, player_vel.z).length();
+        let dt = player_dt(time.delta_secs(), dilation);
+        let capped = capped_speed(h_speed, &config.movement, dt);
+        if capped < h_speed {
+            let scale = capped / h_speed;
+            player_vel.x *= scale;
+            player_vel.z *= scale;
+        }
 
         if grounded.is_some() {
             let horizontal = Vec3::new(player_vel.x, 0.0, player_vel.z);
@@ -232,26 +427,257 @@ pub fn apply_velocity(
             lin_vel.z = player_vel.z;
             lin_vel.y = player_vel.y;
         }
+
+        // Physics steps in world time; cover the player's own time instead
+        if let Some(dilation) = dilation {
+            lin_vel.0 *= dilation.0;
+        }
     }
 }
 
-/// Updates sprint state and sprint grace timer
-pub fn update_sprint_state(
+/// Holds disabled players in place so the body doesn't drift or fall while frozen.
+///
+/// `PlayerVelocity` is left alone, so the player resumes with the momentum it
+/// had. Presses made while frozen are dropped so they don't fire on resume.
+#[allow(clippy::type_complexity)]
+pub fn hold_disabled_players(
+    mut query: Query<
+        (&mut LinearVelocity, &mut JumpPressed, &mut UsePressed),
+        (With<Player>, With<LocomotionDisabled>),
+    >,
+) {
+    for (mut lin_vel, mut jump_pressed, mut use_pressed) in &mut query {
+        lin_vel.0 = Vec3::ZERO;
+        jump_pressed.0 = false;
+        use_pressed.0 = false;
+    }
+}
+
+/// Derives `LocomotionState` from the state markers and reports transitions.
+///
+/// Replayed ticks update the state without reporting it again.
+#[allow(clippy::type_complexity)]
+pub fn update_locomotion_state(
     mut commands: Commands,
+    mut writer: MessageWriter<StateChanged>,
     mut query: Query<
-        (Entity, &super::input::SprintInput, &mut SprintGrace, Has<Grounded>, Has<Crouching>),
+        (
+            Entity,
+            &mut LocomotionState,
+            Has<LocomotionDisabled>,
+            Has<LedgeClimbing>,
+            Has<LedgeGrabbing>,
+            Has<OnLadder>,
+            Has<Vaulting>,
+            Has<ForcedSliding>,
+            Has<Sliding>,
+            Has<Grounded>,
+            Has<Crouching>,
+            Has<Sprinting>,
+            Has<LocomotionObservers>,
+        ),
+        With<Player>,
+    >,
+    determinism: Res<LocomotionDeterminism>,
+    resimulating: Option<Res<Resimulating>>,
+) {
+    let mut changes = Vec::new();
+    let mut observers = EntityHashSet::default();
+
+    for (
+        player,
+        mut state,
+        disabled,
+        climbing,
+        grabbing,
+        on_ladder,
+        vaulting,
+        forced_sliding,
+        sliding,
+        grounded,
+        crouching,
+        sprinting,
+        observed,
+    ) in &mut query
+    {
+        let next = if disabled {
+            LocomotionState::Disabled
+        } else if climbing {
+            LocomotionState::LedgeClimbing
+        } else if grabbing {
+            LocomotionState::LedgeGrabbing
+        } else if on_ladder {
+            LocomotionState::OnLadder
+        } else if vaulting {
+            LocomotionState::Vaulting
+        } else if forced_sliding {
+            LocomotionState::ForcedSliding
+        } else if sliding {
+            LocomotionState::Sliding
+        } else if !grounded {
+            LocomotionState::Airborne
+        } else if crouching {
+            LocomotionState::Crouching
+        } else if sprinting {
+            LocomotionState::Sprinting
+        } else {
+            LocomotionState::Walking
+        };
+
+        let from = *state;
+        if state.set_if_neq(next) && resimulating.is_none() {
+            changes.push(StateChanged { player, from, to: next });
+            if observed {
+                observers.insert(player);
+            }
+        }
+    }
+
+    // Observers see the changes in the same order as readers
+    determinism.order(&mut changes, |change| change.player);
+    for &change in changes.iter().filter(|change| observers.contains(&change.player)) {
+        commands.trigger(change);
+    }
+    writer.write_batch(changes);
+}
+
+/// Publishes `LocomotionAnimState` from velocity and the current state
+#[allow(clippy::type_complexity)]
+pub fn update_anim_state(
+    mut query: Query<
+        (
+            &MovementBasis,
+            &PlayerConfig,
+            &PlayerVelocity,
+            &AirTime,
+            &LocomotionState,
+            &mut LocomotionAnimState,
+            Has<Grounded>,
+            Has<Crouching>,
+            Has<Sliding>,
+            Has<Sprinting>,
+            Option<&TimeDilation>,
+        ),
         With<Player>,
     >,
     time: Res<Time>,
 ) {
-    let dt = time.delta_secs();
-    for (entity, sprint_input, mut grace, grounded, crouching) in &mut query {
-        if sprint_input.0 && grounded && !crouching {
-            commands.entity(entity).insert(Sprinting);
+    for (
+        basis,
+        config,
+        velocity,
+        air_time,
+        state,
+        mut anim,
+        grounded,
+        crouching,
+        sliding,
+        sprinting,
+        dilation,
+    ) in &mut query
+    {
+        let dt = player_dt(time.delta_secs(), dilation);
+        let (forward, right) = (basis.forward(), basis.right());
+        let horizontal = Vec3::new(velocity.x, 0.0, velocity.z);
+        let planar_speed = horizontal.length();
+        let direction = horizontal.normalize_or_zero();
+
+        // Two `FootstepConfig` strides per cycle, restarting whenever footsteps do,
+        // so steps land on phases 0.5 and 0.0
+        let (stride, _) = config.footsteps.for_stance(crouching, sprinting);
+        let gait_phase = if grounded && !sliding && planar_speed > 0.5 && stride > 0.0 {
+            (anim.gait_phase + dt * planar_speed / (stride * 2.0)).fract()
+        } else {
+            0.0
+        };
+
+        anim.set_if_neq(LocomotionAnimState {
+            planar_speed,
+            speed_ratio: if config.movement.sprint_speed > 0.0 {
+                planar_speed / config.movement.sprint_speed
+            } else {
+                0.0
+            },
+            move_direction: Vec2::new(direction.dot(right), direction.dot(forward)),
+            gait_phase,
+            vertical_speed: velocity.y,
+            air_time: air_time.duration,
+            stance: if crouching || sliding {
+                Stance::Crouching
+            } else {
+                Stance::Standing
+            },
+            state: *state,
+        });
+    }
+}
+
+/// Updates sprint state and sprint grace timer.
+///
+/// With `auto_sprint`, moving forward past the threshold sprints and each
+/// sprint press toggles walking instead.
+#[allow(clippy::type_complexity)]
+pub fn update_sprint_state(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &PlayerConfig,
+            &super::input::SprintInput,
+            &MoveInput,
+            &mut SprintGrace,
+            Has<Grounded>,
+            Has<Crouching>,
+            Has<Sprinting>,
+            Option<&TimeDilation>,
+        ),
+        (With<Player>, Without<LocomotionDisabled>),
+    >,
+    time: Res<Time>,
+) {
+    for (
+        entity,
+        config,
+        sprint_input,
+        move_input,
+        mut grace,
+        grounded,
+        crouching,
+        sprinting,
+        dilation,
+    ) in &mut query
+    {
+        let dt = player_dt(time.delta_secs(), dilation);
+        let wants_sprint = if config.movement.auto_sprint {
+            if sprint_input.0 && !grace.sprint_was_held {
+                grace.walk_toggled = !grace.walk_toggled;
+            }
+            move_input.y >= config.movement.auto_sprint_threshold && !grace.walk_toggled
+        } else {
+            sprint_input.0
+        };
+        grace.sprint_was_held = sprint_input.0;
+
+        if wants_sprint && grounded && !crouching {
+            if !sprinting {
+                commands.entity(entity).insert(Sprinting);
+            }
             grace.timer = 0.0;
         } else {
-            commands.entity(entity).remove::<Sprinting>();
+            if sprinting {
+                commands.entity(entity).remove::<Sprinting>();
+            }
             grace.timer += dt;
         }
     }
 }
+
+/// Advances `ReducedProbeRate` so each player probes every `interval` ticks
+pub fn advance_probe_rates(mut query: Query<(Entity, &mut ReducedProbeRate)>) {
+    for (entity, mut rate) in &mut query {
+        rate.tick = rate.tick.wrapping_add(1);
+        // Offset by entity index so a crowd doesn't probe on the same tick
+        let phase = rate.tick.wrapping_add(entity.to_bits() as u32);
+        rate.due = phase % rate.interval.max(1) == 0;
+    }
+}
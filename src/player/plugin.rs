@@ -1,109 +1,816 @@
 use avian3d::prelude::*;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
-use bevy_enhanced_input::prelude::*;
 
+#[cfg(feature = "audio-events")]
 use super::audio::*;
+use super::arbitration::AbilityConflict;
+use super::bindings::{InputBindings, InputDevice};
+use super::buffer::*;
+use super::carry::*;
+use super::cooldown::*;
 use super::crouch::*;
+use super::determinism::LocomotionDeterminism;
+use super::events::*;
 use super::forceslide::*;
 use super::input::{
-    clear_look_input, handle_crouch_end, handle_crouch_start, handle_jump_end, handle_jump_start,
-    handle_look_input, handle_move_end, handle_move_input, handle_sprint_end, handle_sprint_start,
-    CrouchAction, CrouchInput, JumpAction, JumpHeld, JumpPressed, LookAction, LookInput,
-    MoveAction, MoveInput, SprintAction, SprintInput,
+    add_input_backend, apply_input_policies, clear_look_input, input_bundle, InputContextStack,
+    InputPolicies, LocomotionIntent, StickResponse,
 };
+use super::interact::*;
 use super::jump::*;
+#[cfg(feature = "ladder")]
 use super::ladder::*;
+#[cfg(feature = "ledge")]
 use super::ledge::*;
+use super::metrics::*;
+use super::mount::*;
 use super::movement::*;
+use super::prediction::*;
+use super::recording::*;
+use super::resize::*;
+#[cfg(feature = "slide")]
+use super::slide::*;
 use super::state::*;
 use super::stepup::*;
-use crate::camera::{CameraConfig, CameraPitch, CameraYaw, FpsCamera, PitchAngle};
+use super::teleport::*;
+#[cfg(feature = "vault")]
+use super::vault::*;
+use crate::camera::{
+    CameraConfig, CameraMotion, CameraPitch, CameraRecoil, CameraRigOf, CameraSmoothing,
+    CameraYaw, EyeHeight, FpsCamera, FreelookYaw, PitchAngle, PreviousGroundedState, SpringArm,
+};
+
+/// Stages of the player's step in `LocomotionSchedule` (`FixedUpdate` by
+/// default), in order.
+///
+/// Order game systems against these, e.g. modify `PlayerVelocity` after
+/// `Movement` and before `ApplyVelocity` to add knockback.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocomotionSet {
+    /// Tick counter, input policies, intent playback, queues and recording
+    Input,
+    /// Ground detection and input buffering
+    Grounding,
+    /// Sprint, crouch, slide, ladder, ledge, vault, and jump state changes
+    StateTransitions,
+    /// Writes `PlayerVelocity` from the current state
+    Movement,
+    /// Hands velocity to physics and emits audio, movement and state change messages
+    ApplyVelocity,
+}
 
-/// Plugin for first-person player controller
+/// Schedules the controller and Avian run in, read when the plugins are added.
+///
+/// Insert it before adding `BevyLocomotionPlugin` (or `PhysicsPlugin` and the
+/// player plugins) to run the controller where your physics runs. Pick the
+/// schedule for the physics step after the controller, so each controller
+/// step is integrated exactly once:
+///
+/// ```ignore
+/// app.insert_resource(LocomotionSchedule::variable())
+///     .add_plugins(BevyLocomotionPlugin);
+/// ```
+///
+/// Prediction, replication, intent recording and course ghosts count fixed
+/// ticks and need the default `fixed` placement.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LocomotionSchedule {
+    /// Schedule the `LocomotionSet` stages run in
+    pub controller: InternedScheduleLabel,
+    /// Schedule `PhysicsPlugin` runs Avian in
+    pub physics: InternedScheduleLabel,
+}
+
+impl Default for LocomotionSchedule {
+    fn default() -> Self {
+        Self::fixed()
+    }
+}
+
+impl LocomotionSchedule {
+    /// `FixedUpdate`, with Avian in `FixedPostUpdate` (Avian's default)
+    pub fn fixed() -> Self {
+        Self::new(FixedUpdate, FixedPostUpdate)
+    }
+
+    /// Once per frame in `Update`, with Avian in `PostUpdate`
+    pub fn variable() -> Self {
+        Self::new(Update, PostUpdate)
+    }
+
+    /// Custom schedules, e.g. for your own substepping loop
+    pub fn new(controller: impl ScheduleLabel, physics: impl ScheduleLabel) -> Self {
+        Self {
+            controller: controller.intern(),
+            physics: physics.intern(),
+        }
+    }
+}
+
+/// Controller schedule configured for `app`
+pub(crate) fn locomotion_schedule(app: &App) -> InternedScheduleLabel {
+    app.world()
+        .get_resource::<LocomotionSchedule>()
+        .copied()
+        .unwrap_or_default()
+        .controller
+}
+
+/// Slots inside the locomotion stages that keep abilities in their original
+/// order regardless of which ability plugins are added
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AbilityStep {
+    DetectForcedSlide,
+    Sprint,
+    DetectSlide,
+    Crouch,
+    DetectLadder,
+    Ledge,
+    Vault,
+    Jump,
+    JumpCut,
+    Walk,
+    ForcedSlide,
+    Ladder,
+    StepUp,
+    AirControl,
+    Slide,
+    Gravity,
+}
+
+/// Plugin for first-person player controller.
+///
+/// Adds `CoreMovementPlugin` and every ability plugin. For a subset, add
+/// `CoreMovementPlugin` plus the abilities you want instead.
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        if !app.is_plugin_added::<EnhancedInputPlugin>() {
-            app.add_plugins(EnhancedInputPlugin);
+        if !app.is_plugin_added::<CoreMovementPlugin>() {
+            app.add_plugins(CoreMovementPlugin);
+        }
+        if !app.is_plugin_added::<JumpPlugin>() {
+            app.add_plugins(JumpPlugin);
+        }
+        #[cfg(feature = "slide")]
+        if !app.is_plugin_added::<SlidePlugin>() {
+            app.add_plugins(SlidePlugin);
+        }
+        #[cfg(feature = "ledge")]
+        if !app.is_plugin_added::<LedgePlugin>() {
+            app.add_plugins(LedgePlugin);
+        }
+        #[cfg(feature = "ladder")]
+        if !app.is_plugin_added::<LadderPlugin>() {
+            app.add_plugins(LadderPlugin);
+        }
+        #[cfg(feature = "vault")]
+        if !app.is_plugin_added::<VaultPlugin>() {
+            app.add_plugins(VaultPlugin);
         }
+        if !app.is_plugin_added::<StepUpPlugin>() {
+            app.add_plugins(StepUpPlugin);
+        }
+        if !app.is_plugin_added::<ForceSlidePlugin>() {
+            app.add_plugins(ForceSlidePlugin);
+        }
+    }
+}
 
-        // Register input context for player
-        app.add_input_context::<Player>();
+/// Input, grounding, walking, sprinting, crouching, gravity, and interaction
+pub struct CoreMovementPlugin;
+
+impl Plugin for CoreMovementPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        // Input backend (bevy_enhanced_input, leafwing-input-manager, or raw Bevy input)
+        add_input_backend(app);
 
         // Audio messages
-        app.add_message::<PlayerAudioMessage>();
-        app.init_resource::<AudioTracker>();
-
-        // Input observers
-        app.add_observer(handle_move_input);
-        app.add_observer(handle_move_end);
-        app.add_observer(handle_look_input);
-        app.add_observer(handle_sprint_start);
-        app.add_observer(handle_sprint_end);
-        app.add_observer(handle_crouch_start);
-        app.add_observer(handle_crouch_end);
-        app.add_observer(handle_jump_start);
-        app.add_observer(handle_jump_end);
-
-        // Fixed update systems for physics
+        #[cfg(feature = "audio-events")]
+        {
+            app.add_message::<PlayerAudioMessage>();
+            app.add_systems(
+                schedule,
+                emit_player_audio_messages
+                    .run_if(not(resource_exists::<Resimulating>))
+                    .after(hold_disabled_players)
+                    .in_set(LocomotionSet::ApplyVelocity),
+            );
+        }
+
+        app.init_resource::<LocomotionDeterminism>();
+        app.init_resource::<LocomotionSchedule>();
+        // Optional startup player, off unless the app inserts `AutoSpawnPlayer`
+        app.add_systems(Startup, auto_spawn_player);
+        // Ability arbitration decisions, for debugging
+        app.add_message::<AbilityConflict>();
+        app.add_message::<CooldownReady>();
+
+        // Velocity overrides from game code, after movement and before physics
+        app.add_message::<VelocityMessage>();
+        // Teleports, before grounding so it is re-detected at the destination
+        app.add_message::<TeleportPlayer>();
+        // Body resizes, after teleports so the feet stay on the destination
+        app.add_message::<ResizePlayer>();
+
+        // Prediction and reconciliation, corrections replayed before the fixed step
+        app.init_resource::<LocomotionTick>();
+        app.add_message::<Reconcile>();
+        app.add_systems(
+            RunFixedMainLoop,
+            reconcile_predictions.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+        );
+
+        // Movement events
+        app.add_message::<PlayerMovementEvent>();
+        app.add_systems(
+            schedule,
+            emit_movement_events
+                .run_if(not(resource_exists::<Resimulating>))
+                .after(hold_disabled_players)
+                .in_set(LocomotionSet::ApplyVelocity),
+        );
+
+        // State transitions
+        app.add_message::<StateChanged>();
+        app.add_systems(
+            schedule,
+            (update_locomotion_state, update_anim_state)
+                .chain()
+                .after(hold_disabled_players)
+                .in_set(LocomotionSet::ApplyVelocity),
+        );
+        app.add_systems(
+            schedule,
+            update_locomotion_metrics
+                .after(emit_movement_events)
+                .after(update_locomotion_state)
+                .in_set(LocomotionSet::ApplyVelocity),
+        );
+
+        // Interaction
+        app.add_message::<Interacted>();
+        app.add_message::<HoverChanged>();
+        // Carrying, with use presses while carrying taken before interaction sees them.
+        // Replayed ticks don't interact again.
+        app.add_message::<CarryMessage>();
+        app.add_systems(
+            schedule,
+            (
+                update_interaction_hover,
+                drop_on_use,
+                handle_use_input,
+                pick_up_on_interact,
+                handle_carry_messages,
+            )
+                .chain()
+                .run_if(not(resource_exists::<Resimulating>))
+                .after(record_prediction_history)
+                .in_set(LocomotionSet::Input),
+        );
         app.add_systems(
-            FixedUpdate,
+            schedule,
+            hold_carried_objects
+                .after(hold_disabled_players)
+                .in_set(LocomotionSet::ApplyVelocity),
+        );
+
+        // Mounts: handoffs before teleports so exits land this tick
+        app.add_message::<MountMessage>();
+        app.add_message::<MountEvent>();
+        app.add_systems(schedule, handle_mount_messages.in_set(LocomotionSet::Input));
+        app.add_systems(
+            schedule,
+            park_riders.after(hold_disabled_players).in_set(LocomotionSet::ApplyVelocity),
+        );
+
+        // Controller step stages
+        app.configure_sets(
+            schedule,
             (
+                LocomotionSet::Input,
+                LocomotionSet::Grounding,
+                LocomotionSet::StateTransitions,
+                LocomotionSet::Movement,
+                LocomotionSet::ApplyVelocity,
+            )
+                .chain(),
+        );
+        app.configure_sets(
+            schedule,
+            (
+                (
+                    AbilityStep::DetectForcedSlide,
+                    AbilityStep::Sprint,
+                    AbilityStep::DetectSlide,
+                    AbilityStep::Crouch,
+                    AbilityStep::DetectLadder,
+                    AbilityStep::Ledge,
+                    AbilityStep::Vault,
+                    AbilityStep::Jump,
+                )
+                    .chain()
+                    .in_set(LocomotionSet::StateTransitions),
                 (
+                    AbilityStep::JumpCut,
+                    AbilityStep::Walk,
+                    AbilityStep::ForcedSlide,
+                    AbilityStep::Ladder,
+                    AbilityStep::StepUp,
+                    AbilityStep::AirControl,
+                    AbilityStep::Slide,
+                    AbilityStep::Gravity,
+                )
+                    .chain()
+                    .in_set(LocomotionSet::Movement),
+            ),
+        );
+        app.add_systems(
+            schedule,
+            (
+                (
+                    advance_locomotion_tick,
+                    apply_input_policies,
+                    playback_intent,
+                    apply_intent_queue.run_if(any_with_component::<IntentQueue>),
+                    sync_movement_basis,
+                    record_intent.run_if(not(resource_exists::<Resimulating>)),
+                    record_prediction_history.run_if(any_with_component::<PredictionHistory>),
+                )
+                    .chain()
+                    .in_set(LocomotionSet::Input),
+                (
+                    apply_teleports,
+                    apply_resizes,
                     update_grounded_state,
-                    detect_forced_slide,
-                    update_sprint_state,
-                    update_crouch_state,
-                    update_last_slide,
-                    detect_ladder,
-                    detect_ledge_grab,
-                    apply_ledge_grab,
-                    animate_ledge_climb,
-                    handle_jump,
+                    update_input_buffer,
+                    tick_cooldowns,
+                    advance_probe_rates,
                 )
-                    .chain(),
+                    .chain()
+                    .in_set(LocomotionSet::Grounding),
+                update_sprint_state.in_set(AbilityStep::Sprint),
+                update_crouch_state.in_set(AbilityStep::Crouch),
+                (ground_movement, crowd_movement).in_set(AbilityStep::Walk),
+                air_movement.in_set(AbilityStep::AirControl),
+                apply_gravity.in_set(AbilityStep::Gravity),
                 (
-                    variable_jump_height,
-                    ground_movement,
-                    apply_forced_slide,
-                    apply_ladder_movement,
-                    apply_step_up,
-                    air_movement,
-                    apply_slide,
-                    apply_gravity,
+                    apply_velocity_messages,
                     apply_velocity,
                     update_collider_height,
-                    emit_player_audio_messages,
+                    hold_disabled_players,
                 )
-                    .chain(),
-            )
-                .chain(),
+                    .chain()
+                    .in_set(LocomotionSet::ApplyVelocity),
+            ),
         );
 
-        // Clear look input at end of frame (jump is cleared in FixedUpdate)
+        // Clear look input at end of frame (jump is cleared by the controller step)
         app.add_systems(Last, clear_look_input);
     }
 }
 
-/// Spawns the player entity with all required components
-pub fn spawn_player(commands: &mut Commands, config: PlayerConfig, position: Vec3) {
+/// Jumping with coyote time, buffering, variable height, and slide-jump boost
+pub struct JumpPlugin;
+
+impl Plugin for JumpPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        app.add_systems(
+            schedule,
+            (
+                // Ladders, ledges, and vaults get the first look at a jump press
+                (update_last_slide, handle_jump)
+                    .chain()
+                    .in_set(AbilityStep::Jump),
+                variable_jump_height.in_set(AbilityStep::JumpCut),
+            ),
+        );
+    }
+}
+
+/// Sliding from sprint-crouch, with grace windows and air-buffered slides
+#[cfg(feature = "slide")]
+pub struct SlidePlugin;
+
+#[cfg(feature = "slide")]
+impl Plugin for SlidePlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        app.add_systems(
+            schedule,
+            (
+                detect_slide.in_set(AbilityStep::DetectSlide),
+                apply_slide.in_set(AbilityStep::Slide),
+            ),
+        );
+    }
+}
+
+/// Ledge grab, shuffle, climb, and wall jump (needs `LedgeGrabbable` surfaces)
+#[cfg(feature = "ledge")]
+pub struct LedgePlugin;
+
+#[cfg(feature = "ledge")]
+impl Plugin for LedgePlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        app.add_systems(
+            schedule,
+            (
+                detect_ledge_grab,
+                apply_ledge_grab,
+                animate_ledge_climb,
+                apply_root_motion_climb,
+            )
+                .chain()
+                .in_set(AbilityStep::Ledge),
+        );
+    }
+}
+
+/// Ladder climbing on `Ladder` volumes
+#[cfg(feature = "ladder")]
+pub struct LadderPlugin;
+
+#[cfg(feature = "ladder")]
+impl Plugin for LadderPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        app.add_systems(
+            schedule,
+            (
+                detect_ladder.in_set(AbilityStep::DetectLadder),
+                apply_ladder_movement.in_set(AbilityStep::Ladder),
+            ),
+        );
+    }
+}
+
+/// Vaulting over waist-high obstacles at a run
+#[cfg(feature = "vault")]
+pub struct VaultPlugin;
+
+#[cfg(feature = "vault")]
+impl Plugin for VaultPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        app.add_systems(
+            schedule,
+            (detect_vault, animate_vault)
+                .chain()
+                .in_set(AbilityStep::Vault),
+        );
+    }
+}
+
+/// Automatic stepping over stairs and curbs
+pub struct StepUpPlugin;
+
+impl Plugin for StepUpPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        app.add_systems(schedule, apply_step_up.in_set(AbilityStep::StepUp));
+    }
+}
+
+/// Forced downhill sliding on `ForceSlide` surfaces
+pub struct ForceSlidePlugin;
+
+impl Plugin for ForceSlidePlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = locomotion_schedule(app);
+        app.add_systems(
+            schedule,
+            (
+                detect_forced_slide.in_set(AbilityStep::DetectForcedSlide),
+                apply_forced_slide.in_set(AbilityStep::ForcedSlide),
+            ),
+        );
+    }
+}
+
+/// Every component the controller needs on the player body.
+///
+/// Insert it onto an existing entity (which needs a `Transform`) to turn it
+/// into a player, optionally with `player_input` for a device-driven one. The
+/// `spawn_player*` functions use it and also spawn the camera rig.
+#[derive(Bundle)]
+pub struct PlayerBundle {
+    pub player: Player,
+    pub config: PlayerConfig,
+    pub velocity: PlayerVelocity,
+    pub movement_basis: MovementBasis,
+    pub state: LocomotionState,
+    pub anim_state: LocomotionAnimState,
+    pub coyote_time: CoyoteTime,
+    pub jump_buffer: JumpBuffer,
+    pub input_buffer: InputBuffer,
+    pub air_time: AirTime,
+    pub sprint_grace: SprintGrace,
+    pub last_slide: LastSlide,
+    pub cooldowns: Cooldowns,
+    pub intent: LocomotionIntent,
+    pub policies: InputPolicies,
+    pub stick_response: StickResponse,
+    pub context_stack: InputContextStack,
+    pub interactor: Interactor,
+    pub movement_tracker: MovementTracker,
+    #[cfg(feature = "audio-events")]
+    pub audio_tracker: AudioTracker,
+    // Physics - Dynamic body with locked rotation, let Avian handle collisions
+    pub rigid_body: RigidBody,
+    pub collider: Collider,
+    pub layers: CollisionLayers,
+    pub locked_axes: LockedAxes,
+    pub linear_velocity: LinearVelocity,
+    pub interpolation: TranslationInterpolation,
+    pub friction: Friction,
+    pub restitution: Restitution,
+    pub gravity_scale: GravityScale,
+}
+
+impl PlayerBundle {
+    /// Builds the player body from `config` (collider size and layers included).
+    ///
+    /// `config` is run through `PlayerConfig::validate` first.
+    pub fn new(mut config: PlayerConfig) -> Self {
+        config.validate();
+        Self {
+            player: Player,
+            config,
+            velocity: PlayerVelocity::default(),
+            movement_basis: MovementBasis::default(),
+            state: LocomotionState::default(),
+            anim_state: LocomotionAnimState::default(),
+            coyote_time: CoyoteTime::default(),
+            jump_buffer: JumpBuffer::default(),
+            input_buffer: InputBuffer::default(),
+            air_time: AirTime::default(),
+            sprint_grace: SprintGrace::default(),
+            last_slide: LastSlide::default(),
+            cooldowns: Cooldowns::default(),
+            intent: LocomotionIntent::default(),
+            policies: InputPolicies::default(),
+            stick_response: StickResponse::default(),
+            context_stack: InputContextStack::default(),
+            interactor: Interactor::default(),
+            movement_tracker: MovementTracker::default(),
+            #[cfg(feature = "audio-events")]
+            audio_tracker: AudioTracker::default(),
+            rigid_body: RigidBody::Dynamic,
+            collider: config.collider.capsule(false),
+            layers: CollisionLayers::new(
+                config.collider.player_layer,
+                config.collider.collision_mask,
+            ),
+            locked_axes: LockedAxes::ROTATION_LOCKED,
+            linear_velocity: LinearVelocity::default(),
+            interpolation: TranslationInterpolation,
+            friction: Friction::new(0.0),  // No friction - we handle movement ourselves
+            restitution: Restitution::new(0.0),  // No bounce
+            gravity_scale: GravityScale(0.0),  // We handle gravity ourselves for more control
+        }
+    }
+}
+
+impl Default for PlayerBundle {
+    fn default() -> Self {
+        Self::new(PlayerConfig::default())
+    }
+}
+
+/// Input backend components reading from `device`, for use with `PlayerBundle`.
+///
+/// Bindings for other device kinds are dropped, so a gamepad player ignores
+/// the keyboard and a keyboard player ignores every gamepad.
+pub fn player_input(bindings: &InputBindings, device: InputDevice) -> impl Bundle {
+    (device, input_bundle(&bindings.for_device(device), device))
+}
+
+/// Configures and spawns a player: body, optional input, optional camera rig.
+///
+/// The `spawn_player*` functions are shorthands for common combinations.
+#[derive(Clone)]
+pub struct PlayerBuilder {
+    config: PlayerConfig,
+    position: Vec3,
+    input: Option<(InputBindings, InputDevice)>,
+    camera: bool,
+}
+
+impl PlayerBuilder {
+    /// A player at `position` with default bindings on any device and a camera rig
+    pub fn new(config: PlayerConfig, position: Vec3) -> Self {
+        Self {
+            config,
+            position,
+            input: Some((InputBindings::default(), InputDevice::Any)),
+            camera: true,
+        }
+    }
+
+    /// Reads input through `bindings` instead of the defaults
+    pub fn with_bindings(mut self, bindings: InputBindings) -> Self {
+        let device = self.input.map_or(InputDevice::Any, |(_, device)| device);
+        self.input = Some((bindings, device));
+        self
+    }
+
+    /// Reads input only from `device` (couch co-op)
+    pub fn with_device(mut self, device: InputDevice) -> Self {
+        let bindings = self.input.map(|(bindings, _)| bindings).unwrap_or_default();
+        self.input = Some((bindings, device));
+        self
+    }
+
+    /// Attaches no input backend; drive the player through `LocomotionIntent`
+    pub fn without_input(mut self) -> Self {
+        self.input = None;
+        self
+    }
+
+    /// Whether to spawn the camera rig (default true).
+    ///
+    /// Without one the controller runs headless and moves relative to its
+    /// `MovementBasis`; attach a rig later with `spawn_camera_rig`.
+    pub fn with_camera(mut self, camera: bool) -> Self {
+        self.camera = camera;
+        self
+    }
+
+    /// Spawns the player and returns its entity
+    pub fn spawn(self, commands: &mut Commands) -> Entity {
+        let bundle = PlayerBundle::new(self.config);
+        let config = bundle.config;
+        let player = commands
+            .spawn((
+                bundle,
+                Transform::from_translation(self.position),
+                Visibility::default(),
+            ))
+            .id();
+        if let Some((bindings, device)) = &self.input {
+            commands.entity(player).insert(player_input(bindings, *device));
+        }
+        if self.camera {
+            spawn_camera_rig(commands, player, &config);
+        }
+        player
+    }
+}
+
+/// Command that spawns a player built by `PlayerBuilder`.
+///
+/// Queue it from systems that only describe players, such as level loaders
+/// or respawn handlers, or put it in `AutoSpawnPlayer`:
+///
+/// ```ignore
+/// commands.queue(SpawnPlayer::new(PlayerConfig::default(), spawn_point));
+/// ```
+#[derive(Clone)]
+pub struct SpawnPlayer(pub PlayerBuilder);
+
+impl SpawnPlayer {
+    /// A player at `position` with default bindings on any device and a camera rig
+    pub fn new(config: PlayerConfig, position: Vec3) -> Self {
+        Self(PlayerBuilder::new(config, position))
+    }
+}
+
+impl From<PlayerBuilder> for SpawnPlayer {
+    fn from(builder: PlayerBuilder) -> Self {
+        Self(builder)
+    }
+}
+
+impl Command for SpawnPlayer {
+    fn apply(self, world: &mut World) {
+        self.0.spawn(&mut world.commands());
+        world.flush();
+    }
+}
+
+/// Player spawned at `Startup`, read then rather than when the plugins are added.
+///
+/// Off by default, so the app decides when and where players appear. Insert
+/// it with `enabled: true` for a single player at startup, and set `enabled`
+/// back to false (or remove the resource) before `Startup` to opt out again.
+#[derive(Resource, Clone)]
+pub struct AutoSpawnPlayer {
+    pub enabled: bool,
+    pub player: SpawnPlayer,
+}
+
+impl Default for AutoSpawnPlayer {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            player: SpawnPlayer::new(PlayerConfig::default(), Vec3::new(0.0, 2.0, 0.0)),
+        }
+    }
+}
+
+impl AutoSpawnPlayer {
+    /// Spawns `player` at startup
+    pub fn new(player: impl Into<SpawnPlayer>) -> Self {
+        Self {
+            enabled: true,
+            player: player.into(),
+        }
+    }
+}
+
+fn auto_spawn_player(mut commands: Commands, auto_spawn: Option<Res<AutoSpawnPlayer>>) {
+    if let Some(auto_spawn) = auto_spawn.filter(|auto_spawn| auto_spawn.enabled) {
+        commands.queue(auto_spawn.player.clone());
+    }
+}
+
+/// Spawns the player entity with all required components and its camera rig.
+///
+/// Returns the player entity; rig entities carry `CameraRigOf(player)`.
+pub fn spawn_player(commands: &mut Commands, config: PlayerConfig, position: Vec3) -> Entity {
+    PlayerBuilder::new(config, position).spawn(commands)
+}
+
+/// Spawns the player like `spawn_player`, using a custom binding set
+pub fn spawn_player_with_bindings(
+    commands: &mut Commands,
+    config: PlayerConfig,
+    bindings: &InputBindings,
+    position: Vec3,
+) -> Entity {
+    PlayerBuilder::new(config, position)
+        .with_bindings(bindings.clone())
+        .spawn(commands)
+}
+
+/// Spawns a local player that reads only from `device` (couch co-op).
+///
+/// Bindings for other device kinds are dropped, so a gamepad player ignores
+/// the keyboard and a keyboard player ignores every gamepad.
+pub fn spawn_player_with_device(
+    commands: &mut Commands,
+    config: PlayerConfig,
+    bindings: &InputBindings,
+    device: InputDevice,
+    position: Vec3,
+) -> Entity {
+    PlayerBuilder::new(config, position)
+        .with_bindings(bindings.clone())
+        .with_device(device)
+        .spawn(commands)
+}
+
+/// Spawns a player with no input backend attached.
+///
+/// Drive it by writing the `LocomotionIntent` components (bots, replays,
+/// server-authoritative movement).
+pub fn spawn_player_without_input(
+    commands: &mut Commands,
+    config: PlayerConfig,
+    position: Vec3,
+) -> Entity {
+    PlayerBuilder::new(config, position)
+        .without_input()
+        .spawn(commands)
+}
+
+/// Spawns the yaw -> pitch -> camera rig for `player` and returns the camera.
+///
+/// Use this for players inserted with `PlayerBundle` or built
+/// `with_camera(false)`. The camera follow system moves it onto the player.
+pub fn spawn_camera_rig(
+    commands: &mut Commands,
+    player: Entity,
+    config: &PlayerConfig,
+) -> Entity {
     // Spawn yaw entity (rotates on Y axis for left/right look)
     let yaw_entity = commands
         .spawn((
             CameraYaw,
-            Transform::from_translation(position),
+            CameraRigOf(player),
+            CameraSmoothing::default(),
+            Transform::default(),
             Visibility::default(),
         ))
         .id();
 
     // Spawn pitch entity as child (rotates on X axis for up/down look)
+    let eye_height = config.collider.eye_height(false);
     let pitch_entity = commands
         .spawn((
             CameraPitch,
+            CameraRigOf(player),
             PitchAngle::default(),
+            FreelookYaw::default(),
+            EyeHeight::new(eye_height),
+            CameraRecoil::default(),
             CameraConfig::default(),
-            Transform::from_translation(Vec3::new(0.0, config.stand_height / 2.0 - 0.1, 0.0)),
+            Transform::from_translation(Vec3::new(0.0, eye_height, 0.0)),
             Visibility::default(),
         ))
         .id();
@@ -112,6 +819,10 @@ pub fn spawn_player(commands: &mut Commands, config: PlayerConfig, position: Vec
     let camera_entity = commands
         .spawn((
             FpsCamera::default(),
+            CameraRigOf(player),
+            CameraMotion::default(),
+            SpringArm::default(),
+            PreviousGroundedState::default(),
             Camera3d::default(),
             Projection::Perspective(PerspectiveProjection {
                 fov: 90.0_f32.to_radians(),
@@ -125,77 +836,5 @@ pub fn spawn_player(commands: &mut Commands, config: PlayerConfig, position: Vec
     commands.entity(yaw_entity).add_child(pitch_entity);
     commands.entity(pitch_entity).add_child(camera_entity);
 
-    // Spawn player body
-    let capsule_height = config.stand_height - config.radius * 2.0;
-
-    commands
-        .spawn((
-            Player,
-            config,
-            PlayerVelocity::default(),
-            CoyoteTime::default(),
-            JumpBuffer::default(),
-            AirTime::default(),
-            SprintGrace::default(),
-            LastSlide::default(),
-            LedgeCooldown::default(),
-        ))
-        .insert((
-            // Input state
-            MoveInput::default(),
-            LookInput::default(),
-            SprintInput::default(),
-            CrouchInput::default(),
-            JumpPressed::default(),
-            JumpHeld::default(),
-        ))
-        .insert((
-            // Physics - Dynamic body with locked rotation, let Avian handle collisions
-            RigidBody::Dynamic,
-            Collider::capsule(config.radius, capsule_height),
-            CollisionLayers::new(config.player_layer, config.collision_mask),
-            LockedAxes::ROTATION_LOCKED,
-            LinearVelocity::default(),
-            TranslationInterpolation,
-            Friction::new(0.0),  // No friction - we handle movement ourselves
-            Restitution::new(0.0),  // No bounce
-            GravityScale(0.0),  // We handle gravity ourselves for more control
-        ))
-        .insert((
-            // Transform
-            Transform::from_translation(position),
-            Visibility::default(),
-        ))
-        .insert(
-            // Input bindings
-            actions!(Player[
-                (
-                    Action::<MoveAction>::new(),
-                    bindings![
-                        (KeyCode::KeyW, SwizzleAxis::YXZ),
-                        (KeyCode::KeyS, SwizzleAxis::YXZ, Negate::all()),
-                        KeyCode::KeyD,
-                        (KeyCode::KeyA, Negate::all()),
-                    ],
-                ),
-                (
-                    Action::<LookAction>::new(),
-                    bindings![
-                        Binding::mouse_motion(),
-                    ],
-                ),
-                (
-                    Action::<JumpAction>::new(),
-                    bindings![KeyCode::Space, GamepadButton::South],
-                ),
-                (
-                    Action::<SprintAction>::new(),
-                    bindings![KeyCode::ShiftLeft, GamepadButton::LeftTrigger],
-                ),
-                (
-                    Action::<CrouchAction>::new(),
-                    bindings![KeyCode::ControlLeft, GamepadButton::RightThumb],
-                ),
-            ]),
-        );
+    camera_entity
 }
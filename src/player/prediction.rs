@@ -0,0 +1,676 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use avian3d::prelude::*;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+
+use super::plugin::LocomotionSchedule;
+use super::recording::{IntentFrame, IntentPlayback, IntentTrack};
+use super::snapshot::LocomotionSnapshot;
+use super::state::{LocomotionDisabled, Player};
+
+/// Fixed tick counter, advanced at the start of every locomotion step.
+///
+/// Stamps predicted intent and history. Client and server keep it aligned
+/// however their netcode syncs clocks; with bevy_replicon,
+/// `LocomotionReplicationPlugin` sends it on connect.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct LocomotionTick(pub u32);
+
+/// Orders two ticks across `u32` wraparound, as long as they are less than
+/// half the range apart
+fn tick_cmp(a: u32, b: u32) -> Ordering {
+    (a.wrapping_sub(b) as i32).cmp(&0)
+}
+
+/// Intent for one fixed tick, stamped with the tick it drives
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickedIntent {
+    pub tick: u32,
+    pub frame: IntentFrame,
+}
+
+/// One predicted tick: the intent it ran with, the state it started from and
+/// the clock it ran on
+#[derive(Clone, Copy, Debug)]
+pub struct PredictedTick {
+    pub tick: u32,
+    pub intent: IntentFrame,
+    pub state: LocomotionSnapshot,
+    pub time: Time,
+}
+
+/// Client-side prediction for a locally controlled player.
+///
+/// Keeps the intent and starting state of recent ticks. Send `latest()` to
+/// the server every tick and write its corrections as `Reconcile` messages.
+#[derive(Component, Clone, Debug)]
+pub struct PredictionHistory {
+    /// Ticks kept, which bounds how late a correction can arrive
+    pub capacity: usize,
+    /// Position and velocity error accepted without rolling back
+    pub tolerance: f32,
+    ticks: VecDeque<PredictedTick>,
+}
+
+impl Default for PredictionHistory {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl PredictionHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tolerance: 0.01,
+            ticks: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Intent of the most recent tick, to send to the server
+    pub fn latest(&self) -> Option<TickedIntent> {
+        self.ticks.back().map(|predicted| TickedIntent {
+            tick: predicted.tick,
+            frame: predicted.intent,
+        })
+    }
+
+    /// The predicted tick `tick`, if still in the history
+    pub fn get(&self, tick: u32) -> Option<&PredictedTick> {
+        self.ticks.iter().find(|predicted| predicted.tick == tick)
+    }
+
+    /// Unconfirmed ticks, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &PredictedTick> {
+        self.ticks.iter()
+    }
+
+    fn push(&mut self, predicted: PredictedTick) {
+        while self
+            .ticks
+            .back()
+            .is_some_and(|last| tick_cmp(last.tick, predicted.tick).is_ge())
+        {
+            self.ticks.pop_back();
+        }
+        while self.ticks.len() >= self.capacity.max(1) {
+            self.ticks.pop_front();
+        }
+        self.ticks.push_back(predicted);
+    }
+}
+
+/// Authoritative state of `player` at the start of `tick`, from the server.
+///
+/// Applied before the next fixed step. If it disagrees with the prediction,
+/// the player is rolled back and its buffered intent replayed.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct Reconcile {
+    pub player: Entity,
+    pub tick: u32,
+    pub state: LocomotionSnapshot,
+}
+
+/// Present while replayed ticks run, so game systems can skip one-shot effects
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct Resimulating;
+
+/// Server-side queue of a client's intent, applied on the tick it is stamped with.
+///
+/// When a tick's intent hasn't arrived, the last one is repeated without its
+/// jump press.
+#[derive(Component, Clone, Debug, Default)]
+pub struct IntentQueue {
+    pending: VecDeque<TickedIntent>,
+    last: IntentFrame,
+}
+
+impl IntentQueue {
+    /// Queues intent received from the client, replacing any for the same tick
+    pub fn push(&mut self, intent: TickedIntent) {
+        let index = self
+            .pending
+            .partition_point(|queued| tick_cmp(queued.tick, intent.tick).is_lt());
+        match self.pending.get_mut(index) {
+            Some(queued) if queued.tick == intent.tick => *queued = intent,
+            _ => self.pending.insert(index, intent),
+        }
+    }
+
+    /// Takes the intent for `tick`, using late intent if that's all there is
+    fn take(&mut self, tick: u32) -> IntentFrame {
+        let mut current = None;
+        while self
+            .pending
+            .front()
+            .is_some_and(|queued| tick_cmp(queued.tick, tick).is_le())
+        {
+            current = self.pending.pop_front();
+        }
+        match current {
+            Some(intent) => {
+                self.last = intent.frame;
+                intent.frame
+            }
+            None => IntentFrame {
+                jump_pressed: false,
+                ..self.last
+            },
+        }
+    }
+}
+
+/// Advances `LocomotionTick`, first in the fixed step
+pub fn advance_locomotion_tick(mut tick: ResMut<LocomotionTick>) {
+    tick.0 = tick.0.wrapping_add(1);
+}
+
+/// Drives players with an `IntentQueue` from the intent stamped for this tick
+pub fn apply_intent_queue(world: &mut World) {
+    let tick = world.resource::<LocomotionTick>().0;
+    let mut query = world.query_filtered::<
+        (Entity, &mut IntentQueue),
+        (With<Player>, Without<LocomotionDisabled>),
+    >();
+    let frames: Vec<(Entity, IntentFrame)> =
+        query.iter_mut(world).map(|(player, mut queue)| (player, queue.take(tick))).collect();
+    for (player, frame) in frames {
+        frame.apply(world, player);
+    }
+}
+
+/// Records this tick's intent and starting state for predicted players
+pub fn record_prediction_history(world: &mut World) {
+    let tick = world.resource::<LocomotionTick>().0;
+    let time = *world.resource::<Time>();
+    let mut query = world
+        .query_filtered::<Entity, (With<PredictionHistory>, Without<LocomotionDisabled>)>();
+    let players: Vec<Entity> = query.iter(world).collect();
+    for player in players {
+        let (Some(intent), Some(state)) =
+            (IntentFrame::capture(world, player), LocomotionSnapshot::capture(world, player))
+        else {
+            continue;
+        };
+        if let Some(mut history) = world.get_mut::<PredictionHistory>(player) {
+            history.push(PredictedTick {
+                tick,
+                intent,
+                state,
+                time,
+            });
+        }
+    }
+}
+
+/// Applies `Reconcile` messages before the fixed step, newest per player
+pub fn reconcile_predictions(world: &mut World) {
+    let mut corrections: Vec<Reconcile> =
+        world.resource_mut::<Messages<Reconcile>>().drain().collect();
+    corrections.sort_by(|a, b| a.player.cmp(&b.player).then(tick_cmp(b.tick, a.tick)));
+    corrections.dedup_by_key(|correction| correction.player);
+    for correction in corrections {
+        resimulate(world, correction.player, correction.tick, &correction.state);
+    }
+}
+
+/// Rolls `player` back to the authoritative `state` at the start of `tick`
+/// and replays its buffered intent up to the present.
+///
+/// Returns whether a rollback ran. Corrections within
+/// `PredictionHistory::tolerance` only confirm the history, and ticks no
+/// longer in it are ignored. Other players and rigid bodies advance with the
+/// replay and are then put back where they were, like `step_player` does.
+pub fn resimulate(
+    world: &mut World,
+    player: Entity,
+    tick: u32,
+    state: &LocomotionSnapshot,
+) -> bool {
+    let Some(mut history) = world.get_mut::<PredictionHistory>(player) else {
+        return false;
+    };
+    let Some(index) = history.ticks.iter().position(|predicted| predicted.tick == tick) else {
+        return false;
+    };
+    // Everything before `tick` is confirmed
+    history.ticks.drain(..index);
+
+    let predicted = history.ticks[0].state;
+    if predicted.position.distance(state.position) <= history.tolerance
+        && predicted.velocity.distance(state.velocity) <= history.tolerance
+    {
+        return false;
+    }
+
+    // Replaying re-records these ticks with the corrected states
+    let ticks: Vec<(IntentFrame, Time)> =
+        history.ticks.drain(..).map(|predicted| (predicted.intent, predicted.time)).collect();
+    let others = SavedWorld::capture(world).without(player);
+    state.restore(world, player);
+    replay(world, player, tick, ticks);
+    others.restore(world);
+    true
+}
+
+/// Runs one locomotion tick for `player` from `state`, driven by `intent`,
+/// and returns the state it ends in, or `None` if `player` isn't a player.
+///
+/// Avian has no per-body step, so the tick runs like a replay: every player
+/// and rigid body advances with it, then their locomotion state, transforms
+/// and velocities are put back. Avian's contacts and sleeping state, and
+/// messages other than the locomotion ones written during the tick, are not
+/// restored.
+pub fn step_player(
+    world: &mut World,
+    player: Entity,
+    state: &LocomotionSnapshot,
+    intent: IntentFrame,
+) -> Option<LocomotionSnapshot> {
+    // Replaying a missing entity would panic
+    LocomotionSnapshot::capture(world, player)?;
+    let saved = SavedWorld::capture(world);
+    state.restore(world, player);
+    let tick = world.resource::<LocomotionTick>().0;
+    let time = tick_clock(world);
+    replay(world, player, tick, vec![(intent, time)]);
+    let stepped = LocomotionSnapshot::capture(world, player);
+    saved.restore(world);
+    stepped
+}
+
+/// Clock one controller tick runs on: the fixed clock, unless the controller
+/// runs outside `FixedUpdate`
+fn tick_clock(world: &World) -> Time {
+    let schedule = world.get_resource::<LocomotionSchedule>().copied().unwrap_or_default();
+    if schedule.controller == FixedUpdate.intern() {
+        world.resource::<Time<Fixed>>().as_generic()
+    } else {
+        *world.resource::<Time>()
+    }
+}
+
+/// What replayed ticks change besides the replayed player
+struct SavedWorld {
+    players: Vec<(Entity, LocomotionSnapshot)>,
+    bodies: Vec<(Entity, Transform, Position, Rotation, LinearVelocity, AngularVelocity)>,
+    histories: Vec<(Entity, PredictionHistory)>,
+    queues: Vec<(Entity, IntentQueue)>,
+}
+
+impl SavedWorld {
+    fn capture(world: &mut World) -> Self {
+        let mut player_query = world.query_filtered::<Entity, With<Player>>();
+        let players: Vec<Entity> = player_query.iter(world).collect();
+        let mut body_query = world.query_filtered::<
+            (Entity, &Transform, &Position, &Rotation, &LinearVelocity, &AngularVelocity),
+            (With<RigidBody>, Without<Player>),
+        >();
+        Self {
+            players: players
+                .into_iter()
+                .filter_map(|player| Some((player, LocomotionSnapshot::capture(world, player)?)))
+                .collect(),
+            bodies: body_query
+                .iter(world)
+                .map(|(entity, transform, position, rotation, linear, angular)| {
+                    (entity, *transform, *position, *rotation, *linear, *angular)
+                })
+                .collect(),
+            histories: cloned(world),
+            queues: cloned(world),
+        }
+    }
+
+    /// Leaves `player` and its re-recorded history as the replay left them
+    fn without(mut self, player: Entity) -> Self {
+        self.players.retain(|(entity, _)| *entity != player);
+        self.histories.retain(|(entity, _)| *entity != player);
+        self
+    }
+
+    fn restore(self, world: &mut World) {
+        for (player, snapshot) in self.players {
+            snapshot.restore(world, player);
+        }
+        for (entity, transform, position, rotation, linear, angular) in self.bodies {
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                entity.insert((transform, position, rotation, linear, angular));
+            }
+        }
+        reinsert(world, self.histories);
+        reinsert(world, self.queues);
+    }
+}
+
+fn cloned<C: Component + Clone>(world: &mut World) -> Vec<(Entity, C)> {
+    let mut query = world.query::<(Entity, &C)>();
+    query.iter(world).map(|(entity, component)| (entity, component.clone())).collect()
+}
+
+fn reinsert<C: Component>(world: &mut World, components: Vec<(Entity, C)>) {
+    for (entity, component) in components {
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.insert(component);
+        }
+    }
+}
+
+/// Runs the `LocomotionSchedule` controller and physics schedules once per
+/// tick, with `player` driven by each tick's intent on the clock it was
+/// recorded with, starting at `first_tick`.
+///
+/// Avian has no per-body step, so every body in the physics world advances;
+/// callers put the others back afterwards with `SavedWorld`.
+fn replay(world: &mut World, player: Entity, first_tick: u32, ticks: Vec<(IntentFrame, Time)>) {
+    let schedule = world.get_resource::<LocomotionSchedule>().copied().unwrap_or_default();
+    let live_intent = IntentFrame::capture(world, player);
+    let live_playback = world.entity_mut(player).take::<IntentPlayback>();
+    let live_tick = world.resource::<LocomotionTick>().0;
+    let live_time = *world.resource::<Time>();
+    let live_physics_time = world.get_resource::<Time<Physics>>().copied();
+    let live_substep_time = world.get_resource::<Time<Substeps>>().copied();
+
+    let (frames, clocks): (Vec<IntentFrame>, Vec<Time>) = ticks.into_iter().unzip();
+    world.entity_mut(player).insert(IntentPlayback::new(IntentTrack {
+        frames,
+        ..default()
+    }));
+    world.resource_mut::<LocomotionTick>().0 = first_tick.wrapping_sub(1);
+    world.insert_resource(Resimulating);
+    for time in clocks {
+        world.insert_resource(time);
+        world.run_schedule(schedule.controller);
+        // Missing without `PhysicsPlugins`
+        let _ = world.try_run_schedule(schedule.physics);
+    }
+    world.remove_resource::<Resimulating>();
+    world.insert_resource(live_time);
+    // The replayed ticks already advanced the physics clocks once
+    if let Some(time) = live_physics_time {
+        world.insert_resource(time);
+    }
+    if let Some(time) = live_substep_time {
+        world.insert_resource(time);
+    }
+    world.resource_mut::<LocomotionTick>().0 = live_tick;
+
+    let mut entity = world.entity_mut(player);
+    entity.remove::<IntentPlayback>();
+    if let Some(playback) = live_playback {
+        entity.insert(playback);
+    }
+    if let Some(intent) = live_intent {
+        intent.apply(world, player);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::player::{
+        AirTime, Cooldowns, CoyoteTime, InputBuffer, JumpBuffer, LastSlide, PlayerVelocity,
+        SprintGrace,
+    };
+
+    fn frame(x: f32, jump_pressed: bool) -> IntentFrame {
+        IntentFrame {
+            move_input: Vec2::new(x, 0.0),
+            jump_pressed,
+            ..default()
+        }
+    }
+
+    fn ticked(tick: u32, frame: IntentFrame) -> TickedIntent {
+        TickedIntent { tick, frame }
+    }
+
+    fn snapshot(position: Vec3) -> LocomotionSnapshot {
+        LocomotionSnapshot {
+            position,
+            rotation: Quat::IDENTITY,
+            velocity: Vec3::ZERO,
+            yaw: None,
+            pitch: None,
+            ground_normal: None,
+            ground_entity: None,
+            ground_point: None,
+            sprinting: false,
+            crouching: false,
+            jump_cut: false,
+            pending_slide: false,
+            sliding: None,
+            forced_sliding: None,
+            ledge_grabbing: None,
+            ledge_climbing: None,
+            on_ladder: None,
+            vaulting: None,
+            coyote_time: default(),
+            jump_buffer: default(),
+            air_time: default(),
+            sprint_grace: default(),
+            last_slide: default(),
+            cooldowns: default(),
+            input_buffer: default(),
+        }
+    }
+
+    fn predicted(tick: u32) -> PredictedTick {
+        PredictedTick {
+            tick,
+            intent: frame(tick as f32, false),
+            state: snapshot(Vec3::ZERO),
+            time: default(),
+        }
+    }
+
+    fn history_ticks(history: &PredictionHistory) -> Vec<u32> {
+        history.iter().map(|predicted| predicted.tick).collect()
+    }
+
+    #[test]
+    fn queue_takes_intent_for_its_tick() {
+        let mut queue = IntentQueue::default();
+        queue.push(ticked(6, frame(2.0, false)));
+        queue.push(ticked(5, frame(1.0, true)));
+
+        assert_eq!(queue.take(5), frame(1.0, true));
+        assert_eq!(queue.take(6), frame(2.0, false));
+    }
+
+    #[test]
+    fn queue_repeats_last_intent_without_jump() {
+        let mut queue = IntentQueue::default();
+        queue.push(ticked(5, frame(1.0, true)));
+
+        assert_eq!(queue.take(5), frame(1.0, true));
+        assert_eq!(queue.take(6), frame(1.0, false));
+    }
+
+    #[test]
+    fn queue_uses_newest_late_intent() {
+        let mut queue = IntentQueue::default();
+        queue.push(ticked(3, frame(3.0, false)));
+        queue.push(ticked(4, frame(4.0, false)));
+
+        assert_eq!(queue.take(5), frame(4.0, false));
+        assert!(queue.pending.is_empty());
+    }
+
+    #[test]
+    fn queue_replaces_duplicate_tick() {
+        let mut queue = IntentQueue::default();
+        queue.push(ticked(5, frame(1.0, false)));
+        queue.push(ticked(5, frame(2.0, false)));
+
+        assert_eq!(queue.pending.len(), 1);
+        assert_eq!(queue.take(5), frame(2.0, false));
+    }
+
+    #[test]
+    fn queue_orders_across_wraparound() {
+        let mut queue = IntentQueue::default();
+        queue.push(ticked(0, frame(2.0, false)));
+        queue.push(ticked(u32::MAX, frame(1.0, false)));
+
+        assert_eq!(queue.take(u32::MAX), frame(1.0, false));
+        assert_eq!(queue.take(0), frame(2.0, false));
+    }
+
+    #[test]
+    fn history_evicts_oldest_at_capacity() {
+        let mut history = PredictionHistory::new(3);
+        for tick in 1..=5 {
+            history.push(predicted(tick));
+        }
+
+        assert_eq!(history_ticks(&history), [3, 4, 5]);
+        assert_eq!(history.latest().map(|intent| intent.tick), Some(5));
+    }
+
+    #[test]
+    fn history_drops_ticks_replaced_by_an_earlier_one() {
+        let mut history = PredictionHistory::new(8);
+        for tick in 1..=3 {
+            history.push(predicted(tick));
+        }
+        history.push(predicted(2));
+
+        assert_eq!(history_ticks(&history), [1, 2]);
+    }
+
+    #[test]
+    fn history_keeps_ticks_across_wraparound() {
+        let mut history = PredictionHistory::new(8);
+        for tick in [u32::MAX - 1, u32::MAX, 0, 1] {
+            history.push(predicted(tick));
+        }
+
+        assert_eq!(history_ticks(&history), [u32::MAX - 1, u32::MAX, 0, 1]);
+    }
+
+    #[test]
+    fn resimulate_ignores_ticks_not_in_history() {
+        let mut world = World::new();
+        let mut history = PredictionHistory::new(8);
+        for tick in 5..=7 {
+            history.push(predicted(tick));
+        }
+        let player = world.spawn(history).id();
+
+        assert!(!resimulate(&mut world, player, 4, &snapshot(Vec3::X)));
+        let history = world.get::<PredictionHistory>(player).unwrap();
+        assert_eq!(history_ticks(history), [5, 6, 7]);
+    }
+
+    #[test]
+    fn resimulate_within_tolerance_only_confirms() {
+        let mut world = World::new();
+        let mut history = PredictionHistory::new(8);
+        for tick in 5..=7 {
+            history.push(predicted(tick));
+        }
+        let player = world.spawn(history).id();
+
+        let state = snapshot(Vec3::new(0.005, 0.0, 0.0));
+        assert!(!resimulate(&mut world, player, 6, &state));
+        let history = world.get::<PredictionHistory>(player).unwrap();
+        assert_eq!(history_ticks(history), [6, 7]);
+    }
+
+    #[derive(Resource, Default)]
+    struct ReplayedTicks(Vec<u32>);
+
+    fn record_replayed_tick(
+        tick: Res<LocomotionTick>,
+        time: Res<Time>,
+        resimulating: Option<Res<Resimulating>>,
+        mut replayed: ResMut<ReplayedTicks>,
+    ) {
+        assert!(resimulating.is_some());
+        assert_eq!(time.delta_secs(), 1.0 / 64.0);
+        replayed.0.push(tick.0);
+    }
+
+    #[test]
+    fn resimulate_rolls_back_and_replays_buffered_ticks() {
+        let mut world = World::new();
+        world.insert_resource(LocomotionTick(u32::MAX));
+        world.init_resource::<ReplayedTicks>();
+        world.insert_resource(Time::<()>::default());
+        let mut schedule = Schedule::new(FixedUpdate);
+        schedule.add_systems((advance_locomotion_tick, record_replayed_tick).chain());
+        world.add_schedule(schedule);
+
+        let mut history = PredictionHistory::new(8);
+        for tick in [u32::MAX - 2, u32::MAX - 1, u32::MAX] {
+            let mut time = Time::<()>::default();
+            time.advance_by(Duration::from_secs_f32(1.0 / 64.0));
+            history.push(PredictedTick {
+                time,
+                ..predicted(tick)
+            });
+        }
+        let player = world.spawn(history).id();
+
+        let correction = snapshot(Vec3::X);
+        assert!(resimulate(&mut world, player, u32::MAX - 1, &correction));
+        let replayed = &world.resource::<ReplayedTicks>().0;
+        assert_eq!(*replayed, [u32::MAX - 1, u32::MAX]);
+        assert_eq!(world.resource::<LocomotionTick>().0, u32::MAX);
+        assert!(!world.contains_resource::<Resimulating>());
+        assert_eq!(world.resource::<Time>().delta(), Duration::ZERO);
+
+        let entity = world.entity(player);
+        assert_eq!(entity.get::<Transform>().unwrap().translation, Vec3::X);
+        assert!(!entity.contains::<IntentPlayback>());
+        assert!(history_ticks(entity.get::<PredictionHistory>().unwrap()).is_empty());
+    }
+
+    fn push_players(mut query: Query<&mut Transform, With<Player>>) {
+        for mut transform in &mut query {
+            transform.translation.x += 1.0;
+        }
+    }
+
+    #[test]
+    fn resimulate_puts_other_players_back() {
+        let mut world = World::new();
+        world.insert_resource(LocomotionTick(7));
+        world.insert_resource(Time::<()>::default());
+        let mut schedule = Schedule::new(FixedUpdate);
+        schedule.add_systems((advance_locomotion_tick, push_players).chain());
+        world.add_schedule(schedule);
+
+        let mut history = PredictionHistory::new(8);
+        for tick in 5..=7 {
+            history.push(predicted(tick));
+        }
+        let player = world.spawn(history).id();
+        let other_position = Vec3::new(3.0, 1.0, -2.0);
+        let other = world
+            .spawn((
+                Player,
+                Transform::from_translation(other_position),
+                PlayerVelocity::default(),
+                CoyoteTime::default(),
+                JumpBuffer::default(),
+                AirTime::default(),
+                SprintGrace::default(),
+                LastSlide::default(),
+                Cooldowns::default(),
+                InputBuffer::default(),
+            ))
+            .id();
+
+        assert!(resimulate(&mut world, player, 5, &snapshot(Vec3::X)));
+        let transform = world.get::<Transform>(other).unwrap();
+        assert_eq!(transform.translation, other_position);
+    }
+}
@@ -0,0 +1,295 @@
+use bevy::prelude::*;
+
+use super::input::{CrouchInput, FreelookInput, JumpHeld, JumpPressed, MoveInput, SprintInput};
+use super::state::*;
+use crate::camera::{CameraPitch, CameraRig, CameraYaw, PitchAngle};
+
+/// Controller intent for one fixed tick
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntentFrame {
+    pub move_input: Vec2,
+    /// Movement yaw in radians (look is recorded as the resulting aim)
+    pub yaw: f32,
+    /// Pitch in radians
+    pub pitch: f32,
+    pub jump_pressed: bool,
+    pub jump_held: bool,
+    pub crouch: bool,
+    pub sprint: bool,
+    pub freelook: bool,
+}
+
+impl IntentFrame {
+    /// Reads `player`'s current intent, or `None` if it has no input components
+    pub fn capture(world: &World, player: Entity) -> Option<Self> {
+        let entity = world.get_entity(player).ok()?;
+        let rig = entity.get::<CameraRig>().map(CameraRig::entities).unwrap_or_default();
+        let rig_component = |e: &Entity| world.get_entity(*e).ok();
+        let yaw = rig
+            .iter()
+            .filter_map(rig_component)
+            .find(|e| e.contains::<CameraYaw>())
+            .and_then(|e| e.get::<Transform>())
+            .map(|yaw| yaw.rotation.to_euler(EulerRot::YXZ).0)
+            .or(entity.get::<MovementBasis>().map(|basis| basis.0))
+            .unwrap_or(0.0);
+        let pitch = rig
+            .iter()
+            .filter_map(rig_component)
+            .find(|e| e.contains::<CameraPitch>())
+            .and_then(|e| e.get::<PitchAngle>())
+            .map(|pitch| pitch.0)
+            .unwrap_or(0.0);
+
+        Some(Self {
+            move_input: entity.get::<MoveInput>()?.0,
+            yaw,
+            pitch,
+            jump_pressed: entity.get::<JumpPressed>()?.0,
+            jump_held: entity.get::<JumpHeld>()?.0,
+            crouch: entity.get::<CrouchInput>()?.0,
+            sprint: entity.get::<SprintInput>()?.0,
+            freelook: entity.get::<FreelookInput>()?.0,
+        })
+    }
+
+    /// Writes this intent into `player`'s inputs and camera rig, like `IntentPlayback`
+    pub fn apply(&self, world: &mut World, player: Entity) {
+        let Ok(mut entity) = world.get_entity_mut(player) else {
+            return;
+        };
+        entity.insert((
+            MoveInput(self.move_input),
+            JumpPressed(self.jump_pressed),
+            JumpHeld(self.jump_held),
+            CrouchInput(self.crouch),
+            SprintInput(self.sprint),
+            FreelookInput(self.freelook),
+            MovementBasis(self.yaw),
+        ));
+
+        let rig: Vec<Entity> = entity
+            .get::<CameraRig>()
+            .map(|rig| rig.entities().to_vec())
+            .unwrap_or_default();
+        for rig_entity in rig {
+            let Ok(mut rig_entity) = world.get_entity_mut(rig_entity) else {
+                continue;
+            };
+            let yaw = rig_entity.contains::<CameraYaw>();
+            let pitch = rig_entity.contains::<CameraPitch>();
+            if let Some(mut transform) = rig_entity.get_mut::<Transform>().filter(|_| yaw) {
+                transform.rotation = Quat::from_rotation_y(self.yaw);
+            }
+            if let Some(mut pitch_angle) = rig_entity.get_mut::<PitchAngle>().filter(|_| pitch) {
+                pitch_angle.0 = self.pitch;
+            }
+        }
+    }
+}
+
+/// A recorded sequence of per-tick intent, serializable with the `serde` feature
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntentTrack {
+    /// Fixed timestep the track was recorded at (Hz)
+    pub tick_rate: f64,
+    /// Player position when recording started
+    pub start_position: Vec3,
+    pub frames: Vec<IntentFrame>,
+}
+
+/// Records the player's intent every fixed tick while `recording` is set
+#[derive(Component, Clone, Debug, Default)]
+pub struct IntentRecorder {
+    pub recording: bool,
+    pub track: IntentTrack,
+}
+
+impl IntentRecorder {
+    /// Starts a fresh recording
+    pub fn start() -> Self {
+        Self {
+            recording: true,
+            track: IntentTrack::default(),
+        }
+    }
+}
+
+/// Drives the player from a recorded track, one frame per fixed tick.
+///
+/// Removed when the track ends unless `looping` is set. Spawn ghosts and test
+/// players with `spawn_player_without_input` so no device writes compete.
+#[derive(Component, Clone, Debug)]
+pub struct IntentPlayback {
+    pub track: IntentTrack,
+    /// Index of the next frame to play
+    pub cursor: usize,
+    pub looping: bool,
+}
+
+impl IntentPlayback {
+    pub fn new(track: IntentTrack) -> Self {
+        Self {
+            track,
+            cursor: 0,
+            looping: false,
+        }
+    }
+
+    pub fn looping(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+
+    /// Whether every frame has been played
+    pub fn finished(&self) -> bool {
+        self.cursor >= self.track.frames.len()
+    }
+}
+
+/// Writes the next recorded frame into the player's intent and camera rig.
+/// Runs first in the fixed chain so the frame is seen by every movement system.
+#[allow(clippy::type_complexity)]
+pub fn playback_intent(
+    mut commands: Commands,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut IntentPlayback,
+            &mut MoveInput,
+            &mut JumpPressed,
+            &mut JumpHeld,
+            &mut CrouchInput,
+            &mut SprintInput,
+            &mut FreelookInput,
+            &mut MovementBasis,
+            Option<&CameraRig>,
+        ),
+        (With<Player>, Without<LocomotionDisabled>),
+    >,
+    mut yaw_query: Query<&mut Transform, (With<CameraYaw>, Without<Player>)>,
+    mut pitch_query: Query<&mut PitchAngle, With<CameraPitch>>,
+) {
+    for (
+        entity,
+        mut playback,
+        mut move_input,
+        mut jump_pressed,
+        mut jump_held,
+        mut crouch,
+        mut sprint,
+        mut freelook,
+        mut basis,
+        rig,
+    ) in &mut player_query
+    {
+        if playback.finished() && playback.looping {
+            playback.cursor = 0;
+        }
+        let Some(&frame) = playback.track.frames.get(playback.cursor) else {
+            commands.entity(entity).remove::<IntentPlayback>();
+            continue;
+        };
+        playback.cursor += 1;
+
+        move_input.0 = frame.move_input;
+        jump_pressed.0 = frame.jump_pressed;
+        jump_held.0 = frame.jump_held;
+        crouch.0 = frame.crouch;
+        sprint.0 = frame.sprint;
+        freelook.0 = frame.freelook;
+        basis.0 = frame.yaw;
+
+        let Some(rig) = rig else {
+            continue;
+        };
+        let mut yaws = yaw_query.iter_many_mut(rig.entities());
+        while let Some(mut yaw_transform) = yaws.fetch_next() {
+            yaw_transform.rotation = Quat::from_rotation_y(frame.yaw);
+        }
+        let mut pitches = pitch_query.iter_many_mut(rig.entities());
+        while let Some(mut pitch_angle) = pitches.fetch_next() {
+            pitch_angle.0 = frame.pitch;
+        }
+    }
+}
+
+/// Appends the player's current intent to active recorders
+#[allow(clippy::type_complexity)]
+pub fn record_intent(
+    mut player_query: Query<
+        (
+            &Transform,
+            &mut IntentRecorder,
+            &MoveInput,
+            &JumpPressed,
+            &JumpHeld,
+            &CrouchInput,
+            &SprintInput,
+            &FreelookInput,
+            &MovementBasis,
+            Option<&CameraRig>,
+        ),
+        With<Player>,
+    >,
+    pitch_query: Query<&PitchAngle, With<CameraPitch>>,
+    time: Res<Time<Fixed>>,
+) {
+    for (
+        transform,
+        mut recorder,
+        move_input,
+        jump,
+        jump_held,
+        crouch,
+        sprint,
+        freelook,
+        basis,
+        rig,
+    ) in &mut player_query
+    {
+        if !recorder.recording {
+            continue;
+        }
+
+        if recorder.track.frames.is_empty() {
+            recorder.track.tick_rate = 1.0 / time.timestep().as_secs_f64();
+            recorder.track.start_position = transform.translation;
+        }
+
+        let rig = rig.map(CameraRig::entities).unwrap_or_default();
+        let pitch = pitch_query.iter_many(rig).next().map(|pitch| pitch.0).unwrap_or(0.0);
+
+        recorder.track.frames.push(IntentFrame {
+            move_input: move_input.0,
+            yaw: basis.0,
+            pitch,
+            jump_pressed: jump.0,
+            jump_held: jump_held.0,
+            crouch: crouch.0,
+            sprint: sprint.0,
+            freelook: freelook.0,
+        });
+    }
+}
+
+/// Body pose for one fixed tick
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransformFrame {
+    pub translation: Vec3,
+    /// Movement yaw in radians
+    pub yaw: f32,
+}
+
+/// A recorded sequence of per-tick poses, for ghosts that replay a run without
+/// simulating it. Serializable with the `serde` feature.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransformTrack {
+    /// Fixed timestep the track was recorded at (Hz)
+    pub tick_rate: f64,
+    pub frames: Vec<TransformFrame>,
+}
@@ -0,0 +1,93 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::state::*;
+
+/// Changes a player's body size at runtime (shrink potions, child characters).
+///
+/// Writing `PlayerConfig::collider` directly rebuilds the capsule around the
+/// body center, leaving the feet in the air or in the floor. This keeps the
+/// feet where they are, and crouches the player if the new standing height
+/// doesn't fit. The eye height, ground check, ledge and step-up probes all
+/// follow the new size from the config.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct ResizePlayer {
+    pub player: Entity,
+    /// New standing capsule height
+    pub stand_height: f32,
+    /// New crouching capsule height
+    pub crouch_height: f32,
+    /// New capsule radius
+    pub radius: f32,
+}
+
+impl ResizePlayer {
+    /// Resizes to `collider` with every dimension multiplied by `factor`.
+    ///
+    /// Pass the spawn config's collider rather than the current one so
+    /// repeated resizes don't compound.
+    pub fn scaled(player: Entity, collider: &ColliderConfig, factor: f32) -> Self {
+        let scaled = collider.scaled(factor);
+        Self {
+            player,
+            stand_height: scaled.stand_height,
+            crouch_height: scaled.crouch_height,
+            radius: scaled.radius,
+        }
+    }
+}
+
+/// Applies `ResizePlayer` at the start of the fixed tick, before grounding
+pub fn apply_resizes(
+    mut commands: Commands,
+    mut reader: MessageReader<ResizePlayer>,
+    mut player_query: Query<
+        (&mut Transform, &mut Position, &mut PlayerConfig, Has<Crouching>),
+        With<Player>,
+    >,
+    spatial_query: SpatialQuery,
+) {
+    for msg in reader.read() {
+        let Ok((mut transform, mut position, mut config, crouching)) =
+            player_query.get_mut(msg.player)
+        else {
+            continue;
+        };
+
+        let old_height = config.collider.height(crouching);
+        config.collider.stand_height = msg.stand_height;
+        config.collider.crouch_height = msg.crouch_height;
+        config.collider.radius = msg.radius;
+        config.validate();
+
+        // Keep the feet planted; the collider itself is rebuilt on config change
+        let new_height = config.collider.height(crouching);
+        let growth = new_height - old_height;
+        let old_top = transform.translation.y + old_height / 2.0;
+        transform.translation.y += growth / 2.0;
+        position.0 = transform.translation;
+
+        if crouching || growth <= 0.0 {
+            continue;
+        }
+
+        // Duck if the head no longer fits; standing resumes once there is room
+        let check_shape = Collider::capsule(config.collider.radius * 0.9, growth);
+        let check_pos = Vec3::new(
+            transform.translation.x,
+            old_top + growth / 2.0,
+            transform.translation.z,
+        );
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.world_layer);
+        let cast_config = ShapeCastConfig {
+            max_distance: 0.01,
+            ..default()
+        };
+        let blocked = spatial_query
+            .cast_shape(&check_shape, check_pos, Quat::IDENTITY, Dir3::Y, &cast_config, &filter)
+            .is_some();
+        if blocked {
+            commands.entity(msg.player).insert(Crouching);
+        }
+    }
+}
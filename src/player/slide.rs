@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+
+use super::arbitration::AbilityArbiter;
+use super::buffer::{BufferedInput, InputBuffer};
+use super::input::CrouchInput;
+use super::state::*;
+
+/// Starts slides from crouch presses while sprinting, within the sprint grace
+/// window, or on landing with a slide buffered in the air
+#[allow(clippy::type_complexity)]
+pub fn detect_slide(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &CrouchInput,
+        &PlayerConfig,
+        &PlayerVelocity,
+        &SprintGrace,
+        &mut InputBuffer,
+        Has<Grounded>,
+        Has<Sprinting>,
+        Has<Crouching>,
+        Has<Sliding>,
+        Has<PendingSlide>,
+    ), (Without<CrowdController>, Without<LocomotionDisabled>)>,
+    mut arbiter: AbilityArbiter,
+) {
+    for (entity, crouch_input, config, velocity, sprint_grace, mut buffer, grounded, sprinting, crouching, sliding, pending_slide) in
+        &mut query
+    {
+        // Already sliding - let apply_slide manage it
+        if !crouch_input.0 || sliding {
+            continue;
+        }
+
+        let horizontal_vel = Vec3::new(velocity.x, 0.0, velocity.z);
+        let horizontal_speed = horizontal_vel.length();
+
+        // Landed with a pending slide from air
+        if pending_slide && grounded {
+            commands.entity(entity).remove::<PendingSlide>();
+            let allowed = horizontal_speed > 0.5
+                && arbiter.request(&mut commands, entity, Ability::Slide, &config.abilities);
+            if allowed {
+                let dir = horizontal_vel.normalize_or_zero();
+                commands.entity(entity).insert((
+                    Crouching,
+                    Sliding {
+                        direction: dir,
+                        elapsed: 0.0,
+                        initial_speed: horizontal_speed * config.crouch_slide.slide_boost,
+                    },
+                ));
+                commands.entity(entity).remove::<Sprinting>();
+                continue;
+            }
+        }
+
+        // Buffer slide if pressing crouch in the air with speed
+        if !grounded && !crouching && horizontal_speed > config.crouch_slide.min_slide_speed {
+            commands.entity(entity).insert((Crouching, PendingSlide));
+            continue;
+        }
+
+        // Check if we should start sliding (ground initiation)
+        let in_grace = sprint_grace.timer < config.crouch_slide.sprint_slide_grace;
+        // A crouch pressed just before slide conditions are met still slides
+        let crouch_slide = &config.crouch_slide;
+        let fresh_press =
+            !crouching || buffer.is_buffered(BufferedInput::Crouch, crouch_slide.crouch_buffer);
+
+        let slide_initiate = if sprinting && horizontal_speed >= crouch_slide.min_slide_speed {
+            // Active sprint slide
+            Some((horizontal_vel.normalize_or_zero(), horizontal_speed))
+        } else if fresh_press && grounded && in_grace && horizontal_speed > 0.5 {
+            // Grace window slide
+            let dir = horizontal_vel.normalize_or_zero();
+            Some((dir, config.movement.sprint_speed))
+        } else {
+            None
+        };
+
+        if let Some((slide_dir, slide_speed)) = slide_initiate {
+            let allowed = fresh_press
+                && grounded
+                && arbiter.request(&mut commands, entity, Ability::Slide, &config.abilities);
+            if allowed {
+                buffer.clear(BufferedInput::Crouch);
+                commands.entity(entity).insert((
+                    Crouching,
+                    Sliding {
+                        direction: slide_dir,
+                        elapsed: 0.0,
+                        initial_speed: slide_speed * config.crouch_slide.slide_boost,
+                    },
+                ));
+                commands.entity(entity).remove::<Sprinting>();
+            }
+        }
+    }
+}
+
+/// Applies slide movement
+#[allow(clippy::type_complexity)]
+pub fn apply_slide(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &PlayerConfig,
+            &mut PlayerVelocity,
+            &mut Sliding,
+            Option<&TimeDilation>,
+        ),
+        Without<LocomotionDisabled>,
+    >,
+    time: Res<Time>,
+) {
+    for (entity, config, mut velocity, mut sliding, dilation) in &mut query {
+        sliding.elapsed += player_dt(time.delta_secs(), dilation);
+        let elapsed = sliding.elapsed;
+
+        if elapsed >= config.crouch_slide.slide_duration {
+            // End slide
+            commands.entity(entity).remove::<Sliding>();
+            continue;
+        }
+
+        // Gradual deceleration curve: higher slide_friction = more speed retained early
+        let t = elapsed / config.crouch_slide.slide_duration;
+        let speed = sliding.initial_speed * (1.0 - t.powf(config.crouch_slide.slide_friction));
+
+        // Override horizontal velocity with slide
+        velocity.x = sliding.direction.x * speed;
+        velocity.z = sliding.direction.z * speed;
+    }
+}
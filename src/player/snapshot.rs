@@ -0,0 +1,195 @@
+use avian3d::prelude::*;
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::prelude::*;
+
+use super::buffer::InputBuffer;
+use super::cooldown::Cooldowns;
+use super::state::*;
+use crate::camera::{CameraPitch, CameraRig, CameraYaw, FreelookYaw, PitchAngle};
+
+/// Everything needed to put a player back exactly where it was: body,
+/// velocity, view, stance, active states, their timers and buffered presses.
+///
+/// Capture and restore need the whole `World` because the view lives on the
+/// camera rig; from a regular system, queue the restore as a command:
+///
+/// ```ignore
+/// commands.queue(move |world: &mut World| snapshot.restore(world, player));
+/// ```
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocomotionSnapshot {
+    pub position: Vec3,
+    /// Body rotation
+    pub rotation: Quat,
+    pub velocity: Vec3,
+    /// Camera yaw in radians, or the `MovementBasis` of a player without a rig
+    pub yaw: Option<f32>,
+    /// Camera pitch in radians, if the player has a rig
+    pub pitch: Option<f32>,
+    /// Ground normal while grounded
+    pub ground_normal: Option<Vec3>,
+    /// Entity stood on while grounded
+    pub ground_entity: Option<Entity>,
+    /// Ground contact under the body while grounded
+    pub ground_point: Option<Vec3>,
+    pub sprinting: bool,
+    pub crouching: bool,
+    pub jump_cut: bool,
+    pub pending_slide: bool,
+    pub sliding: Option<Sliding>,
+    pub forced_sliding: Option<ForcedSliding>,
+    pub ledge_grabbing: Option<LedgeGrabbing>,
+    pub ledge_climbing: Option<LedgeClimbing>,
+    pub on_ladder: Option<OnLadder>,
+    pub vaulting: Option<Vaulting>,
+    pub coyote_time: CoyoteTime,
+    pub jump_buffer: JumpBuffer,
+    pub air_time: AirTime,
+    pub sprint_grace: SprintGrace,
+    pub last_slide: LastSlide,
+    pub cooldowns: Cooldowns,
+    pub input_buffer: InputBuffer,
+}
+
+impl MapEntities for LocomotionSnapshot {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        if let Some(ground) = &mut self.ground_entity {
+            *ground = entity_mapper.get_mapped(*ground);
+        }
+    }
+}
+
+impl LocomotionSnapshot {
+    /// Captures `player`, or `None` if it isn't a complete player
+    pub fn capture(world: &World, player: Entity) -> Option<Self> {
+        let entity = world.get_entity(player).ok()?;
+        if !entity.contains::<Player>() {
+            return None;
+        }
+        let transform = entity.get::<Transform>()?;
+
+        let rig = entity.get::<CameraRig>().map(CameraRig::entities).unwrap_or_default();
+        let yaw = rig.iter().find_map(|&e| {
+            let yaw_entity = world.get_entity(e).ok()?;
+            yaw_entity.contains::<CameraYaw>().then_some(())?;
+            let (yaw, _, _) = yaw_entity.get::<Transform>()?.rotation.to_euler(EulerRot::YXZ);
+            Some(yaw)
+        });
+        let yaw = yaw.or(entity.get::<MovementBasis>().map(|basis| basis.0));
+        let pitch = rig.iter().find_map(|&e| {
+            let pitch_entity = world.get_entity(e).ok()?;
+            pitch_entity.contains::<CameraPitch>().then_some(())?;
+            pitch_entity.get::<PitchAngle>().map(|angle| angle.0)
+        });
+
+        Some(Self {
+            position: transform.translation,
+            rotation: transform.rotation,
+            velocity: entity.get::<PlayerVelocity>()?.0,
+            yaw,
+            pitch,
+            ground_normal: entity
+                .contains::<Grounded>()
+                .then(|| entity.get::<GroundNormal>().map_or(Vec3::Y, |normal| normal.0)),
+            ground_entity: entity.get::<GroundEntity>().map(|ground| ground.0),
+            ground_point: entity.get::<GroundPoint>().map(|point| point.0),
+            sprinting: entity.contains::<Sprinting>(),
+            crouching: entity.contains::<Crouching>(),
+            jump_cut: entity.contains::<JumpCut>(),
+            pending_slide: entity.contains::<PendingSlide>(),
+            sliding: entity.get::<Sliding>().copied(),
+            forced_sliding: entity.get::<ForcedSliding>().copied(),
+            ledge_grabbing: entity.get::<LedgeGrabbing>().copied(),
+            ledge_climbing: entity.get::<LedgeClimbing>().copied(),
+            on_ladder: entity.get::<OnLadder>().copied(),
+            vaulting: entity.get::<Vaulting>().copied(),
+            coyote_time: *entity.get::<CoyoteTime>()?,
+            jump_buffer: *entity.get::<JumpBuffer>()?,
+            air_time: *entity.get::<AirTime>()?,
+            sprint_grace: *entity.get::<SprintGrace>()?,
+            last_slide: *entity.get::<LastSlide>()?,
+            cooldowns: *entity.get::<Cooldowns>()?,
+            input_buffer: *entity.get::<InputBuffer>()?,
+        })
+    }
+
+    /// Puts `player` back into this snapshot's state
+    pub fn restore(&self, world: &mut World, player: Entity) {
+        let Ok(mut entity) = world.get_entity_mut(player) else {
+            return;
+        };
+
+        entity.insert((
+            Transform {
+                translation: self.position,
+                rotation: self.rotation,
+                ..entity.get::<Transform>().copied().unwrap_or_default()
+            },
+            Position(self.position),
+            PlayerVelocity(self.velocity),
+            LinearVelocity(self.velocity),
+            self.coyote_time,
+            self.jump_buffer,
+            self.air_time,
+            self.sprint_grace,
+            self.last_slide,
+            self.cooldowns,
+            self.input_buffer,
+        ));
+        if let Some(yaw) = self.yaw {
+            entity.insert(MovementBasis(yaw));
+        }
+
+        set_marker(&mut entity, self.ground_normal.map(|_| Grounded));
+        set_marker(&mut entity, self.ground_normal.map(GroundNormal));
+        set_marker(&mut entity, self.ground_entity.map(GroundEntity));
+        set_marker(&mut entity, self.ground_point.map(GroundPoint));
+        set_marker(&mut entity, self.sprinting.then_some(Sprinting));
+        set_marker(&mut entity, self.crouching.then_some(Crouching));
+        set_marker(&mut entity, self.jump_cut.then_some(JumpCut));
+        set_marker(&mut entity, self.pending_slide.then_some(PendingSlide));
+        set_marker(&mut entity, self.sliding);
+        set_marker(&mut entity, self.forced_sliding);
+        set_marker(&mut entity, self.ledge_grabbing);
+        set_marker(&mut entity, self.ledge_climbing);
+        set_marker(&mut entity, self.on_ladder);
+        set_marker(&mut entity, self.vaulting);
+
+        let rig: Vec<Entity> = entity
+            .get::<CameraRig>()
+            .map(|rig| rig.entities().to_vec())
+            .unwrap_or_default();
+        for rig_entity in rig {
+            let Ok(mut rig_entity) = world.get_entity_mut(rig_entity) else {
+                continue;
+            };
+            let is_yaw = rig_entity.contains::<CameraYaw>();
+            if let Some(mut transform) = rig_entity.get_mut::<Transform>().filter(|_| is_yaw) {
+                // Snap past any camera smoothing
+                transform.translation = self.position;
+                if let Some(yaw) = self.yaw {
+                    transform.rotation = Quat::from_rotation_y(yaw);
+                }
+            }
+            if let Some(pitch) = self.pitch.filter(|_| rig_entity.contains::<CameraPitch>()) {
+                rig_entity.insert((PitchAngle(pitch), FreelookYaw(0.0)));
+                if let Some(mut transform) = rig_entity.get_mut::<Transform>() {
+                    transform.rotation = Quat::from_rotation_x(pitch);
+                }
+            }
+        }
+    }
+}
+
+/// Inserts `value`, or removes the component when `None`
+fn set_marker<T: Component>(entity: &mut EntityWorldMut, value: Option<T>) {
+    match value {
+        Some(value) => {
+            entity.insert(value);
+        }
+        None => {
+            entity.remove::<T>();
+        }
+    }
+}
@@ -4,38 +4,133 @@ use bevy::prelude::*;
 use crate::physics::GameLayer;
 
 /// Marker component for the player entity (also used as input context)
-#[derive(Component, Default)]
+#[derive(Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player;
 
-/// Player movement configuration
-#[derive(Component, Clone, Copy)]
+/// Player movement configuration, grouped by ability.
+///
+/// Every section has its own `Default`, so presets and overrides only spell
+/// out the sections and fields they change.
+#[derive(Component, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PlayerConfig {
+    pub movement: MovementConfig,
+    pub jump: JumpConfig,
+    pub crouch_slide: CrouchSlideConfig,
+    pub ledge: LedgeConfig,
+    pub ladder: LadderConfig,
+    pub vault: VaultConfig,
+    pub footsteps: FootstepConfig,
+    pub collider: ColliderConfig,
+    pub abilities: AbilityConfig,
+}
+
+/// Walking, sprinting and ground handling
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MovementConfig {
     /// Walking speed in m/s
     pub walk_speed: f32,
     /// Sprinting speed in m/s
     pub sprint_speed: f32,
-    /// Crouching speed in m/s
-    pub crouch_speed: f32,
+    /// Always run: sprint whenever moving forward, and use sprint input to toggle walking
+    pub auto_sprint: bool,
+    /// Forward input needed to auto-sprint (0.0-1.0)
+    pub auto_sprint_threshold: f32,
     /// Ground acceleration
     pub ground_accel: f32,
     /// Ground friction/deceleration
     pub ground_friction: f32,
     /// Air acceleration (reduced control)
     pub air_accel: f32,
+    /// Maximum horizontal speed (m/s), 0.0 = uncapped
+    pub max_horizontal_speed: f32,
+    /// How fast speed above `max_horizontal_speed` bleeds back to it (m/s²);
+    /// 0.0 = clamp to the cap instantly
+    pub overspeed_decay: f32,
+    /// Horizontal speed that is clamped even while overspeed decays (m/s),
+    /// 0.0 = none
+    pub overspeed_limit: f32,
+    /// Maximum walkable slope angle in degrees (steeper slopes cause the player to slide off)
+    pub max_slope_angle: f32,
+    /// Maximum height of obstacles the player can auto-step over (m)
+    pub step_up_height: f32,
+    /// Seconds a dash press stays valid (see `BufferedInput::Dash`)
+    pub dash_buffer: f32,
+    /// Speed into a wall (m/s) that reports `MovementEventKind::HitWall`, 0.0 = never
+    pub wall_hit_speed: f32,
+    /// Seconds airborne before a fall reports `MovementEventKind::LongFall`, 0.0 = never
+    pub long_fall_time: f32,
+    /// Fall speed (m/s) that reports `MovementEventKind::LongFall` early, 0.0 = never
+    pub long_fall_speed: f32,
+}
+
+impl Default for MovementConfig {
+    fn default() -> Self {
+        Self {
+            walk_speed: 5.0,
+            sprint_speed: 8.0,
+            auto_sprint: false,
+            auto_sprint_threshold: 0.7,
+            ground_accel: 50.0,
+            ground_friction: 40.0,
+            air_accel: 15.0,
+            max_horizontal_speed: 20.0,
+            overspeed_decay: 0.0,
+            overspeed_limit: 0.0,
+            max_slope_angle: 39.0,
+            step_up_height: 0.35,
+            dash_buffer: 0.1,
+            wall_hit_speed: 5.0,
+            long_fall_time: 1.5,
+            long_fall_speed: 20.0,
+        }
+    }
+}
+
+/// Jump height, forgiveness windows and variable height
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct JumpConfig {
     /// Jump impulse velocity
-    pub jump_velocity: f32,
+    pub velocity: f32,
     /// Multiplier applied to upward velocity when jump is released early (0.0-1.0)
-    pub jump_cut_multiplier: f32,
+    pub cut_multiplier: f32,
     /// Coyote time duration in seconds
     pub coyote_time: f32,
     /// Jump buffer duration in seconds
-    pub jump_buffer: f32,
-    /// Standing collider height
-    pub stand_height: f32,
-    /// Crouching collider height
-    pub crouch_height: f32,
-    /// Collider radius
-    pub radius: f32,
+    pub buffer: f32,
+}
+
+impl Default for JumpConfig {
+    fn default() -> Self {
+        Self {
+            velocity: 8.0,
+            cut_multiplier: 0.5,
+            coyote_time: 0.15,
+            buffer: 0.1,
+        }
+    }
+}
+
+/// Crouching, slides and slide-jumps
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CrouchSlideConfig {
+    /// Crouching speed in m/s
+    pub crouch_speed: f32,
+    /// Seconds a crouch press stays valid for starting a slide
+    pub crouch_buffer: f32,
     /// Minimum horizontal speed to initiate a slide (m/s)
     pub min_slide_speed: f32,
     /// Slide duration in seconds
@@ -50,28 +145,278 @@ pub struct PlayerConfig {
     pub slide_jump_boost: f32,
     /// Grace period after slide ends where slide-jump boost still applies (seconds)
     pub slide_jump_grace: f32,
-    /// Maximum horizontal speed (m/s), 0.0 = uncapped
-    pub max_horizontal_speed: f32,
+}
+
+impl Default for CrouchSlideConfig {
+    fn default() -> Self {
+        Self {
+            crouch_speed: 2.5,
+            crouch_buffer: 0.12,
+            min_slide_speed: 6.0,
+            slide_duration: 0.8,
+            slide_friction: 2.0,
+            slide_boost: 1.2,
+            sprint_slide_grace: 0.15,
+            slide_jump_boost: 3.0,
+            slide_jump_grace: 0.2,
+        }
+    }
+}
+
+/// Ledge grabbing, shuffling and climbing
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LedgeConfig {
     /// Forward probe distance past capsule surface for ledge detection
-    pub ledge_detect_reach: f32,
+    pub detect_reach: f32,
     /// Duration of the animated ledge climb in seconds
-    pub ledge_climb_duration: f32,
+    pub climb_duration: f32,
     /// Ledge shuffle speed in m/s
-    pub ledge_shuffle_speed: f32,
+    pub shuffle_speed: f32,
     /// Ledge shuffle head bob amplitude in meters
-    pub ledge_shuffle_bob_amplitude: f32,
+    pub shuffle_bob_amplitude: f32,
     /// Seconds before re-grab is allowed after releasing a ledge
-    pub ledge_cooldown: f32,
+    pub cooldown: f32,
     /// Maximum downward speed at which ledge grab is allowed (m/s), 0.0 = uncapped
-    pub ledge_grab_max_fall_speed: f32,
+    pub grab_max_fall_speed: f32,
     /// Whether ledge grab triggers while the player is moving upward
-    pub ledge_grab_ascending: bool,
+    pub grab_ascending: bool,
+    /// Seconds an airborne jump press stays valid for grabbing a ledge
+    pub grab_buffer: f32,
+}
+
+impl Default for LedgeConfig {
+    fn default() -> Self {
+        Self {
+            detect_reach: 0.6,
+            climb_duration: 1.05,
+            shuffle_speed: 1.75,
+            shuffle_bob_amplitude: 0.006,
+            cooldown: 0.4,
+            grab_max_fall_speed: 10.0,
+            grab_ascending: false,
+            grab_buffer: 0.15,
+        }
+    }
+}
+
+/// Ladder climbing
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LadderConfig {
     /// Ladder climbing speed in m/s
-    pub ladder_climb_speed: f32,
-    /// Maximum walkable slope angle in degrees (steeper slopes cause the player to slide off)
-    pub max_slope_angle: f32,
-    /// Maximum height of obstacles the player can auto-step over (m)
-    pub step_up_height: f32,
+    pub climb_speed: f32,
+}
+
+impl Default for LadderConfig {
+    fn default() -> Self {
+        Self { climb_speed: 4.0 }
+    }
+}
+
+/// Vaulting over waist-high obstacles
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct VaultConfig {
+    /// Forward probe distance past capsule surface for obstacle detection
+    pub detect_reach: f32,
+    /// Lowest obstacle top above the feet that is vaulted instead of stepped (m)
+    pub min_height: f32,
+    /// Highest obstacle top above the feet that can be vaulted (m)
+    pub max_height: f32,
+    /// Thickest obstacle that can be vaulted (m)
+    pub max_depth: f32,
+    /// Gap kept between the feet and the obstacle top while passing over (m)
+    pub clearance: f32,
+    /// Horizontal speed needed to vault, on top of sprinting (m/s)
+    pub min_speed: f32,
+    /// Shortest vault in seconds; faster vaults are paced by the entry speed
+    pub min_duration: f32,
+    /// Fraction of the entry speed kept when the vault ends
+    pub exit_speed_multiplier: f32,
+}
+
+impl Default for VaultConfig {
+    fn default() -> Self {
+        Self {
+            detect_reach: 0.5,
+            min_height: 0.5,
+            max_height: 1.5,
+            max_depth: 1.0,
+            clearance: 0.1,
+            min_speed: 6.0,
+            min_duration: 0.35,
+            exit_speed_multiplier: 1.0,
+        }
+    }
+}
+
+/// Exclusive movement abilities, resolved by `AbilityArbiter`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ability {
+    /// `Sliding`
+    Slide,
+    /// `ForcedSliding`
+    ForcedSlide,
+    /// `OnLadder`
+    Ladder,
+    /// `LedgeGrabbing`, including the climb
+    Ledge,
+    /// `Vaulting`
+    Vault,
+}
+
+impl Ability {
+    pub const ALL: [Ability; 5] = [
+        Ability::Slide,
+        Ability::ForcedSlide,
+        Ability::Ladder,
+        Ability::Ledge,
+        Ability::Vault,
+    ];
+}
+
+/// How one ability competes with the others
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AbilityRule {
+    /// Higher wins a conflict; on a tie the active ability keeps running
+    pub priority: u8,
+    /// Mutual-exclusion groups as a bitmask; abilities sharing a bit can't be
+    /// active together (0 = never conflicts)
+    pub groups: u32,
+}
+
+impl AbilityRule {
+    /// Group every built-in ability is in by default
+    pub const MOVEMENT: u32 = 1;
+
+    pub fn new(priority: u8, groups: u32) -> Self {
+        Self { priority, groups }
+    }
+
+    /// Whether both rules share a group
+    pub fn excludes(&self, other: &AbilityRule) -> bool {
+        self.groups & other.groups != 0
+    }
+}
+
+/// Which abilities may run together and which wins when they can't.
+///
+/// The defaults keep a ledge, ladder or vault until it ends, let forced
+/// slides cancel voluntary ones, and refuse slides during any of them.
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AbilityConfig {
+    pub slide: AbilityRule,
+    pub forced_slide: AbilityRule,
+    pub ladder: AbilityRule,
+    pub ledge: AbilityRule,
+    pub vault: AbilityRule,
+}
+
+impl Default for AbilityConfig {
+    fn default() -> Self {
+        Self {
+            slide: AbilityRule::new(10, AbilityRule::MOVEMENT),
+            forced_slide: AbilityRule::new(20, AbilityRule::MOVEMENT),
+            ladder: AbilityRule::new(30, AbilityRule::MOVEMENT),
+            ledge: AbilityRule::new(30, AbilityRule::MOVEMENT),
+            vault: AbilityRule::new(30, AbilityRule::MOVEMENT),
+        }
+    }
+}
+
+impl AbilityConfig {
+    pub fn rule(&self, ability: Ability) -> AbilityRule {
+        match ability {
+            Ability::Slide => self.slide,
+            Ability::ForcedSlide => self.forced_slide,
+            Ability::Ladder => self.ladder,
+            Ability::Ledge => self.ledge,
+            Ability::Vault => self.vault,
+        }
+    }
+
+    pub fn with_rule(mut self, ability: Ability, rule: AbilityRule) -> Self {
+        match ability {
+            Ability::Slide => self.slide = rule,
+            Ability::ForcedSlide => self.forced_slide = rule,
+            Ability::Ladder => self.ladder = rule,
+            Ability::Ledge => self.ledge = rule,
+            Ability::Vault => self.vault = rule,
+        }
+        self
+    }
+}
+
+/// Footstep spacing and loudness per stance, for `PlayerSound::Footstep`
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FootstepConfig {
+    /// Meters walked between footsteps
+    pub walk_stride: f32,
+    /// Meters sprinted between footsteps
+    pub sprint_stride: f32,
+    /// Meters crouch-walked between footsteps
+    pub crouch_stride: f32,
+    /// Footstep volume scale while walking
+    pub walk_volume: f32,
+    /// Footstep volume scale while sprinting
+    pub sprint_volume: f32,
+    /// Footstep volume scale while crouching
+    pub crouch_volume: f32,
+}
+
+impl Default for FootstepConfig {
+    fn default() -> Self {
+        Self {
+            walk_stride: 2.5,
+            sprint_stride: 3.2,
+            crouch_stride: 1.5,
+            walk_volume: 1.0,
+            sprint_volume: 1.0,
+            crouch_volume: 0.4,
+        }
+    }
+}
+
+impl FootstepConfig {
+    /// Stride and volume scale for the given stance
+    pub fn for_stance(&self, crouching: bool, sprinting: bool) -> (f32, f32) {
+        if crouching {
+            (self.crouch_stride, self.crouch_volume)
+        } else if sprinting {
+            (self.sprint_stride, self.sprint_volume)
+        } else {
+            (self.walk_stride, self.walk_volume)
+        }
+    }
+}
+
+/// Capsule size and physics layers
+#[derive(Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ColliderConfig {
+    /// Standing collider height
+    pub stand_height: f32,
+    /// Crouching collider height
+    pub crouch_height: f32,
+    /// Collider radius
+    pub radius: f32,
     /// Physics layer the player body belongs to
     pub player_layer: LayerMask,
     /// Physics layer mask used for world queries (ground, ledge, step-up, crouch)
@@ -80,40 +425,12 @@ pub struct PlayerConfig {
     pub collision_mask: LayerMask,
 }
 
-impl Default for PlayerConfig {
+impl Default for ColliderConfig {
     fn default() -> Self {
         Self {
-            walk_speed: 5.0,
-            sprint_speed: 8.0,
-            crouch_speed: 2.5,
-            ground_accel: 50.0,
-            ground_friction: 40.0,
-            air_accel: 15.0,
-            jump_velocity: 8.0,
-            jump_cut_multiplier: 0.5,
-            coyote_time: 0.15,
-            jump_buffer: 0.1,
             stand_height: 1.8,
             crouch_height: 1.0,
             radius: 0.4,
-            min_slide_speed: 6.0,
-            slide_duration: 0.8,
-            slide_friction: 2.0,
-            slide_boost: 1.2,
-            sprint_slide_grace: 0.15,
-            slide_jump_boost: 3.0,
-            slide_jump_grace: 0.2,
-            max_horizontal_speed: 20.0,
-            ledge_detect_reach: 0.6,
-            ledge_climb_duration: 1.05,
-            ledge_shuffle_speed: 1.75,
-            ledge_shuffle_bob_amplitude: 0.006,
-            ledge_cooldown: 0.4,
-            ledge_grab_max_fall_speed: 10.0,
-            ledge_grab_ascending: false,
-            ladder_climb_speed: 4.0,
-            max_slope_angle: 39.0,
-            step_up_height: 0.35,
             player_layer: GameLayer::Player.into(),
             world_layer: GameLayer::World.into(),
             collision_mask: LayerMask::from([GameLayer::World, GameLayer::Trigger]),
@@ -121,73 +438,492 @@ impl Default for PlayerConfig {
     }
 }
 
+impl ColliderConfig {
+    /// Capsule height for the given stance
+    pub fn height(&self, crouching: bool) -> f32 {
+        if crouching {
+            self.crouch_height
+        } else {
+            self.stand_height
+        }
+    }
+
+    /// Camera eye height above the body center for the given stance
+    pub fn eye_height(&self, crouching: bool) -> f32 {
+        self.height(crouching) / 2.0 - 0.1
+    }
+
+    /// Player collider for the given stance
+    pub fn capsule(&self, crouching: bool) -> Collider {
+        let length = self.height(crouching) - self.radius * 2.0;
+        Collider::capsule(self.radius, length.max(0.1))
+    }
+
+    /// Same layers with every dimension multiplied by `factor`
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            stand_height: self.stand_height * factor,
+            crouch_height: self.crouch_height * factor,
+            radius: self.radius * factor,
+            ..self
+        }
+    }
+}
+
+/// Shortest ledge climb `PlayerConfig::validate` allows, in seconds
+const MIN_LEDGE_CLIMB_DURATION: f32 = 0.05;
+
+impl PlayerConfig {
+    pub fn with_movement(mut self, movement: MovementConfig) -> Self {
+        self.movement = movement;
+        self
+    }
+
+    pub fn with_jump(mut self, jump: JumpConfig) -> Self {
+        self.jump = jump;
+        self
+    }
+
+    pub fn with_crouch_slide(mut self, crouch_slide: CrouchSlideConfig) -> Self {
+        self.crouch_slide = crouch_slide;
+        self
+    }
+
+    pub fn with_ledge(mut self, ledge: LedgeConfig) -> Self {
+        self.ledge = ledge;
+        self
+    }
+
+    pub fn with_ladder(mut self, ladder: LadderConfig) -> Self {
+        self.ladder = ladder;
+        self
+    }
+
+    pub fn with_vault(mut self, vault: VaultConfig) -> Self {
+        self.vault = vault;
+        self
+    }
+
+    pub fn with_footsteps(mut self, footsteps: FootstepConfig) -> Self {
+        self.footsteps = footsteps;
+        self
+    }
+
+    pub fn with_collider(mut self, collider: ColliderConfig) -> Self {
+        self.collider = collider;
+        self
+    }
+
+    pub fn with_abilities(mut self, abilities: AbilityConfig) -> Self {
+        self.abilities = abilities;
+        self
+    }
+
+    /// Clamps values the controller can't work with, warning about each fix.
+    ///
+    /// Runs on spawn and whenever a tuning asset is applied. Returns `true` if
+    /// anything was changed.
+    pub fn validate(&mut self) -> bool {
+        let mut changed = false;
+        let collider = &mut self.collider;
+
+        let defaults = ColliderConfig::default();
+        for (name, value, default) in [
+            ("stand_height", &mut collider.stand_height, defaults.stand_height),
+            ("crouch_height", &mut collider.crouch_height, defaults.crouch_height),
+            ("radius", &mut collider.radius, defaults.radius),
+        ] {
+            if !(value.is_finite() && *value > 0.0) {
+                warn!("PlayerConfig: {name} {value} must be positive, using {default}");
+                *value = default;
+                changed = true;
+            }
+        }
+
+        if collider.radius * 2.0 > collider.stand_height {
+            let radius = collider.stand_height / 2.0;
+            warn!(
+                "PlayerConfig: radius {} does not fit stand_height {}, clamping radius to {radius}",
+                collider.radius, collider.stand_height
+            );
+            collider.radius = radius;
+            changed = true;
+        }
+
+        if collider.crouch_height > collider.stand_height {
+            warn!(
+                "PlayerConfig: crouch_height {} is taller than stand_height {}, clamping",
+                collider.crouch_height, collider.stand_height
+            );
+            collider.crouch_height = collider.stand_height;
+            changed = true;
+        }
+
+        if collider.crouch_height < collider.radius * 2.0 {
+            let crouch_height = collider.radius * 2.0;
+            warn!(
+                "PlayerConfig: crouch_height {} is shorter than the capsule's diameter, clamping \
+                 to {crouch_height}",
+                collider.crouch_height
+            );
+            collider.crouch_height = crouch_height;
+            changed = true;
+        }
+
+        if self.crouch_slide.min_slide_speed > self.movement.sprint_speed {
+            warn!(
+                "PlayerConfig: min_slide_speed {} is above sprint_speed {} so slides never start, \
+                 clamping",
+                self.crouch_slide.min_slide_speed, self.movement.sprint_speed
+            );
+            self.crouch_slide.min_slide_speed = self.movement.sprint_speed;
+            changed = true;
+        }
+
+        if self.ledge.climb_duration <= 0.0 {
+            warn!(
+                "PlayerConfig: ledge climb_duration {} must be positive, using {}",
+                self.ledge.climb_duration, MIN_LEDGE_CLIMB_DURATION
+            );
+            self.ledge.climb_duration = MIN_LEDGE_CLIMB_DURATION;
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Arena-shooter feel: fast, snappy, strong air control and no speed cap,
+    /// so strafe-jumping keeps its momentum. No sprint, short slides.
+    pub fn quake() -> Self {
+        Self {
+            movement: MovementConfig {
+                walk_speed: 8.0,
+                sprint_speed: 8.0,
+                ground_accel: 100.0,
+                ground_friction: 60.0,
+                air_accel: 40.0,
+                max_horizontal_speed: 0.0,
+                ..default()
+            },
+            jump: JumpConfig {
+                velocity: 7.0,
+                cut_multiplier: 1.0,
+                coyote_time: 0.05,
+                ..default()
+            },
+            crouch_slide: CrouchSlideConfig {
+                crouch_speed: 4.0,
+                min_slide_speed: 7.5,
+                slide_duration: 0.5,
+                slide_boost: 1.0,
+                slide_jump_boost: 0.0,
+                ..default()
+            },
+            ledge: LedgeConfig {
+                climb_duration: 0.6,
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Source-engine feel: moderate ground speed, fixed-height jumps and
+    /// enough air control to bunny-hop, with a high speed cap.
+    pub fn source() -> Self {
+        Self {
+            movement: MovementConfig {
+                walk_speed: 6.35,
+                sprint_speed: 8.0,
+                ground_accel: 60.0,
+                ground_friction: 40.0,
+                air_accel: 25.0,
+                max_horizontal_speed: 30.0,
+                ..default()
+            },
+            jump: JumpConfig {
+                velocity: 6.8,
+                cut_multiplier: 1.0,
+                coyote_time: 0.05,
+                ..default()
+            },
+            crouch_slide: CrouchSlideConfig {
+                crouch_speed: 2.1,
+                min_slide_speed: 7.0,
+                slide_duration: 0.6,
+                slide_boost: 1.0,
+                slide_jump_boost: 0.0,
+                ..default()
+            },
+            ledge: LedgeConfig {
+                climb_duration: 0.8,
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Modern military shooter feel: slow walk, distinct sprint, little air
+    /// control and long boosted slides that chain into slide-jumps.
+    pub fn modern_military() -> Self {
+        Self {
+            movement: MovementConfig {
+                walk_speed: 4.5,
+                sprint_speed: 7.5,
+                ground_accel: 35.0,
+                ground_friction: 30.0,
+                air_accel: 8.0,
+                max_horizontal_speed: 12.0,
+                ..default()
+            },
+            jump: JumpConfig {
+                velocity: 6.0,
+                cut_multiplier: 0.6,
+                coyote_time: 0.15,
+                ..default()
+            },
+            crouch_slide: CrouchSlideConfig {
+                crouch_speed: 2.0,
+                min_slide_speed: 6.0,
+                slide_duration: 1.0,
+                slide_boost: 1.3,
+                slide_jump_boost: 2.0,
+                ..default()
+            },
+            ledge: LedgeConfig {
+                climb_duration: 1.2,
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Walking-sim feel: slow, weighty movement with gentle acceleration,
+    /// low jumps, barely any air control and soft short slides.
+    pub fn walking_sim() -> Self {
+        Self {
+            movement: MovementConfig {
+                walk_speed: 2.5,
+                sprint_speed: 4.0,
+                ground_accel: 12.0,
+                ground_friction: 10.0,
+                air_accel: 3.0,
+                max_horizontal_speed: 6.0,
+                ..default()
+            },
+            jump: JumpConfig {
+                velocity: 4.5,
+                coyote_time: 0.2,
+                buffer: 0.15,
+                ..default()
+            },
+            crouch_slide: CrouchSlideConfig {
+                crouch_speed: 1.5,
+                min_slide_speed: 3.5,
+                slide_duration: 0.5,
+                slide_boost: 1.0,
+                slide_jump_boost: 0.0,
+                ..default()
+            },
+            ledge: LedgeConfig {
+                climb_duration: 1.4,
+                shuffle_speed: 1.0,
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Realistic feel: human running speeds, firm footing, knee-high jumps
+    /// with no air control to speak of, short unboosted slides and slow
+    /// climbs and vaults that shed speed.
+    pub fn realistic() -> Self {
+        Self {
+            movement: MovementConfig {
+                walk_speed: 3.0,
+                sprint_speed: 6.0,
+                ground_accel: 20.0,
+                ground_friction: 25.0,
+                air_accel: 1.5,
+                max_horizontal_speed: 8.0,
+                ..default()
+            },
+            jump: JumpConfig {
+                velocity: 4.0,
+                cut_multiplier: 1.0,
+                coyote_time: 0.05,
+                ..default()
+            },
+            crouch_slide: CrouchSlideConfig {
+                crouch_speed: 1.5,
+                min_slide_speed: 5.0,
+                slide_duration: 0.4,
+                slide_boost: 1.0,
+                slide_jump_boost: 0.0,
+                ..default()
+            },
+            ledge: LedgeConfig {
+                climb_duration: 1.3,
+                shuffle_speed: 0.8,
+                ..default()
+            },
+            vault: VaultConfig {
+                min_speed: 4.0,
+                min_duration: 0.5,
+                exit_speed_multiplier: 0.7,
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Same as `quake`
+    pub fn quake_like() -> Self {
+        Self::quake()
+    }
+
+    /// Same as `source`
+    pub fn source_like() -> Self {
+        Self::source()
+    }
+}
+
 /// Current player velocity
-#[derive(Component, Default, Deref, DerefMut)]
+#[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerVelocity(pub Vec3);
 
+/// Facing yaw in radians that movement input is relative to.
+///
+/// Copied from the camera rig's yaw every tick for players that have one.
+/// Players without a rig (NPCs, bots) move relative to whatever their AI
+/// writes here.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MovementBasis(pub f32);
+
+impl MovementBasis {
+    /// Faces along the horizontal part of `direction`
+    pub fn facing(direction: Vec3) -> Self {
+        Self((-direction.x).atan2(-direction.z))
+    }
+
+    /// Horizontal forward
+    pub fn forward(&self) -> Vec3 {
+        Quat::from_rotation_y(self.0) * Vec3::NEG_Z
+    }
+
+    /// Horizontal right
+    pub fn right(&self) -> Vec3 {
+        Quat::from_rotation_y(self.0) * Vec3::X
+    }
+}
+
 /// Marker: player is on the ground
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct Grounded;
 
 /// Ground surface normal (set when grounded)
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[component(storage = "SparseSet")]
 pub struct GroundNormal(pub Vec3);
 
+/// Where the ground probe hit the ground under the player (set when grounded)
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[component(storage = "SparseSet")]
+pub struct GroundPoint(pub Vec3);
+
+/// Entity the player is standing on (set when grounded)
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[component(storage = "SparseSet")]
+pub struct GroundEntity(pub Entity);
+
 /// Marker: player is sprinting
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct Sprinting;
 
 /// Marker: player is crouching
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct Crouching;
 
 /// Player is sliding
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct Sliding {
     /// Direction of the slide
     pub direction: Vec3,
-    /// Time when slide started
-    pub start_time: f32,
+    /// Seconds since the slide started
+    pub elapsed: f32,
     /// Initial velocity when slide started
     pub initial_speed: f32,
 }
 
 /// Tracks time since sprinting ended (for sprint-slide grace period)
-#[derive(Component, Default)]
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SprintGrace {
     pub timer: f32,
+    /// Auto-sprint is toggled off in favor of walking
+    pub walk_toggled: bool,
+    /// Sprint input on the previous tick (for auto-sprint toggling)
+    pub sprint_was_held: bool,
 }
 
 /// Marker: slide should initiate on landing (crouch pressed while airborne)
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct PendingSlide;
 
 /// Tracks the most recent slide for slide-jump boost
-#[derive(Component, Default)]
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LastSlide {
     pub direction: Vec3,
     pub timer: f32,
 }
 
 /// Marker: variable jump height cut has been applied this jump
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct JumpCut;
 
 /// Coyote time tracking
-#[derive(Component, Default)]
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoyoteTime {
     /// Time since leaving ground
     pub timer: f32,
 }
 
 /// Jump buffer tracking
-#[derive(Component, Default)]
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JumpBuffer {
     /// Time since jump was pressed
     pub timer: f32,
@@ -196,13 +932,19 @@ pub struct JumpBuffer {
 }
 
 /// Tracks the last time player was grounded (for fall damage, landing effects)
-#[derive(Component, Default)]
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AirTime {
     pub duration: f32,
+    /// Highest body center height since last grounded (current height while grounded)
+    pub peak_y: f32,
 }
 
 /// Marker: player is grabbing a ledge
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct LedgeGrabbing {
     pub surface_point: Vec3,
@@ -210,7 +952,9 @@ pub struct LedgeGrabbing {
 }
 
 /// Marker: player is on a ladder
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct OnLadder {
     /// Outward-facing normal from the ladder surface toward the player
@@ -218,7 +962,9 @@ pub struct OnLadder {
 }
 
 /// Marker: player is being forced to slide down a surface
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct ForcedSliding {
     /// Downhill direction on the slope surface
@@ -227,14 +973,95 @@ pub struct ForcedSliding {
     pub surface_normal: Vec3,
 }
 
-/// Cooldown timer before ledge re-grab is allowed
-#[derive(Component, Default)]
-pub struct LedgeCooldown {
-    pub timer: f32,
+/// Marker: controller is frozen (photo mode, cutscenes, pause menus).
+///
+/// Player systems skip entities with this component, so timers, state markers
+/// and `PlayerVelocity` are preserved; the body is held in place and the
+/// camera holds its effects until it is removed.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocomotionDisabled;
+
+/// Triggers `PlayerMovementEvent` and `StateChanged` on this player as entity
+/// events, in addition to writing them as messages, so per-player observers
+/// can react to them
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct LocomotionObservers;
+
+/// Runs the optional probes (step-up, ladder detection, stand-up clearance)
+/// only every `interval` fixed ticks, for distant or NPC controllers.
+///
+/// Grounding and movement still run every tick. Players are spread across
+/// ticks by entity so a crowd doesn't probe on the same frame.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReducedProbeRate {
+    /// Fixed ticks between probes (1 = every tick)
+    pub interval: u32,
+    pub(crate) tick: u32,
+    pub(crate) due: bool,
+}
+
+impl ReducedProbeRate {
+    pub fn new(interval: u32) -> Self {
+        Self {
+            interval,
+            tick: 0,
+            due: true,
+        }
+    }
+
+    /// Whether the probes run this tick
+    pub fn is_due(&self) -> bool {
+        self.due
+    }
+}
+
+/// Rate a player's controller runs at relative to world time (1.0 = normal).
+///
+/// Scales the player's timers, acceleration, gravity and the velocity handed
+/// to physics. For bullet time, slow `Time<Virtual>` and give the hero the
+/// inverse of its relative speed so it keeps moving at normal speed.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeDilation(pub f32);
+
+impl Default for TimeDilation {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// `dt` as experienced by a player (unchanged without `TimeDilation`)
+pub(crate) fn player_dt(dt: f32, dilation: Option<&TimeDilation>) -> f32 {
+    dilation.map_or(dt, |dilation| dt * dilation.0)
+}
+
+/// Cheap controller tier for background NPCs.
+///
+/// Grounds with one ray per `ReducedProbeRate` interval and reuses the hit in
+/// between, skips ledge, ladder, slide and forced-slide detection, and walks
+/// with a single acceleration instead of separate ground and air control.
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[require(ReducedProbeRate = ReducedProbeRate::new(4))]
+pub struct CrowdController {
+    /// Ground height, normal and entity from the last probe
+    pub(crate) ground: Option<(f32, Vec3, Entity)>,
+}
+
+/// Whether a player's optional probes run this tick (always without `ReducedProbeRate`)
+pub(crate) fn probe_due(rate: Option<&ReducedProbeRate>) -> bool {
+    rate.is_none_or(ReducedProbeRate::is_due)
 }
 
 /// Active ledge climb animation state
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[component(storage = "SparseSet")]
 pub struct LedgeClimbing {
     pub start_pos: Vec3,
@@ -243,3 +1070,114 @@ pub struct LedgeClimbing {
     pub elapsed: f32,
     pub duration: f32,
 }
+
+/// Active vault animation state.
+///
+/// The body rises from `start_pos` to `apex_pos` above the obstacle's near
+/// edge, passes over to `end_pos` past its far side, then falls with
+/// `exit_velocity`.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[component(storage = "SparseSet")]
+pub struct Vaulting {
+    pub start_pos: Vec3,
+    pub apex_pos: Vec3,
+    pub end_pos: Vec3,
+    /// Horizontal velocity restored when the vault ends
+    pub exit_velocity: Vec3,
+    pub wall_normal: Vec3,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+/// The player's overall movement state, derived from the state markers.
+///
+/// Updated once per fixed step after all transitions, so it is always exactly
+/// one value. Prefer it over combining markers when driving animation or HUD.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LocomotionState {
+    /// Grounded, not sprinting or crouching
+    #[default]
+    Walking,
+    Sprinting,
+    Crouching,
+    Sliding,
+    ForcedSliding,
+    /// Not grounded and not in any other state
+    Airborne,
+    LedgeGrabbing,
+    LedgeClimbing,
+    OnLadder,
+    Vaulting,
+    /// `LocomotionDisabled` is present
+    Disabled,
+}
+
+/// Body posture for animation
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stance {
+    #[default]
+    Standing,
+    /// Crouching or sliding
+    Crouching,
+}
+
+/// Normalized animation parameters, updated every fixed tick.
+///
+/// Feed these into an `AnimationGraph` instead of re-deriving them from
+/// velocity and the state markers.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocomotionAnimState {
+    /// Horizontal speed in m/s
+    pub planar_speed: f32,
+    /// Horizontal speed as a fraction of `sprint_speed` (above 1 when boosted)
+    pub speed_ratio: f32,
+    /// Horizontal velocity direction relative to the view: +Y forward, +X right
+    /// (zero when still)
+    pub move_direction: Vec2,
+    /// Position in the stride cycle in `[0, 1)`; one cycle is two footsteps.
+    ///
+    /// Advances by `FootstepConfig` strides, so footstep sounds land on 0.5
+    /// and 0.0, and head bob follows it with `FpsCamera::head_bob_sync_gait`.
+    /// Resets to 0.0 whenever the player stops walking.
+    pub gait_phase: f32,
+    /// Vertical speed in m/s, positive upward
+    pub vertical_speed: f32,
+    /// Seconds since leaving the ground
+    pub air_time: f32,
+    pub stance: Stance,
+    /// Active ability or movement mode
+    pub state: LocomotionState,
+}
+
+/// Sent whenever a player's `LocomotionState` changes.
+///
+/// Also triggered on the player for observers when it has `LocomotionObservers`.
+#[derive(Message, EntityEvent, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StateChanged {
+    #[event_target]
+    pub player: Entity,
+    pub from: LocomotionState,
+    pub to: LocomotionState,
+}
+
+/// Overrides a player's velocity from game code (grapples, throws, vehicle exits).
+///
+/// Applied after the movement systems and before the velocity is handed to
+/// physics, so it wins over walking, air control and gravity for that tick.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum VelocityMessage {
+    /// Replace the velocity
+    Set { player: Entity, velocity: Vec3 },
+    /// Add to the velocity
+    Add { player: Entity, velocity: Vec3 },
+    /// Replace the velocity and go airborne: drops grounding, slides, ledge
+    /// and ladder states, stands up if there is room and spends coyote time
+    Launch { player: Entity, velocity: Vec3 },
+}
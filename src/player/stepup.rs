@@ -1,25 +1,57 @@
 use avian3d::prelude::*;
+use bevy::ecs::entity::EntityHashSet;
 use bevy::prelude::*;
 
-use super::audio::PlayerAudioMessage;
+#[cfg(feature = "audio-events")]
+use super::audio::{PlayerAudioMessage, PlayerSound};
+#[cfg(feature = "audio-events")]
+use super::events::{surface_material, SurfaceMaterial};
+use super::determinism::LocomotionDeterminism;
+use super::events::{MovementEventKind, PlayerMovementEvent};
+use super::prediction::Resimulating;
 use super::state::*;
+use crate::physics::probe_step;
 
 /// Auto-steps the player over small obstacles (stairs, curbs) when grounded and moving.
 ///
-/// Uses a three-ray approach:
-/// 1. **Foot ray** (forward from ankle): must HIT — obstacle exists
-/// 2. **Step ray** (forward from step height): must MISS — space above obstacle
-/// 3. **Surface ray** (downward at obstacle distance): must HIT with upward normal — step surface
+/// Steps are found with the three rays of `probe_step`. Replayed ticks step
+/// without reporting it again. Events and sounds go out in
+/// `LocomotionDeterminism` order.
+#[allow(clippy::type_complexity)]
 pub fn apply_step_up(
+    mut commands: Commands,
     spatial_query: SpatialQuery,
     mut query: Query<
-        (&mut Transform, &PlayerConfig, &PlayerVelocity),
-        With<Grounded>,
+        (
+            Entity,
+            &mut Transform,
+            &PlayerConfig,
+            &PlayerVelocity,
+            Option<&ReducedProbeRate>,
+            Has<LocomotionObservers>,
+        ),
+        (With<Grounded>, Without<Vaulting>, Without<LocomotionDisabled>),
     >,
-    mut writer: MessageWriter<PlayerAudioMessage>,
+    mut writer: MessageWriter<PlayerMovementEvent>,
+    #[cfg(feature = "audio-events")] mut audio_writer: MessageWriter<PlayerAudioMessage>,
+    #[cfg(feature = "audio-events")] surface_queries: (
+        Query<&SurfaceMaterial>,
+        Query<&ColliderOf>,
+    ),
+    resimulating: Option<Res<Resimulating>>,
+    determinism: Res<LocomotionDeterminism>,
 ) {
-    for (mut transform, config, velocity) in &mut query {
-        let filter = SpatialQueryFilter::default().with_mask(config.world_layer);
+    #[cfg(feature = "audio-events")]
+    let (material_query, collider_query) = surface_queries;
+    let mut events = Vec::new();
+    let mut observers = EntityHashSet::default();
+    #[cfg(feature = "audio-events")]
+    let mut sounds = Vec::new();
+
+    for (player, mut transform, config, velocity, probe_rate, observed) in &mut query {
+        if !probe_due(probe_rate) {
+            continue;
+        }
         let h_vel = Vec3::new(velocity.x, 0.0, velocity.z);
         if h_vel.length_squared() < 0.25 {
             continue;
@@ -30,61 +62,50 @@ pub fn apply_step_up(
             Err(_) => continue,
         };
 
-        let half_height = config.stand_height / 2.0;
-        let center = transform.translation;
-        let probe_dist = config.radius + 0.15;
-
-        // Ray 1: foot height (ankle) — must HIT (obstacle exists)
-        let foot_origin = center + Vec3::Y * (-half_height + 0.05);
-        let foot_hit = spatial_query.cast_ray(
-            foot_origin,
-            forward_dir,
-            probe_dist,
-            true,
-            &filter,
-        );
-        let Some(foot_hit) = foot_hit else {
+        let Some(step) = probe_step(&spatial_query, transform.translation, forward_dir, config)
+        else {
             continue;
         };
-
-        // Ray 2: step height — must MISS (space above obstacle)
-        let step_origin = center + Vec3::Y * (-half_height + config.step_up_height);
-        let step_hit = spatial_query.cast_ray(
-            step_origin,
-            forward_dir,
-            probe_dist,
-            true,
-            &filter,
-        );
-        if step_hit.is_some() {
+        transform.translation.y += step.height;
+        if resimulating.is_some() {
             continue;
         }
 
-        // Ray 3: downward from step height at obstacle distance — must HIT with upward normal
-        let obstacle_point = foot_origin + h_vel.normalize() * foot_hit.distance;
-        let surface_origin = Vec3::new(
-            obstacle_point.x,
-            center.y + (-half_height + config.step_up_height),
-            obstacle_point.z,
-        );
-        let surface_hit = spatial_query.cast_ray(
-            surface_origin,
-            Dir3::NEG_Y,
-            config.step_up_height,
-            true,
-            &filter,
-        );
-        let Some(surface_hit) = surface_hit else {
-            continue;
+        let position = Vec3::new(transform.translation.x, step.point.y, transform.translation.z);
+        let surface = Some(step.entity);
+        let event = PlayerMovementEvent {
+            player,
+            kind: MovementEventKind::SteppedUp { height: step.height },
+            position,
+            normal: step.normal,
+            surface,
         };
-
-        if surface_hit.normal.dot(Vec3::Y) < 0.7 {
-            continue;
+        events.push(event);
+        if observed {
+            observers.insert(player);
         }
 
-        let surface_y = surface_origin.y - surface_hit.distance;
-        transform.translation.y = surface_y + half_height;
+        #[cfg(feature = "audio-events")]
+        sounds.push(PlayerAudioMessage {
+            player,
+            sound: PlayerSound::SteppedUp,
+            position,
+            normal: step.normal,
+            surface,
+            material: surface_material(step.entity, &material_query, &collider_query),
+        });
+    }
+
+    // Observers see the events in the same order as readers
+    determinism.order(&mut events, |event| event.player);
+    for &event in events.iter().filter(|event| observers.contains(&event.player)) {
+        commands.trigger(event);
+    }
+    writer.write_batch(events);
 
-        writer.write(PlayerAudioMessage::SteppedUp);
+    #[cfg(feature = "audio-events")]
+    {
+        determinism.order(&mut sounds, |sound| sound.player);
+        audio_writer.write_batch(sounds);
     }
 }
@@ -0,0 +1,131 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::arbitration::release_ledge;
+use super::buffer::InputBuffer;
+use super::cooldown::{Cooldown, Cooldowns};
+use super::input::JumpPressed;
+use super::state::*;
+use crate::camera::{CameraConfig, CameraPitch, CameraRig, CameraYaw, FreelookYaw, PitchAngle};
+
+/// Moves a player and resets the controller state tied to where it was.
+///
+/// Setting `Transform` directly keeps coyote time, buffers and ledge state
+/// from the old position; this clears them so the player arrives standing
+/// still, with grounding re-detected at the destination.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct TeleportPlayer {
+    pub player: Entity,
+    /// New body center
+    pub position: Vec3,
+    /// View direction to face on arrival (keeps the current view if `None`)
+    pub facing: Option<Dir3>,
+}
+
+/// Applies `TeleportPlayer` at the start of the fixed tick, before grounding
+#[allow(clippy::type_complexity)]
+pub fn apply_teleports(
+    mut commands: Commands,
+    mut reader: MessageReader<TeleportPlayer>,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut Position,
+            &mut PlayerVelocity,
+            &mut LinearVelocity,
+            &PlayerConfig,
+            (&mut CoyoteTime, &mut JumpBuffer, &mut JumpPressed, &mut InputBuffer),
+            (&mut Cooldowns, &mut AirTime, &mut LastSlide),
+            &mut MovementBasis,
+            Option<&CameraRig>,
+        ),
+        With<Player>,
+    >,
+    mut yaw_query: Query<&mut Transform, (With<CameraYaw>, Without<Player>)>,
+    mut pitch_query: Query<
+        (&mut Transform, &mut PitchAngle, &mut FreelookYaw, &CameraConfig),
+        (With<CameraPitch>, Without<CameraYaw>, Without<Player>),
+    >,
+) {
+    for msg in reader.read() {
+        let Ok((
+            mut transform,
+            mut body_position,
+            mut velocity,
+            mut lin_vel,
+            config,
+            (mut coyote, mut jump_buffer, mut jump_pressed, mut input_buffer),
+            (mut cooldowns, mut air_time, mut last_slide),
+            mut basis,
+            rig,
+        )) = player_query.get_mut(msg.player)
+        else {
+            continue;
+        };
+
+        transform.translation = msg.position;
+        body_position.0 = msg.position;
+        velocity.0 = Vec3::ZERO;
+        lin_vel.0 = Vec3::ZERO;
+
+        // Spent coyote time: no ghost jump off the ground we left
+        coyote.timer = config.jump.coyote_time;
+        *jump_buffer = JumpBuffer::default();
+        jump_pressed.0 = false;
+        *input_buffer = InputBuffer::default();
+        // Restart the cooldown so a ledge at the destination isn't grabbed instantly
+        cooldowns.start(Cooldown::LedgeGrab, config.ledge.cooldown);
+        air_time.duration = 0.0;
+        air_time.peak_y = msg.position.y;
+        *last_slide = LastSlide::default();
+
+        commands.entity(msg.player).remove::<(
+            Grounded,
+            GroundNormal,
+            GroundEntity,
+            GroundPoint,
+            JumpCut,
+            Sliding,
+            ForcedSliding,
+            PendingSlide,
+            OnLadder,
+            Vaulting,
+        )>();
+        // Also clears the ledge camera bobs left on the rig
+        release_ledge(&mut commands, msg.player);
+
+        let facing = msg.facing.map(|dir| {
+            let yaw = (-dir.x).atan2(-dir.z);
+            let pitch = dir.y.clamp(-1.0, 1.0).asin();
+            (yaw, pitch)
+        });
+
+        if let Some((yaw, _)) = facing {
+            basis.0 = yaw;
+        }
+        let Some(rig) = rig else {
+            continue;
+        };
+
+        let mut yaws = yaw_query.iter_many_mut(rig.entities());
+        while let Some(mut yaw_transform) = yaws.fetch_next() {
+            // Snap past any camera smoothing
+            yaw_transform.translation = msg.position;
+            if let Some((yaw, _)) = facing {
+                yaw_transform.rotation = Quat::from_rotation_y(yaw);
+            }
+        }
+
+        let Some((_, pitch)) = facing else {
+            continue;
+        };
+        let mut pitches = pitch_query.iter_many_mut(rig.entities());
+        while let Some((mut pitch_transform, mut pitch_angle, mut freelook_yaw, camera_config)) =
+            pitches.fetch_next()
+        {
+            pitch_angle.0 = pitch.clamp(camera_config.min_pitch, camera_config.max_pitch);
+            freelook_yaw.0 = 0.0;
+            pitch_transform.rotation = Quat::from_rotation_x(pitch_angle.0);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::arbitration::AbilityArbiter;
+use super::input::JumpPressed;
+use super::state::*;
+use crate::physics::probe_vault;
+
+/// Starts a vault when a grounded player sprints at a waist-high obstacle and
+/// presses jump, using `probe_vault`.
+///
+/// Runs before the jump so the press is spent on the vault instead.
+#[allow(clippy::type_complexity)]
+pub fn detect_vault(
+    mut commands: Commands,
+    spatial_query: SpatialQuery,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &PlayerConfig,
+            &PlayerVelocity,
+            &mut JumpPressed,
+            &mut JumpBuffer,
+        ),
+        (
+            With<Grounded>,
+            With<Sprinting>,
+            Without<Crouching>,
+            Without<Vaulting>,
+            Without<CrowdController>,
+            Without<LocomotionDisabled>,
+        ),
+    >,
+    mut arbiter: AbilityArbiter,
+) {
+    for (entity, transform, config, velocity, mut jump_pressed, mut jump_buffer) in &mut query {
+        if !jump_pressed.0 && !jump_buffer.buffered {
+            continue;
+        }
+
+        let h_vel = Vec3::new(velocity.x, 0.0, velocity.z);
+        let speed = h_vel.length();
+        if speed < config.vault.min_speed {
+            continue;
+        }
+        let Ok(direction) = Dir3::new(h_vel) else {
+            continue;
+        };
+
+        let Some(vault) = probe_vault(&spatial_query, transform.translation, direction, config)
+        else {
+            continue;
+        };
+
+        if !arbiter.request(&mut commands, entity, Ability::Vault, &config.abilities) {
+            continue;
+        }
+
+        // The press is spent on the vault; don't let it jump as well
+        jump_pressed.0 = false;
+        jump_buffer.buffered = false;
+
+        let radius = config.collider.radius;
+        let half_height = config.collider.stand_height / 2.0;
+        let start_pos = transform.translation;
+        let apex_pos = Vec3::new(
+            vault.surface_point.x,
+            vault.surface_point.y + config.vault.clearance + half_height,
+            vault.surface_point.z,
+        ) - *direction * radius;
+        let end_pos = apex_pos + *direction * (vault.depth + radius * 2.0);
+
+        // Paced by the entry speed so the run carries over the obstacle
+        let path = start_pos.distance(apex_pos) + apex_pos.distance(end_pos);
+        let duration = (path / speed).max(config.vault.min_duration);
+
+        commands.entity(entity).insert(Vaulting {
+            start_pos,
+            apex_pos,
+            end_pos,
+            exit_velocity: h_vel * config.vault.exit_speed_multiplier,
+            wall_normal: vault.wall_normal,
+            elapsed: 0.0,
+            duration,
+        });
+    }
+}
+
+/// Animates the vault: up onto the near edge, then over the top, with time
+/// split by distance so the pace stays even. Hands back `exit_velocity` at
+/// the end and lets gravity take over.
+#[allow(clippy::type_complexity)]
+pub fn animate_vault(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut PlayerVelocity,
+            &mut Vaulting,
+            Option<&TimeDilation>,
+        ),
+        Without<LocomotionDisabled>,
+    >,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut velocity, mut vault, dilation) in &mut query {
+        vault.elapsed += player_dt(time.delta_secs(), dilation);
+        let t = (vault.elapsed / vault.duration).clamp(0.0, 1.0);
+
+        let rise = vault.start_pos.distance(vault.apex_pos);
+        let over = vault.apex_pos.distance(vault.end_pos);
+        let split = rise / (rise + over).max(f32::EPSILON);
+
+        if t < split {
+            // Phase 1: rise toward the near edge, easing out so the body tops
+            // out before it reaches the wall
+            let phase = t / split;
+            let lift = 1.0 - (1.0 - phase) * (1.0 - phase);
+            let mut position = vault.start_pos.lerp(vault.apex_pos, phase);
+            position.y = vault.start_pos.y + (vault.apex_pos.y - vault.start_pos.y) * lift;
+            transform.translation = position;
+        } else {
+            // Phase 2: pass over the top
+            let phase = (t - split) / (1.0 - split).max(f32::EPSILON);
+            transform.translation = vault.apex_pos.lerp(vault.end_pos, phase);
+        }
+
+        // Keep velocity zeroed during animation
+        velocity.0 = Vec3::ZERO;
+
+        // Finished
+        if t >= 1.0 {
+            velocity.0 = vault.exit_velocity;
+            commands.entity(entity).remove::<Vaulting>();
+        }
+    }
+}
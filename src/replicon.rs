@@ -0,0 +1,343 @@
+use std::collections::VecDeque;
+
+use bevy::ecs::entity::MapEntities;
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::camera::{CameraPitch, CameraRig, PitchAngle};
+use crate::player::prediction::{reconcile_predictions, record_prediction_history};
+use crate::player::{
+    IntentFrame, IntentQueue, JumpPressed, LocomotionSet, LocomotionSnapshot, LocomotionState,
+    LocomotionTick, MovementBasis, Player, PlayerVelocity, PredictionHistory, Reconcile,
+    Resimulating, TickedIntent,
+};
+
+/// Replicated pose of a server-simulated player, interpolated on clients
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Default)]
+pub struct LocomotionPose {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    /// View yaw in radians
+    pub yaw: f32,
+    /// View pitch in radians, for head aim on remote models
+    pub pitch: f32,
+}
+
+/// Server: the client whose intent drives this player.
+///
+/// Replicates the player and routes the client's `IntentMessage`s into its
+/// `IntentQueue`.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[require(IntentQueue, LocomotionPose, Replicated)]
+pub struct ControlledBy(pub ClientId);
+
+/// Client: the player this client controls. Its intent is sent to the server
+/// every fixed tick.
+///
+/// Add a full `PlayerBundle` and `PredictionHistory` as well to predict it
+/// locally; otherwise it is interpolated like remote players.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct LocallyControlled;
+
+/// Client → server: intent for one of the sender's players.
+///
+/// `player` is mapped to the server's entity on the way, and intent for a
+/// player the sender doesn't control is dropped.
+#[derive(Message, Clone, Copy, Debug, MapEntities, Serialize, Deserialize)]
+pub struct IntentMessage {
+    #[entities]
+    pub player: Entity,
+    pub intent: TickedIntent,
+}
+
+/// Server → owning client: authoritative state of `player` at the start of `tick`
+#[derive(Message, Clone, Copy, Debug, MapEntities, Serialize, Deserialize)]
+pub struct ServerSnapshot {
+    #[entities]
+    pub player: Entity,
+    pub tick: u32,
+    #[entities]
+    pub state: LocomotionSnapshot,
+}
+
+/// Server → newly authorized client: the server's `LocomotionTick`.
+///
+/// The client sets its own tick ahead of it by the round trip, so its intent
+/// arrives before the server runs the tick it is stamped for and the server's
+/// snapshots line up with the client's `PredictionHistory`.
+#[derive(Message, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct TickSync(pub u32);
+
+/// Ticks a client runs ahead of the server on top of the round trip, to absorb
+/// jitter
+const TICK_SYNC_MARGIN: u32 = 2;
+
+/// Client-side interpolation of a replicated `LocomotionPose` into `Transform`.
+///
+/// Rendered `delay` seconds behind the newest pose so there is usually a
+/// pose on either side to blend between.
+#[derive(Component, Clone, Debug)]
+pub struct LocomotionInterpolation {
+    /// Seconds behind the newest pose
+    pub delay: f32,
+    samples: VecDeque<(f32, LocomotionPose)>,
+}
+
+impl Default for LocomotionInterpolation {
+    fn default() -> Self {
+        Self {
+            delay: 0.1,
+            samples: VecDeque::new(),
+        }
+    }
+}
+
+impl LocomotionInterpolation {
+    /// Pose at `time`, holding the oldest or newest pose outside the buffer
+    fn sample(&self, time: f32) -> Option<LocomotionPose> {
+        let after = self.samples.iter().position(|(received, _)| *received > time);
+        match after {
+            Some(0) => self.samples.front().map(|(_, pose)| *pose),
+            Some(index) => {
+                let (t0, from) = self.samples[index - 1];
+                let (t1, to) = self.samples[index];
+                let t = ((time - t0) / (t1 - t0).max(f32::EPSILON)).clamp(0.0, 1.0);
+                let yaw = Quat::from_rotation_y(from.yaw)
+                    .slerp(Quat::from_rotation_y(to.yaw), t)
+                    .to_euler(EulerRot::YXZ)
+                    .0;
+                Some(LocomotionPose {
+                    position: from.position.lerp(to.position, t),
+                    velocity: from.velocity.lerp(to.velocity, t),
+                    yaw,
+                    pitch: from.pitch.lerp(to.pitch, t),
+                })
+            }
+            None => self.samples.back().map(|(_, pose)| *pose),
+        }
+    }
+}
+
+/// Server: writes each player's pose after physics has moved it
+#[allow(clippy::type_complexity)]
+pub fn update_locomotion_pose(
+    mut player_query: Query<
+        (
+            &Transform,
+            &PlayerVelocity,
+            &MovementBasis,
+            &mut LocomotionPose,
+            Option<&CameraRig>,
+        ),
+        With<Player>,
+    >,
+    pitch_query: Query<&PitchAngle, With<CameraPitch>>,
+) {
+    for (transform, velocity, basis, mut pose, rig) in &mut player_query {
+        let rig = rig.map(CameraRig::entities).unwrap_or_default();
+        let pitch = pitch_query.iter_many(rig).next().map_or(0.0, |pitch| pitch.0);
+        pose.set_if_neq(LocomotionPose {
+            position: transform.translation,
+            velocity: velocity.0,
+            yaw: basis.0,
+            pitch,
+        });
+    }
+}
+
+/// Server: queues received intent on the player it is for, if the sender controls it
+pub fn receive_intent(
+    mut reader: MessageReader<FromClient<IntentMessage>>,
+    mut player_query: Query<(&ControlledBy, &mut IntentQueue)>,
+) {
+    for msg in reader.read() {
+        let Ok((controlled_by, mut queue)) = player_query.get_mut(msg.message.player) else {
+            continue;
+        };
+        if controlled_by.0 == msg.client_id {
+            queue.push(msg.message.intent);
+        }
+    }
+}
+
+/// Server: sends the current `LocomotionTick` to clients as they are authorized
+pub fn send_tick_sync(
+    mut writer: MessageWriter<ToClients<TickSync>>,
+    client_query: Query<Entity, Added<AuthorizedClient>>,
+    tick: Res<LocomotionTick>,
+) {
+    for client in &client_query {
+        writer.write(ToClients {
+            mode: SendMode::Direct(ClientId::Client(client)),
+            message: TickSync(tick.0),
+        });
+    }
+}
+
+/// Client: moves `LocomotionTick` ahead of the server's by the round trip plus
+/// a small margin
+pub fn receive_tick_sync(
+    mut reader: MessageReader<TickSync>,
+    mut tick: ResMut<LocomotionTick>,
+    stats: Res<ClientStats>,
+    fixed: Res<Time<Fixed>>,
+) {
+    let Some(sync) = reader.read().last() else {
+        return;
+    };
+    // The sync is half a round trip old and intent takes another half to arrive
+    let round_trip = (stats.rtt / fixed.timestep().as_secs_f64()).ceil() as u32;
+    tick.0 = sync.0.wrapping_add(round_trip + TICK_SYNC_MARGIN);
+}
+
+/// Server: sends each controlled player's state at the start of this tick to its client
+pub fn send_server_snapshots(world: &mut World) {
+    let tick = world.resource::<LocomotionTick>().0;
+    let mut query = world.query_filtered::<(Entity, &ControlledBy), With<Player>>();
+    let players: Vec<(Entity, ClientId)> =
+        query.iter(world).map(|(player, controlled_by)| (player, controlled_by.0)).collect();
+    for (player, client) in players {
+        let Some(state) = LocomotionSnapshot::capture(world, player) else {
+            continue;
+        };
+        world.write_message(ToClients {
+            mode: SendMode::Direct(client),
+            message: ServerSnapshot { player, tick, state },
+        });
+    }
+}
+
+/// Client: sends this tick's intent for each locally controlled player
+pub fn send_local_intent(world: &mut World) {
+    let tick = world.resource::<LocomotionTick>().0;
+    let mut query = world.query_filtered::<(Entity, Has<Player>), With<LocallyControlled>>();
+    let players: Vec<(Entity, bool)> = query.iter(world).collect();
+    for (player, simulated) in players {
+        let Some(frame) = IntentFrame::capture(world, player) else {
+            continue;
+        };
+        world.write_message(IntentMessage {
+            player,
+            intent: TickedIntent { tick, frame },
+        });
+        if !simulated {
+            // Nothing local consumes the press, so it would repeat every tick
+            if let Some(mut jump_pressed) = world.get_mut::<JumpPressed>(player) {
+                jump_pressed.0 = false;
+            }
+        }
+    }
+}
+
+/// Client: turns server snapshots into `Reconcile` for the predicted player
+/// each one is for
+pub fn receive_server_snapshots(
+    mut reader: MessageReader<ServerSnapshot>,
+    mut reconcile: MessageWriter<Reconcile>,
+    player_query: Query<(), (With<LocallyControlled>, With<PredictionHistory>)>,
+) {
+    for msg in reader.read() {
+        if !player_query.contains(msg.player) {
+            continue;
+        }
+        reconcile.write(Reconcile {
+            player: msg.player,
+            tick: msg.tick,
+            state: msg.state,
+        });
+    }
+}
+
+/// Client: starts interpolating replicated players that aren't simulated here
+#[allow(clippy::type_complexity)]
+pub fn add_locomotion_interpolation(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (Added<LocomotionPose>, Without<Player>, Without<LocomotionInterpolation>),
+    >,
+) {
+    for entity in &query {
+        commands.entity(entity).insert(LocomotionInterpolation::default());
+    }
+}
+
+/// Client: buffers received poses and moves `Transform` along them
+pub fn interpolate_locomotion_pose(
+    time: Res<Time>,
+    mut query: Query<
+        (&mut Transform, Ref<LocomotionPose>, &mut LocomotionInterpolation),
+        Without<Player>,
+    >,
+) {
+    let now = time.elapsed_secs();
+    for (mut transform, pose, mut interpolation) in &mut query {
+        if pose.is_changed() {
+            interpolation.samples.push_back((now, *pose));
+        }
+        let render_time = now - interpolation.delay;
+        // Keep one pose at or before the render time to blend from
+        while interpolation.samples.get(1).is_some_and(|(received, _)| *received <= render_time) {
+            interpolation.samples.pop_front();
+        }
+
+        let Some(pose) = interpolation.sample(render_time) else {
+            continue;
+        };
+        transform.translation = pose.position;
+        transform.rotation = Quat::from_rotation_y(pose.yaw);
+    }
+}
+
+/// Replicates players with bevy_replicon: server-driven movement from relayed
+/// client intent, interpolated remote players, and optional prediction of the
+/// local player.
+///
+/// Add after `RepliconPlugins` on both client and server.
+pub struct LocomotionReplicationPlugin;
+
+impl Plugin for LocomotionReplicationPlugin {
+    fn build(&self, app: &mut App) {
+        app.replicate::<LocomotionPose>()
+            .replicate::<LocomotionState>()
+            .add_mapped_client_message::<IntentMessage>(Channel::Unreliable)
+            .add_mapped_server_message::<ServerSnapshot>(Channel::Unreliable)
+            .add_server_message::<TickSync>(Channel::Ordered)
+            .add_systems(Update, send_tick_sync.run_if(in_state(ServerState::Running)))
+            // Before the fixed loop so this frame's ticks see what arrived
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    receive_intent.run_if(in_state(ServerState::Running)),
+                    (receive_tick_sync, receive_server_snapshots)
+                        .chain()
+                        .run_if(in_state(ClientState::Connected))
+                        .before(reconcile_predictions),
+                )
+                    .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    send_local_intent
+                        .run_if(any_with_component::<LocallyControlled>)
+                        .run_if(not(resource_exists::<Resimulating>)),
+                    send_server_snapshots.run_if(in_state(ServerState::Running)),
+                )
+                    .in_set(LocomotionSet::Input)
+                    .after(record_prediction_history),
+            )
+            .add_systems(
+                FixedLast,
+                update_locomotion_pose.run_if(in_state(ServerState::Running)),
+            )
+            .add_systems(
+                Update,
+                (add_locomotion_interpolation, interpolate_locomotion_pose)
+                    .chain()
+                    .run_if(in_state(ClientState::Connected)),
+            );
+    }
+}
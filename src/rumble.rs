@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+
+use crate::player::{InputDevice, MovementEventKind, PlayerMovementEvent};
+
+/// One rumble pulse
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RumbleEffect {
+    /// Low-frequency motor strength, 0–1
+    pub strong: f32,
+    /// High-frequency motor strength, 0–1
+    pub weak: f32,
+    /// Seconds
+    pub duration: f32,
+}
+
+impl RumbleEffect {
+    pub const fn new(strong: f32, weak: f32, duration: f32) -> Self {
+        Self {
+            strong,
+            weak,
+            duration,
+        }
+    }
+
+    fn request(self, gamepad: Entity, scale: f32) -> GamepadRumbleRequest {
+        GamepadRumbleRequest::Add {
+            gamepad,
+            duration: Duration::from_secs_f32(self.duration.max(0.0)),
+            intensity: GamepadRumbleIntensity {
+                strong_motor: (self.strong * scale).clamp(0.0, 1.0),
+                weak_motor: (self.weak * scale).clamp(0.0, 1.0),
+            },
+        }
+    }
+}
+
+/// Rumble `LocomotionRumblePlugin` plays for each movement event.
+///
+/// `None` turns rumble off for that event. Players rumble the gamepad of their
+/// `InputDevice`; `InputDevice::Any` rumbles every connected gamepad.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct LocomotionRumble {
+    /// Scaled by impact speed over `full_impact_speed`
+    pub landed: Option<RumbleEffect>,
+    /// Impact speed (m/s) that gives a landing its full strength
+    pub full_impact_speed: f32,
+    /// Crouch slides and forced slides
+    pub slide: Option<RumbleEffect>,
+    pub ledge_grab: Option<RumbleEffect>,
+    pub ledge_climb: Option<RumbleEffect>,
+    pub stepped_up: Option<RumbleEffect>,
+    /// Multiplies every effect, for an accessibility slider
+    pub strength: f32,
+}
+
+impl Default for LocomotionRumble {
+    fn default() -> Self {
+        Self {
+            landed: Some(RumbleEffect::new(0.8, 0.4, 0.15)),
+            full_impact_speed: 15.0,
+            slide: Some(RumbleEffect::new(0.15, 0.35, 0.4)),
+            ledge_grab: Some(RumbleEffect::new(0.6, 0.2, 0.1)),
+            ledge_climb: Some(RumbleEffect::new(0.2, 0.1, 0.2)),
+            stepped_up: None,
+            strength: 1.0,
+        }
+    }
+}
+
+impl LocomotionRumble {
+    /// Effect and its scale for `kind`, if it rumbles
+    pub fn effect(&self, kind: MovementEventKind) -> Option<(RumbleEffect, f32)> {
+        let (effect, scale) = match kind {
+            MovementEventKind::Landed { impact_speed, .. } => {
+                let scale = (impact_speed / self.full_impact_speed.max(0.01)).clamp(0.2, 1.0);
+                (self.landed, scale)
+            }
+            MovementEventKind::SlideStarted { .. }
+            | MovementEventKind::ForcedSlideStarted { .. } => (self.slide, 1.0),
+            MovementEventKind::LedgeGrabbed => (self.ledge_grab, 1.0),
+            MovementEventKind::LedgeClimbStarted => (self.ledge_climb, 1.0),
+            MovementEventKind::SteppedUp { .. } => (self.stepped_up, 1.0),
+            _ => (None, 1.0),
+        };
+        effect.map(|effect| (effect, scale))
+    }
+}
+
+/// Sends a `GamepadRumbleRequest` for each `PlayerMovementEvent` with an effect
+pub fn rumble_on_movement_events(
+    mut reader: MessageReader<PlayerMovementEvent>,
+    mut writer: MessageWriter<GamepadRumbleRequest>,
+    rumble: Res<LocomotionRumble>,
+    device_query: Query<&InputDevice>,
+    gamepads: Query<Entity, With<Gamepad>>,
+) {
+    for event in reader.read() {
+        let Some((effect, scale)) = rumble.effect(event.kind) else {
+            continue;
+        };
+        let scale = scale * rumble.strength;
+        match device_query.get(event.player) {
+            Ok(InputDevice::Gamepad(gamepad)) => {
+                writer.write(effect.request(*gamepad, scale));
+            }
+            Ok(InputDevice::Any) => {
+                writer.write_batch(gamepads.iter().map(|gamepad| effect.request(gamepad, scale)));
+            }
+            // Keyboard players and players driven by `LocomotionIntent`
+            _ => {}
+        }
+    }
+}
+
+/// Rumbles players' gamepads on landings, slides and ledge grabs
+pub struct LocomotionRumblePlugin;
+
+impl Plugin for LocomotionRumblePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LocomotionRumble>()
+            .add_systems(Update, rumble_on_movement_events);
+    }
+}
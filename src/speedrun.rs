@@ -0,0 +1,253 @@
+use avian3d::prelude::*;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::player::{
+    LocomotionSet, MovementBasis, Player, PlayerConfig, TransformFrame, TransformTrack,
+};
+
+/// Start volume of course `.0`: the timer arms while inside and runs once the
+/// player leaves it.
+///
+/// Course gates should use `Sensor` colliders on `GameLayer::Trigger` so the
+/// player can overlap them.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CourseStart(pub u32);
+
+/// Finish volume of course `.0`
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CourseFinish(pub u32);
+
+/// Best finish of a course and the pose track of that run
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CourseRecord {
+    /// Seconds
+    pub time: f32,
+    pub track: TransformTrack,
+}
+
+/// Times a player's course runs and keeps the best one per course (opt-in:
+/// insert on a player).
+#[derive(Component, Clone, Debug)]
+pub struct CourseTimer {
+    /// Course being run, if any
+    pub course: Option<u32>,
+    /// Seconds since leaving the start volume
+    pub elapsed: f32,
+    /// Time of the last finished run
+    pub last_time: Option<f32>,
+    /// Best run per course; load saved records here
+    pub records: HashMap<u32, CourseRecord>,
+    /// Spawn a ghost of the best run whenever a run starts
+    pub ghost: bool,
+    /// Whether the run has left the start volume
+    started: bool,
+    recording: TransformTrack,
+}
+
+impl Default for CourseTimer {
+    fn default() -> Self {
+        Self {
+            course: None,
+            elapsed: 0.0,
+            last_time: None,
+            records: HashMap::default(),
+            ghost: true,
+            started: false,
+            recording: TransformTrack::default(),
+        }
+    }
+}
+
+impl CourseTimer {
+    /// Whether a run is under way
+    pub fn running(&self) -> bool {
+        self.course.is_some() && self.started
+    }
+
+    /// Best time for `course`
+    pub fn best(&self, course: u32) -> Option<f32> {
+        self.records.get(&course).map(|record| record.time)
+    }
+
+    /// Abandons the current run
+    pub fn cancel(&mut self) {
+        self.course = None;
+        self.started = false;
+        self.recording.frames.clear();
+    }
+}
+
+/// A player left the start volume of `course`
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct CourseStarted {
+    pub player: Entity,
+    pub course: u32,
+}
+
+/// A player reached the finish of `course` in `time` seconds
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct CourseFinished {
+    pub player: Entity,
+    pub course: u32,
+    pub time: f32,
+    /// Beat the previous best, or the first finish
+    pub personal_best: bool,
+}
+
+/// Translucent marker replaying a recorded run, one frame per fixed tick.
+///
+/// Despawned when the track ends or its player starts another run.
+#[derive(Component, Clone, Debug)]
+pub struct CourseGhost {
+    /// Player whose run this is
+    pub player: Entity,
+    pub track: TransformTrack,
+    /// Index of the next frame to show
+    pub cursor: usize,
+}
+
+/// Ghost capsule look
+#[derive(Resource, Clone, Debug)]
+pub struct CourseGhostStyle {
+    pub color: Color,
+}
+
+impl Default for CourseGhostStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::srgba(0.6, 0.8, 1.0, 0.35),
+        }
+    }
+}
+
+/// Starts, times and finishes runs from the gates the player overlaps, and
+/// records the player's pose while running.
+pub fn update_course_timers(
+    mut commands: Commands,
+    mut player_query: Query<
+        (Entity, &Transform, &PlayerConfig, &MovementBasis, &mut CourseTimer),
+        With<Player>,
+    >,
+    start_query: Query<&CourseStart>,
+    finish_query: Query<&CourseFinish>,
+    ghost_query: Query<(Entity, &CourseGhost)>,
+    spatial_query: SpatialQuery,
+    mut started_writer: MessageWriter<CourseStarted>,
+    mut finished_writer: MessageWriter<CourseFinished>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    style: Res<CourseGhostStyle>,
+    time: Res<Time<Fixed>>,
+) {
+    for (player, transform, config, basis, mut timer) in &mut player_query {
+        let shape = config.collider.capsule(false);
+        let filter = SpatialQueryFilter::default().with_mask(config.collider.collision_mask);
+        let overlaps = spatial_query.shape_intersections(
+            &shape,
+            transform.translation,
+            Quat::IDENTITY,
+            &filter,
+        );
+        let start = overlaps.iter().find_map(|&entity| start_query.get(entity).ok());
+        let finish = overlaps.iter().find_map(|&entity| finish_query.get(entity).ok());
+
+        // Standing in a start volume re-arms the timer for that course
+        if let Some(&CourseStart(course)) = start {
+            timer.course = Some(course);
+            timer.elapsed = 0.0;
+            timer.started = false;
+            timer.recording.frames.clear();
+            continue;
+        }
+        let Some(course) = timer.course else {
+            continue;
+        };
+
+        if !timer.started {
+            timer.started = true;
+            timer.recording.tick_rate = 1.0 / time.timestep().as_secs_f64();
+            started_writer.write(CourseStarted { player, course });
+
+            for (ghost, _) in ghost_query.iter().filter(|(_, ghost)| ghost.player == player) {
+                commands.entity(ghost).despawn();
+            }
+            if let Some(record) = timer.records.get(&course).filter(|_| timer.ghost) {
+                commands.spawn((
+                    CourseGhost {
+                        player,
+                        track: record.track.clone(),
+                        cursor: 0,
+                    },
+                    Mesh3d(meshes.add(Capsule3d::new(
+                        config.collider.radius,
+                        config.collider.stand_height - config.collider.radius * 2.0,
+                    ))),
+                    MeshMaterial3d(materials.add(StandardMaterial {
+                        base_color: style.color,
+                        alpha_mode: AlphaMode::Blend,
+                        unlit: true,
+                        ..default()
+                    })),
+                    Transform::from_translation(transform.translation),
+                ));
+            }
+        }
+
+        timer.elapsed += time.delta_secs();
+        timer.recording.frames.push(TransformFrame {
+            translation: transform.translation,
+            yaw: basis.0,
+        });
+
+        if finish.is_some_and(|&CourseFinish(finish)| finish == course) {
+            let time = timer.elapsed;
+            let personal_best = timer.best(course).is_none_or(|best| time < best);
+            if personal_best {
+                let track = std::mem::take(&mut timer.recording);
+                timer.records.insert(course, CourseRecord { time, track });
+            }
+            timer.last_time = Some(time);
+            timer.cancel();
+            finished_writer.write(CourseFinished {
+                player,
+                course,
+                time,
+                personal_best,
+            });
+        }
+    }
+}
+
+/// Moves each `CourseGhost` to its next recorded pose
+pub fn play_course_ghosts(
+    mut commands: Commands,
+    mut ghost_query: Query<(Entity, &mut CourseGhost, &mut Transform)>,
+) {
+    for (entity, mut ghost, mut transform) in &mut ghost_query {
+        let Some(&frame) = ghost.track.frames.get(ghost.cursor) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        ghost.cursor += 1;
+        transform.translation = frame.translation;
+        transform.rotation = Quat::from_rotation_y(frame.yaw);
+    }
+}
+
+/// Course gates, run timing, personal bests and best-run ghosts
+pub struct SpeedrunPlugin;
+
+impl Plugin for SpeedrunPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CourseGhostStyle>()
+            .add_message::<CourseStarted>()
+            .add_message::<CourseFinished>()
+            .add_systems(
+                FixedUpdate,
+                (update_course_timers, play_course_ghosts)
+                    .after(LocomotionSet::ApplyVelocity),
+            );
+    }
+}
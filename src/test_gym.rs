@@ -0,0 +1,654 @@
+use avian3d::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::camera::FpsCamera;
+use crate::physics::GameLayer;
+use crate::player::{ForceSlide, Ladder, LedgeGrabbable, PlayerConfig, SurfaceMaterial};
+#[cfg(feature = "speedrun")]
+use crate::speedrun::{CourseFinish, CourseStart};
+
+/// Marker on every entity of the test gym, to despawn the course
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct TestGym;
+
+/// UI label pinned to a world position in the test gym
+#[derive(Component, Clone, Copy, Debug)]
+pub struct GymLabel {
+    pub world_pos: Vec3,
+}
+
+/// Course id of the jump course's `CourseStart` and `CourseFinish` gates
+#[cfg(feature = "speedrun")]
+pub const JUMP_COURSE: u32 = 0;
+
+/// Labels farther than this from the camera are hidden
+const LABEL_RANGE: f32 = 50.0;
+
+/// Evenly spaced values from `min` to `max` inclusive
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CalibrationRange {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+impl CalibrationRange {
+    pub const fn new(min: f32, max: f32, step: f32) -> Self {
+        Self { min, max, step }
+    }
+
+    /// `min`, `min + step`, … up to `max`; just `min` for an empty range
+    pub fn values(self) -> impl Iterator<Item = f32> {
+        let count = if self.step > 0.0 && self.max > self.min {
+            // Small epsilon so `max` survives float error
+            ((self.max - self.min) / self.step + 1e-3) as usize + 1
+        } else {
+            1
+        };
+        (0..count).map(move |i| self.min + i as f32 * self.step)
+    }
+}
+
+/// Descriptor for a generated calibration course, spawned with
+/// `spawn_calibration_course`.
+///
+/// Each section is a labeled row along +X from `origin`; rows step toward -Z.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalibrationCourse {
+    /// Start of the first row
+    pub origin: Vec3,
+    /// Uphill ramp angles in degrees, facing +Z
+    pub ramps: Option<CalibrationRange>,
+    /// Gaps between jump platforms in meters
+    pub gaps: Option<CalibrationRange>,
+    /// Wall heights in meters
+    pub walls: Option<CalibrationRange>,
+    /// Make walls `LedgeGrabbable`
+    pub grabbable_walls: bool,
+}
+
+impl Default for CalibrationCourse {
+    fn default() -> Self {
+        Self {
+            origin: Vec3::ZERO,
+            ramps: None,
+            gaps: None,
+            walls: None,
+            grabbable_walls: true,
+        }
+    }
+}
+
+impl CalibrationCourse {
+    /// Sections bracketing the limits of `config` under `gravity` (m/s²):
+    /// ramps around `max_slope_angle`, gaps up to a bit past the sprint jump
+    /// distance, and walls from `step_up_height` to the highest reachable ledge.
+    pub fn for_config(config: &PlayerConfig, gravity: f32) -> Self {
+        let gravity = gravity.max(0.01);
+        let jump = config.jump.velocity;
+        let apex = jump * jump / (2.0 * gravity);
+        let sprint_jump = config.movement.sprint_speed * 2.0 * jump / gravity;
+        let slope = config.movement.max_slope_angle;
+        let step_up = config.movement.step_up_height;
+        Self::default()
+            .with_ramps((slope - 10.0).max(2.5), (slope + 10.0).min(85.0), 2.5)
+            .with_gaps(1.0, (sprint_jump * 1.25).ceil(), 1.0)
+            .with_walls((step_up - 0.25).max(0.05), config.collider.stand_height + apex, 0.25)
+    }
+
+    pub fn with_origin(mut self, origin: Vec3) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn with_ramps(mut self, min_degrees: f32, max_degrees: f32, step: f32) -> Self {
+        self.ramps = Some(CalibrationRange::new(min_degrees, max_degrees, step));
+        self
+    }
+
+    pub fn with_gaps(mut self, min: f32, max: f32, step: f32) -> Self {
+        self.gaps = Some(CalibrationRange::new(min, max, step));
+        self
+    }
+
+    pub fn with_walls(mut self, min_height: f32, max_height: f32, step: f32) -> Self {
+        self.walls = Some(CalibrationRange::new(min_height, max_height, step));
+        self
+    }
+
+    pub fn with_grabbable_walls(mut self, grabbable: bool) -> Self {
+        self.grabbable_walls = grabbable;
+        self
+    }
+}
+
+/// Spawns the standard calibration course at startup: slopes, ledges,
+/// ladders, a jump course, obstacles, height jumps, crouch tunnels, slide
+/// ramps and forced slides, each row labeled, plus lighting.
+///
+/// Players and cameras are left to the app.
+pub struct TestGymPlugin;
+
+impl Plugin for TestGymPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_test_gym)
+            .add_systems(Update, update_gym_labels);
+    }
+}
+
+/// Projects `GymLabel` positions into the `FpsCamera`'s viewport.
+///
+/// With several cameras (split-screen) the labels follow the highest-order
+/// one, which is the camera Bevy draws untargeted UI on.
+pub fn update_gym_labels(
+    camera_query: Query<(&Camera, &GlobalTransform), With<FpsCamera>>,
+    mut label_query: Query<(&mut Node, &mut Visibility, &GymLabel)>,
+) {
+    let Some((camera, camera_gt)) = camera_query.iter().max_by_key(|(camera, _)| camera.order)
+    else {
+        return;
+    };
+
+    for (mut node, mut visibility, label) in &mut label_query {
+        if camera_gt.translation().distance(label.world_pos) > LABEL_RANGE {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        match camera.world_to_viewport(camera_gt, label.world_pos) {
+            Ok(viewport) => {
+                *visibility = Visibility::Inherited;
+                node.left = Val::Px(viewport.x - 30.0);
+                node.top = Val::Px(viewport.y - 12.0);
+            }
+            Err(_) => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+// ── Layout ──────────────────────────────────────────────────────────
+//
+// Each section is a row along Z, with items expanding in +X from X = 5.
+//
+//   Z =  48  SLOPES        (ramps face +Z uphill, extend to ~60)
+//   Z =  38  LEDGE GRAB    (walls)
+//   Z =  30  LADDERS       (walls + sensor volumes)
+//   Z =  20  JUMPS         (platforms with gaps)
+//   Z =  10  OBSTACLES     (step-over walls)
+//   Z =  -8  HEIGHT JUMPS  (elevation pairs)
+//   Z = -18  CROUCH        (tunnels extend +Z to ~-12)
+//   Z = -30  SLIDES        (downhill ramps, extend ±8)
+//   Z = -50  FORCED SLIDES (ramps face +Z uphill, extend to ~-38)
+
+/// Spawns the test gym geometry, labels and lighting
+pub fn spawn_test_gym(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let checker = images.add(checker_image());
+    let ground_mat = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.35, 0.55, 0.35),
+        base_color_texture: Some(checker),
+        perceptual_roughness: 0.9,
+        ..default()
+    });
+    let palette = Palette::new(&mut materials);
+    let mut gym = GymBuilder {
+        commands: &mut commands,
+        meshes: &mut meshes,
+        materials: &mut materials,
+    };
+
+    // The checker floor is grass; everything else is untagged stone
+    gym.commands.spawn((
+        TestGym,
+        Mesh3d(gym.meshes.add(Plane3d::default().mesh().size(200.0, 200.0))),
+        MeshMaterial3d(ground_mat),
+        Transform::default(),
+        RigidBody::Static,
+        Collider::half_space(Vec3::Y),
+        CollisionLayers::new(GameLayer::World, [GameLayer::Player]),
+        SurfaceMaterial("grass"),
+    ));
+
+    gym.slopes(48.0);
+    gym.ledges(&palette, 38.0);
+    gym.ladders(&palette, 30.0);
+    gym.jumps(&palette, 20.0);
+    gym.obstacles(&palette, 10.0);
+    gym.height_jumps(&palette, -8.0);
+    gym.tunnels(&palette, -18.0);
+    gym.slides(-30.0);
+    gym.forced_slides(-50.0);
+
+    commands.spawn((
+        TestGym,
+        DirectionalLight {
+            illuminance: 14000.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_rotation(Quat::from_euler(EulerRot::XYZ, -0.7, 0.5, 0.0)),
+    ));
+    commands.spawn((
+        TestGym,
+        AmbientLight {
+            color: Color::srgb(0.6, 0.7, 0.9),
+            brightness: 350.0,
+            affects_lightmapped_meshes: true,
+        },
+    ));
+}
+
+/// Spawns the sections of `course`, tagged `TestGym` and labeled like the
+/// test gym. Labels need `TestGymPlugin` to follow the camera.
+pub fn spawn_calibration_course(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    course: &CalibrationCourse,
+) {
+    let palette = Palette::new(materials);
+    let mut gym = GymBuilder {
+        commands,
+        meshes,
+        materials,
+    };
+
+    let mut origin = course.origin;
+    if let Some(angles) = course.ramps {
+        gym.ramp_gallery(origin, angles);
+        origin.z -= 12.0;
+    }
+    if let Some(gaps) = course.gaps {
+        gym.gap_ladder(&palette, origin, gaps);
+        origin.z -= 12.0;
+    }
+    if let Some(heights) = course.walls {
+        gym.wall_heights(&palette, origin, heights, course.grabbable_walls);
+    }
+}
+
+/// Shared materials of the course
+struct Palette {
+    stone_a: Handle<StandardMaterial>,
+    stone_b: Handle<StandardMaterial>,
+    accent: Handle<StandardMaterial>,
+    ceiling: Handle<StandardMaterial>,
+}
+
+impl Palette {
+    fn new(materials: &mut Assets<StandardMaterial>) -> Self {
+        Self {
+            stone_a: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.38, 0.36, 0.40),
+                perceptual_roughness: 0.85,
+                ..default()
+            }),
+            stone_b: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.52, 0.50, 0.48),
+                perceptual_roughness: 0.8,
+                ..default()
+            }),
+            accent: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.2, 0.4, 0.6),
+                perceptual_roughness: 0.5,
+                metallic: 0.3,
+                ..default()
+            }),
+            ceiling: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.4, 0.3, 0.3),
+                perceptual_roughness: 0.9,
+                ..default()
+            }),
+        }
+    }
+
+    /// Alternating stone so neighboring pieces stand apart
+    fn stone(&self, i: usize) -> Handle<StandardMaterial> {
+        if i % 2 == 0 {
+            self.stone_a.clone()
+        } else {
+            self.stone_b.clone()
+        }
+    }
+}
+
+struct GymBuilder<'a, 'w, 's> {
+    commands: &'a mut Commands<'w, 's>,
+    meshes: &'a mut Assets<Mesh>,
+    materials: &'a mut Assets<StandardMaterial>,
+}
+
+impl GymBuilder<'_, '_, '_> {
+    /// Ramps from 10° to 60°, facing +Z uphill
+    fn slopes(&mut self, z: f32) {
+        let angles = [10.0, 15.0, 20.0, 25.0, 30.0, 35.0, 40.0, 45.0, 50.0, 60.0];
+        for (i, deg) in angles.into_iter().enumerate() {
+            let x = 5.0 + i as f32 * 7.0;
+            let rad = f32::to_radians(deg);
+            let length = 12.0;
+            let rise = length / 2.0 * rad.sin();
+            let material = self.colored(ramp_color(deg), 0.7);
+            let position = Vec3::new(x, rise, z + length / 2.0);
+            self.ramp(material, Vec3::new(5.0, 0.25, length), position, rad);
+            self.label(&format!("{deg}°"), Vec3::new(x, 1.5, z));
+        }
+        self.label("SLOPES", Vec3::new(0.0, 2.5, z - 2.0));
+    }
+
+    /// Grabbable walls of increasing height
+    fn ledges(&mut self, palette: &Palette, z: f32) {
+        for (i, height) in [1.5, 2.0, 2.5, 3.0, 3.5, 4.0].into_iter().enumerate() {
+            let x = 5.0 + i as f32 * 5.0;
+            self.block(palette.stone(i), Vec3::new(3.0, height, 1.0), Vec3::new(x, height / 2.0, z))
+                .insert(LedgeGrabbable);
+            self.label(&format!("{height}m"), Vec3::new(x, height + 0.5, z));
+        }
+        self.label("LEDGE GRAB", Vec3::new(0.0, 5.0, z - 2.0));
+    }
+
+    /// Walls with a ladder volume in front and a platform on top
+    fn ladders(&mut self, palette: &Palette, z: f32) {
+        let ladder_mat = self.colored(Color::srgb(0.55, 0.45, 0.30), 0.9);
+        for (i, height) in [4.0, 6.0, 8.0].into_iter().enumerate() {
+            let x = 5.0 + i as f32 * 6.0;
+            let wall = Vec3::new(3.0, height, 0.4);
+            self.block(palette.stone_a.clone(), wall, Vec3::new(x, height / 2.0, z));
+
+            let size = Vec3::new(1.0, height, 0.3);
+            self.commands.spawn((
+                TestGym,
+                Mesh3d(self.meshes.add(Cuboid::from_size(size))),
+                MeshMaterial3d(ladder_mat.clone()),
+                Transform::from_xyz(x, height / 2.0, z - 0.35),
+                RigidBody::Static,
+                Collider::cuboid(size.x, size.y, size.z),
+                CollisionLayers::new(GameLayer::Trigger, [GameLayer::Player]),
+                Sensor,
+                Ladder,
+            ));
+
+            self.block(
+                palette.stone_b.clone(),
+                Vec3::new(3.0, 0.3, 2.0),
+                Vec3::new(x, height + 0.15, z + 1.0),
+            );
+            self.label(&format!("{height}m"), Vec3::new(x, height + 1.0, z - 1.5));
+        }
+        self.label("LADDERS", Vec3::new(0.0, 9.0, z - 2.0));
+    }
+
+    /// Platforms with gaps from 1 m to 8 m
+    fn jumps(&mut self, palette: &Palette, z: f32) {
+        let size = Vec3::new(3.0, 0.6, 3.0);
+        let y = 0.3;
+        let mut x = 5.0;
+        for (i, gap) in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].into_iter().enumerate() {
+            self.block(palette.stone(i), size, Vec3::new(x, y, z));
+            self.label(&format!("{gap}m gap"), Vec3::new(x + size.x / 2.0 + gap / 2.0, 1.5, z));
+            x += size.x + gap;
+        }
+        self.block(palette.accent.clone(), size, Vec3::new(x, y, z));
+        self.label("JUMPS", Vec3::new(0.0, 2.5, z - 2.0));
+
+        // Timed from the first platform to the last
+        #[cfg(feature = "speedrun")]
+        {
+            let gate = Vec3::new(size.x, 2.0, size.z);
+            self.gate(gate, Vec3::new(5.0, y + 1.3, z)).insert(CourseStart(JUMP_COURSE));
+            self.gate(gate, Vec3::new(x, y + 1.3, z)).insert(CourseFinish(JUMP_COURSE));
+        }
+    }
+
+    /// Walls to step over, vault or climb
+    fn obstacles(&mut self, palette: &Palette, z: f32) {
+        let heights = [0.3, 0.5, 0.7, 1.0, 1.3, 1.5, 1.8, 2.0, 2.5];
+        for (i, height) in heights.into_iter().enumerate() {
+            let x = 5.0 + i as f32 * 4.0;
+            let wall = Vec3::new(2.0, height, 0.4);
+            self.block(palette.stone(i), wall, Vec3::new(x, height / 2.0, z));
+            self.label(&format!("{height}m"), Vec3::new(x, height + 0.4, z));
+        }
+        self.label("OBSTACLES", Vec3::new(0.0, 3.5, z - 2.0));
+    }
+
+    /// Pairs of platforms 3 m apart at different elevations
+    fn height_jumps(&mut self, palette: &Palette, z: f32) {
+        let pairs = [(0.0, 1.0), (0.0, 2.0), (0.0, -1.0), (0.0, -2.0), (1.0, 2.0), (2.0, 1.0)];
+        let size = Vec3::new(2.5, 0.5, 2.5);
+        let gap = 3.0;
+        let mut x = 5.0;
+        for (i, (from, to)) in pairs.into_iter().enumerate() {
+            self.block(palette.stone(i), size, Vec3::new(x, from + 0.25, z));
+            self.block(palette.accent.clone(), size, Vec3::new(x + size.x + gap, to + 0.25, z));
+
+            let diff: f32 = to - from;
+            let sign = if diff >= 0.0 { "+" } else { "" };
+            let label_pos = Vec3::new(x + (size.x + gap) / 2.0, f32::max(from, to) + 1.5, z);
+            self.label(&format!("{sign}{diff}m"), label_pos);
+            x += size.x * 2.0 + gap + 3.0;
+        }
+        self.label("HEIGHT JUMPS", Vec3::new(0.0, 4.0, z - 2.0));
+    }
+
+    /// Corridors with shrinking ceiling clearance
+    fn tunnels(&mut self, palette: &Palette, z: f32) {
+        let width = 3.0;
+        let depth = 6.0;
+        let floor = 0.3;
+        for (i, clearance) in [1.8, 1.5, 1.2, 1.0, 0.8].into_iter().enumerate() {
+            let x = 5.0 + i as f32 * 5.0;
+            let floor_size = Vec3::new(width, floor, depth);
+            self.block(palette.stone_a.clone(), floor_size, Vec3::new(x, floor / 2.0, z));
+
+            let ceiling_y = floor + clearance + 0.15;
+            let ceiling_size = Vec3::new(width, 0.3, depth);
+            self.block(palette.ceiling.clone(), ceiling_size, Vec3::new(x, ceiling_y, z));
+
+            let wall_height = clearance + 0.5;
+            for side in [-1.0, 1.0] {
+                let wall_x = x + side * (width / 2.0 + 0.1);
+                self.block(
+                    palette.stone_b.clone(),
+                    Vec3::new(0.2, wall_height, depth),
+                    Vec3::new(wall_x, wall_height / 2.0 + floor, z),
+                );
+            }
+            self.label(&format!("{clearance}m clear"), Vec3::new(x, ceiling_y + 0.5, z));
+        }
+        self.label("CROUCH", Vec3::new(0.0, 3.0, z - 2.0));
+    }
+
+    /// Downhill ramps for sprint-slides
+    fn slides(&mut self, z: f32) {
+        for (i, deg) in [5.0, 10.0, 15.0, 20.0, 30.0].into_iter().enumerate() {
+            let x = 5.0 + i as f32 * 8.0;
+            let material = self.colored(ramp_color(deg), 0.6);
+            let rad = f32::to_radians(deg);
+            self.ramp(material, Vec3::new(4.0, 0.25, 16.0), Vec3::new(x, -0.5, z), -rad);
+            self.label(&format!("-{deg}° slide"), Vec3::new(x, 1.5, z + 9.0));
+        }
+        self.label("SLIDES", Vec3::new(0.0, 3.0, z - 2.0));
+    }
+
+    /// `ForceSlide` ramps that push the player downhill
+    fn forced_slides(&mut self, z: f32) {
+        for (i, deg) in [15.0, 25.0, 35.0, 45.0].into_iter().enumerate() {
+            let x = 5.0 + i as f32 * 8.0;
+            let rad = f32::to_radians(deg);
+            let length = 12.0;
+            let rise = length / 2.0 * rad.sin();
+            let material = self.colored(Color::srgb(0.6, 0.3, 0.3), 0.6);
+            let position = Vec3::new(x, rise, z + length / 2.0);
+            self.ramp(material, Vec3::new(5.0, 0.25, length), position, rad)
+                .insert(ForceSlide);
+            self.label(&format!("{deg}° slide"), Vec3::new(x, 1.5, z));
+        }
+        self.label("FORCED SLIDES", Vec3::new(0.0, 4.0, z - 2.0));
+    }
+
+    /// Ramps at each angle in `angles` (degrees), facing +Z uphill
+    fn ramp_gallery(&mut self, origin: Vec3, angles: CalibrationRange) {
+        for (i, deg) in angles.values().enumerate() {
+            let x = origin.x + i as f32 * 7.0;
+            let rad = f32::to_radians(deg);
+            let length = 12.0;
+            let rise = length / 2.0 * rad.sin();
+            let material = self.colored(ramp_color(deg), 0.7);
+            let position = Vec3::new(x, origin.y + rise, origin.z + length / 2.0);
+            self.ramp(material, Vec3::new(5.0, 0.25, length), position, rad);
+            self.label(&format!("{deg:.1}°"), Vec3::new(x, origin.y + 1.5, origin.z));
+        }
+        self.label("RAMPS", origin + Vec3::new(-5.0, 2.5, -2.0));
+    }
+
+    /// Platforms separated by each gap in `gaps` (meters)
+    fn gap_ladder(&mut self, palette: &Palette, origin: Vec3, gaps: CalibrationRange) {
+        let size = Vec3::new(3.0, 0.6, 3.0);
+        let y = origin.y + size.y / 2.0;
+        let mut x = origin.x;
+        for (i, gap) in gaps.values().enumerate() {
+            self.block(palette.stone(i), size, Vec3::new(x, y, origin.z));
+            let label_pos = Vec3::new(x + size.x / 2.0 + gap / 2.0, y + 1.2, origin.z);
+            self.label(&format!("{gap:.2}m gap"), label_pos);
+            x += size.x + gap;
+        }
+        self.block(palette.accent.clone(), size, Vec3::new(x, y, origin.z));
+        self.label("GAPS", origin + Vec3::new(-5.0, 2.5, -2.0));
+    }
+
+    /// Walls of each height in `heights` (meters)
+    fn wall_heights(
+        &mut self,
+        palette: &Palette,
+        origin: Vec3,
+        heights: CalibrationRange,
+        grabbable: bool,
+    ) {
+        let mut top = 0.0_f32;
+        for (i, height) in heights.values().enumerate() {
+            let x = origin.x + i as f32 * 4.0;
+            let position = Vec3::new(x, origin.y + height / 2.0, origin.z);
+            let mut wall = self.block(palette.stone(i), Vec3::new(2.0, height, 1.0), position);
+            if grabbable {
+                wall.insert(LedgeGrabbable);
+            }
+            self.label(&format!("{height:.2}m"), Vec3::new(x, origin.y + height + 0.4, origin.z));
+            top = top.max(height);
+        }
+        self.label("WALLS", origin + Vec3::new(-5.0, top + 1.0, -2.0));
+    }
+
+    fn colored(&mut self, color: Color, roughness: f32) -> Handle<StandardMaterial> {
+        self.materials.add(StandardMaterial {
+            base_color: color,
+            perceptual_roughness: roughness,
+            ..default()
+        })
+    }
+
+    /// Static world box
+    fn block(
+        &mut self,
+        material: Handle<StandardMaterial>,
+        size: Vec3,
+        position: Vec3,
+    ) -> EntityCommands<'_> {
+        self.ramp(material, size, position, 0.0)
+    }
+
+    /// Static world box tilted `angle` radians around X
+    fn ramp(
+        &mut self,
+        material: Handle<StandardMaterial>,
+        size: Vec3,
+        position: Vec3,
+        angle: f32,
+    ) -> EntityCommands<'_> {
+        self.commands.spawn((
+            TestGym,
+            Mesh3d(self.meshes.add(Cuboid::from_size(size))),
+            MeshMaterial3d(material),
+            Transform::from_translation(position).with_rotation(Quat::from_rotation_x(angle)),
+            RigidBody::Static,
+            Collider::cuboid(size.x, size.y, size.z),
+            CollisionLayers::new(GameLayer::World, [GameLayer::Player]),
+        ))
+    }
+
+    /// Invisible trigger volume
+    #[cfg(feature = "speedrun")]
+    fn gate(&mut self, size: Vec3, position: Vec3) -> EntityCommands<'_> {
+        self.commands.spawn((
+            TestGym,
+            Transform::from_translation(position),
+            RigidBody::Static,
+            Collider::cuboid(size.x, size.y, size.z),
+            CollisionLayers::new(GameLayer::Trigger, [GameLayer::Player]),
+            Sensor,
+        ))
+    }
+
+    fn label(&mut self, text: &str, world_pos: Vec3) {
+        self.commands.spawn((
+            TestGym,
+            GymLabel { world_pos },
+            Text::new(text),
+            TextFont {
+                font_size: 15.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.65)),
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Green at 10° → yellow at 30° → orange at 45° → red at 60°
+fn ramp_color(degrees: f32) -> Color {
+    let t = ((degrees - 10.0) / 50.0).clamp(0.0, 1.0);
+    if t < 0.5 {
+        let u = t * 2.0;
+        Color::srgb(0.4 + u * 0.4, 0.7 - u * 0.2, 0.4 - u * 0.2)
+    } else {
+        let u = (t - 0.5) * 2.0;
+        Color::srgb(0.8 + u * 0.1, 0.5 - u * 0.3, 0.2 - u * 0.1)
+    }
+}
+
+/// 64×64 checkerboard for the floor
+fn checker_image() -> Image {
+    let size = 64;
+    let check_size = 8;
+    let mut data = Vec::with_capacity(size * size * 4);
+    for y in 0..size {
+        for x in 0..size {
+            let light = ((x / check_size) + (y / check_size)) % 2 == 0;
+            let rgb = if light { [180, 200, 170] } else { [140, 160, 130] };
+            data.extend(rgb);
+            data.push(255);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use avian3d::prelude::*;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::camera::{CameraConfig, CameraRig};
+use crate::player::{ColliderConfig, Player, PlayerConfig, ResizePlayer};
+
+/// Movement and camera tuning loaded from a `.locomotion.ron` file.
+///
+/// Missing fields keep their defaults, so a file only needs the values it tunes.
+#[derive(Asset, TypePath, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LocomotionTuning {
+    pub player: PlayerConfig,
+    pub camera: CameraConfig,
+}
+
+/// Tuning asset driving a player (lives on the player).
+///
+/// The player's `PlayerConfig` and its rig's `CameraConfig` are replaced
+/// whenever the asset loads or changes on disk. Body size changes go through
+/// `ResizePlayer`, and a reload that leaves the size alone keeps any runtime
+/// resize.
+#[derive(Component, Clone, Debug, Deref)]
+pub struct LocomotionTuningHandle(pub Handle<LocomotionTuning>);
+
+/// Error loading a `LocomotionTuning` asset
+#[derive(Debug)]
+pub enum LocomotionTuningError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl fmt::Display for LocomotionTuningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read locomotion tuning: {err}"),
+            Self::Ron(err) => write!(f, "could not parse locomotion tuning: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LocomotionTuningError {}
+
+impl From<std::io::Error> for LocomotionTuningError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for LocomotionTuningError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Ron(err)
+    }
+}
+
+/// Loads `LocomotionTuning` from RON files ending in `.locomotion.ron`
+#[derive(Default, TypePath)]
+pub struct LocomotionTuningLoader;
+
+impl AssetLoader for LocomotionTuningLoader {
+    type Asset = LocomotionTuning;
+    type Settings = ();
+    type Error = LocomotionTuningError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locomotion.ron"]
+    }
+}
+
+impl PlayerConfig {
+    /// Parses a config from RON, e.g. a preset saved with `to_ron`.
+    ///
+    /// Missing fields keep their defaults and the result is validated.
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        let mut config: Self = ron::from_str(ron)?;
+        config.validate();
+        Ok(config)
+    }
+
+    /// Writes the full config as pretty-printed RON
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+/// Capsule dimensions `ResizePlayer` changes
+fn body_size(collider: &ColliderConfig) -> [f32; 3] {
+    [
+        collider.stand_height,
+        collider.crouch_height,
+        collider.radius,
+    ]
+}
+
+/// Copies loaded or modified tuning onto the players that use it
+#[allow(clippy::type_complexity)]
+pub fn apply_locomotion_tuning(
+    mut reader: MessageReader<AssetEvent<LocomotionTuning>>,
+    tunings: Res<Assets<LocomotionTuning>>,
+    mut resize_writer: MessageWriter<ResizePlayer>,
+    mut last_sizes: Local<HashMap<AssetId<LocomotionTuning>, [f32; 3]>>,
+    mut player_query: Query<
+        (
+            Entity,
+            Ref<LocomotionTuningHandle>,
+            &mut PlayerConfig,
+            &mut CollisionLayers,
+            Option<&CameraRig>,
+        ),
+        With<Player>,
+    >,
+    mut camera_query: Query<&mut CameraConfig>,
+) {
+    let changed: Vec<AssetId<LocomotionTuning>> = reader
+        .read()
+        .filter_map(|event| match *event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(id),
+            _ => None,
+        })
+        .collect();
+    let mut resized = Vec::new();
+    for &id in &changed {
+        let Some(tuning) = tunings.get(id) else {
+            continue;
+        };
+        let size = body_size(&tuning.player.collider);
+        if last_sizes.insert(id, size) != Some(size) {
+            resized.push(id);
+        }
+    }
+
+    for (player, handle, mut config, mut layers, rig) in &mut player_query {
+        // Newly attached handles pick up an already loaded asset
+        if !handle.is_added() && !changed.contains(&handle.id()) {
+            continue;
+        }
+        let Some(tuning) = tunings.get(&handle.0) else {
+            continue;
+        };
+
+        // Resize from the current size so the feet stay planted
+        let current = config.collider;
+        *config = tuning.player;
+        config.collider.stand_height = current.stand_height;
+        config.collider.crouch_height = current.crouch_height;
+        config.collider.radius = current.radius;
+        config.validate();
+        let tuned = &tuning.player.collider;
+        if (handle.is_added() || resized.contains(&handle.id()))
+            && body_size(tuned) != body_size(&current)
+        {
+            resize_writer.write(ResizePlayer {
+                player,
+                stand_height: tuned.stand_height,
+                crouch_height: tuned.crouch_height,
+                radius: tuned.radius,
+            });
+        }
+        let collider = &config.collider;
+        *layers = CollisionLayers::new(collider.player_layer, collider.collision_mask);
+
+        let Some(rig) = rig else {
+            continue;
+        };
+        let mut cameras = camera_query.iter_many_mut(rig.entities());
+        while let Some(mut camera_config) = cameras.fetch_next() {
+            *camera_config = tuning.camera.clone();
+        }
+    }
+}
+
+/// Registers the `LocomotionTuning` asset, its loader, and live reloading
+pub struct LocomotionTuningPlugin;
+
+impl Plugin for LocomotionTuningPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LocomotionTuning>()
+            .add_message::<ResizePlayer>()
+            .init_asset_loader::<LocomotionTuningLoader>()
+            .add_systems(Update, apply_locomotion_tuning);
+    }
+}